@@ -2,7 +2,7 @@ use {crate::Specifier, std::rc::Rc};
 
 #[derive(Debug, PartialEq)]
 pub struct Alias {
-  /// "npm:@fluidframework/build-tools@~0.44.0"
+  /// "npm:@fluidframework/build-tools@~0.44.0" or "jsr:@std/path@^1.0.0"
   pub raw: String,
   /// "@fluidframework/build-tools"
   pub name: String,
@@ -12,9 +12,17 @@ pub struct Alias {
   pub inner_specifier: Rc<Specifier>,
 }
 
+/// Registries an alias can point to
+const PROTOCOLS: [&str; 2] = ["npm:", "jsr:"];
+
+/// Return what follows the `npm:` or `jsr:` protocol of an alias
+fn strip_protocol(raw: &str) -> Option<&str> {
+  PROTOCOLS.iter().find_map(|protocol| raw.strip_prefix(protocol))
+}
+
 impl Alias {
   pub fn create(raw: &str) -> Specifier {
-    let name = raw.strip_prefix("npm:").map(|after_prefix| {
+    let name = strip_protocol(raw).map(|after_prefix| {
       after_prefix
         .rfind('@')
         .filter(|&at_pos| at_pos > 0 && !after_prefix[..at_pos].is_empty())
@@ -22,8 +30,7 @@ impl Alias {
         .unwrap_or(after_prefix)
     });
 
-    let version_part = raw
-      .strip_prefix("npm:")
+    let version_part = strip_protocol(raw)
       .and_then(|after_prefix| after_prefix.rfind('@').map(|at_pos| (after_prefix, at_pos)))
       .and_then(|(after_prefix, at_pos)| (at_pos > 0 && !after_prefix[..at_pos].is_empty()).then(|| &after_prefix[at_pos + 1..]))
       .filter(|version| !version.is_empty());
@@ -47,4 +54,14 @@ impl Alias {
       })
       .unwrap_or(Specifier::Unsupported(raw.to_string()))
   }
+
+  /// The protocol this alias was written with, either "npm:" or "jsr:"
+  pub fn protocol(&self) -> &str {
+    &self.raw[..4]
+  }
+
+  /// Whether this alias points to a package on the JSR registry
+  pub fn is_jsr(&self) -> bool {
+    self.raw.starts_with("jsr:")
+  }
 }
@@ -101,7 +101,7 @@ pub fn strip_semver_range(value: &str) -> &str {
 
 #[derive(Debug)]
 pub enum Specifier {
-  Alias(alias::Alias),                                      // "npm:foo@1.2.3"
+  Alias(alias::Alias),                                      // "npm:foo@1.2.3", "jsr:@std/path@1.2.3"
   Catalog(catalog::Catalog),                                // "catalog:", "catalog:react18"
   ComplexSemver(complex_semver::ComplexSemver),             // ">=1.2.3 <2.0.0"
   Exact(exact::Exact),                                      // "1.2.3"
@@ -258,6 +258,16 @@ impl Specifier {
           Self::Unsupported(value.to_string())
         }
       }
+      // jsr alias
+      Some(b'j') => {
+        if value.starts_with("jsr:") {
+          Alias::create(value)
+        } else if parser::is_tag(value) {
+          Tag::create(value)
+        } else {
+          Self::Unsupported(value.to_string())
+        }
+      }
       // Git
       Some(b'g') => {
         if parser::is_git(value) {
@@ -531,6 +541,7 @@ impl Specifier {
   /// - "npm:@scope/package@1.2.3" + "*" → Some("npm:@scope/package")
   /// - "^1" + "*" → "*"
   /// - "npm:foo@^1" + "*" → "npm:foo"
+  /// - "jsr:@std/path@1.0.0" + "^" → Some("jsr:@std/path@^1.0.0")
   /// - "workspace:^1" + "*" → "workspace:*"
   /// - "git@github.com:npm/cli.git#^1" + "*" → "git@github.com:npm/cli.git"
   pub fn with_range(&self, range: &SemverRange) -> Option<Rc<Self>> {
@@ -542,7 +553,7 @@ impl Specifier {
         Self::WorkspaceProtocol(_) => Some(Self::new("workspace:*")),
         Self::Alias(s) => {
           if s.inner_specifier.get_semver_number().is_some() {
-            Some(Self::new(&format!("npm:{}", s.name)))
+            Some(Self::new(&format!("{}{}", s.protocol(), s.name)))
           } else {
             None
           }
@@ -591,7 +602,7 @@ impl Specifier {
       Self::Alias(s) => s
         .inner_specifier
         .with_range(range)
-        .map(|new_inner| Self::new(&format!("npm:{}@{}", s.name, new_inner.get_raw()))),
+        .map(|new_inner| Self::new(&format!("{}{}@{}", s.protocol(), s.name, new_inner.get_raw()))),
       Self::WorkspaceProtocol(s) => {
         // If resolved, use the resolved specifier's version
         if let Some(resolved) = s.as_resolved() {
@@ -638,6 +649,7 @@ impl Specifier {
   /// - "*" + "1.2.3" -> None
   /// - "npm:@scope/package@1.2.3" + "2.3.4" → Some("npm:@scope/package@2.3.4")
   /// - "npm:@scope/package@~1.2.3" + "2.3.4" → Some("npm:@scope/package@~2.3.4")
+  /// - "jsr:@std/path@^1.0.0" + "1.1.0" → Some("jsr:@std/path@^1.1.0")
   pub fn with_node_version(&self, node_version: &node_semver::Version) -> Option<Rc<Self>> {
     let version_str = node_version.to_string();
 
@@ -678,7 +690,7 @@ impl Specifier {
       Self::Alias(a) => a
         .inner_specifier
         .with_node_version(node_version)
-        .map(|new_inner| Self::new(&format!("npm:{}@{}", a.name, new_inner.get_raw()))),
+        .map(|new_inner| Self::new(&format!("{}{}@{}", a.protocol(), a.name, new_inner.get_raw()))),
 
       Self::WorkspaceProtocol(wp) => {
         // Only works if there's a resolved specifier with version
@@ -867,6 +879,10 @@ impl Specifier {
     matches!(self, Self::Link(_))
   }
 
+  pub fn is_file(&self) -> bool {
+    matches!(self, Self::File(_))
+  }
+
   /// Check if this specifier is a catalog protocol
   pub fn is_catalog(&self) -> bool {
     matches!(self, Self::Catalog(_))
@@ -19,7 +19,31 @@ fn has_tag_suffix(bytes: &[u8], pos: usize) -> bool {
       .all(|&b| matches!(b, b'a'..=b'z' | b'0'..=b'9' | b'.' | b'-' | b'_'))
 }
 
-/// Check if bytes from `pos` match digits.digits.digits (optionally with -tag)
+/// Check if bytes from `pos` match `+build` metadata, such as the
+/// `+sha512.abc123` hash written to `packageManager` by Corepack
+fn has_build_suffix(bytes: &[u8], pos: usize) -> bool {
+  pos < bytes.len()
+    && bytes[pos] == b'+'
+    && bytes.len() > pos + 1
+    && bytes[pos + 1..]
+      .iter()
+      .all(|&b| b.is_ascii_alphanumeric() || matches!(b, b'.' | b'-'))
+}
+
+/// Check if bytes from `pos` match an optional -tag followed by optional
+/// +build metadata
+fn has_version_suffix(bytes: &[u8], pos: usize) -> bool {
+  match bytes[pos..].iter().position(|&b| b == b'+') {
+    Some(offset) => {
+      let build_start = pos + offset;
+      (build_start == pos || has_tag_suffix(&bytes[..build_start], pos)) && has_build_suffix(bytes, build_start)
+    }
+    None => has_tag_suffix(bytes, pos),
+  }
+}
+
+/// Check if bytes from `pos` match digits.digits.digits (optionally with
+/// -tag and +build)
 fn is_semver_triple(bytes: &[u8], pos: usize) -> bool {
   let Some(p1) = skip_digits(bytes, pos) else { return false };
   if p1 >= bytes.len() || bytes[p1] != b'.' {
@@ -30,7 +54,7 @@ fn is_semver_triple(bytes: &[u8], pos: usize) -> bool {
     return false;
   }
   let Some(p3) = skip_digits(bytes, p2 + 1) else { return false };
-  p3 == bytes.len() || has_tag_suffix(bytes, p3)
+  p3 == bytes.len() || has_version_suffix(bytes, p3)
 }
 
 /// Check if bytes from `pos` match digits.digits$
@@ -90,6 +90,22 @@ pub fn get_exact() -> Vec<(&'static str, Exact)> {
         node_range: Rc::new(node_semver::Range::parse("1.2.3-rc.0").unwrap()),
       },
     ),
+    (
+      "9.1.0+sha512.1a2B3c",
+      Exact {
+        raw: "9.1.0+sha512.1a2B3c".to_string(),
+        node_version: Rc::new(node_semver::Version::parse("9.1.0+sha512.1a2B3c").unwrap()),
+        node_range: Rc::new(node_semver::Range::parse("9.1.0+sha512.1a2B3c").unwrap()),
+      },
+    ),
+    (
+      "1.2.3-rc.1+build.5",
+      Exact {
+        raw: "1.2.3-rc.1+build.5".to_string(),
+        node_version: Rc::new(node_semver::Version::parse("1.2.3-rc.1+build.5").unwrap()),
+        node_range: Rc::new(node_semver::Range::parse("1.2.3-rc.1+build.5").unwrap()),
+      },
+    ),
   ]
 }
 
@@ -328,6 +344,7 @@ pub fn get_range() -> Vec<(&'static str, Range)> {
 pub fn get_unsupported() -> Vec<&'static str> {
   vec![
     "$typescript",
+    "1.2.3+",
     "/path/to/foo",
     "/path/to/foo.tar",
     "/path/to/foo.tgz",
@@ -390,6 +407,24 @@ pub fn get_alias() -> Vec<(&'static str, Alias)> {
         inner_specifier: Rc::new(Specifier::create("1.2.3")),
       },
     ),
+    (
+      "jsr:@std/path@^1.0.0",
+      Alias {
+        raw: "jsr:@std/path@^1.0.0".to_string(),
+        name: "@std/path".to_string(),
+        version_str: "^1.0.0".to_string(),
+        inner_specifier: Rc::new(Specifier::create("^1.0.0")),
+      },
+    ),
+    (
+      "jsr:@std/assert",
+      Alias {
+        raw: "jsr:@std/assert".to_string(),
+        name: "@std/assert".to_string(),
+        version_str: "*".to_string(),
+        inner_specifier: Rc::new(Specifier::create("*")),
+      },
+    ),
   ]
 }
 
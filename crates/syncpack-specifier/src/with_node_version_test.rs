@@ -108,6 +108,7 @@ fn on_alias() {
     ("npm:foo@~1.2.3", "2.3.4", "npm:foo@~2.3.4"),
     ("npm:foo@>=1.2.3", "2.3.4", "npm:foo@>=2.3.4"),
     ("npm:foo@1.2.3-alpha", "2.3.4-beta", "npm:foo@2.3.4-beta"),
+    ("jsr:@std/path@^1.2.3", "2.3.4", "jsr:@std/path@^2.3.4"),
   ];
   for (old, new_version_str, expected) in cases {
     let new_version = node_semver::Version::parse(new_version_str).unwrap();
@@ -6,6 +6,7 @@ fn basic_behavior() {
     ("npm:foo", None),
     ("npm:foo@1.2.3", Some(Specifier::new("npm:foo@~1.2.3"))),
     ("npm:@foo/bar@1.2.3", Some(Specifier::new("npm:@foo/bar@~1.2.3"))),
+    ("jsr:@std/path@1.2.3", Some(Specifier::new("jsr:@std/path@~1.2.3"))),
     ("1.2.3", Some(Specifier::new("~1.2.3"))),
     ("^1.2.3", Some(Specifier::new("~1.2.3"))),
     ("~1.2.3", Some(Specifier::new("~1.2.3"))),
@@ -1,6 +1,10 @@
 export interface RcFile {
   /** @see https://syncpack.dev/config/syncpackrc/#json */
   $schema?: string;
+  /** @see https://syncpack.dev/config/check-lockfile */
+  checkLockfile?: boolean;
+  /** @see https://syncpack.dev/config/cross-root-version-groups */
+  crossRootVersionGroups?: CrossRootVersionGroup[];
   /** @see https://syncpack.dev/config/custom-types */
   customTypes?: {
     [name: string]: CustomType.Any;
@@ -13,6 +17,8 @@ export interface RcFile {
   formatRepository?: boolean;
   /** @see https://syncpack.dev/config/indent */
   indent?: string;
+  /** @see https://syncpack.dev/config/local-path-policy */
+  localPathPolicy?: 'allow' | 'workspace' | 'version';
   /** @see https://syncpack.dev/config/max-concurrent-requests */
   maxConcurrentRequests?: number;
   /**
@@ -23,6 +29,12 @@ export interface RcFile {
    * @see https://pnpm.io/settings#minimumreleaseage
    */
   minimumReleaseAge?: number;
+  /** @see https://syncpack.dev/config/respect-gitignore */
+  respectGitignore?: boolean;
+  /** @see https://syncpack.dev/config/selectors */
+  selectors?: {
+    [name: string]: Selector;
+  };
   /** @see https://syncpack.dev/semver-groups */
   semverGroups?: SemverGroup.Any[];
   /** @see https://syncpack.dev/update-groups */
@@ -43,6 +55,8 @@ export interface RcFile {
   strict?: boolean;
   /** @see https://syncpack.dev/version-groups */
   versionGroups?: VersionGroup.Any[];
+  /** @see https://syncpack.dev/config/workspace-roots */
+  workspaceRoots?: string[];
 
   /** @deprecated */
   dependencyTypes?: never;
@@ -67,6 +81,25 @@ export interface GroupSelector {
   label?: string;
   /** @see https://syncpack.dev/version-groups/highest-semver/#packages */
   packages?: string[];
+  /** @see https://syncpack.dev/config/selectors */
+  selector?: string;
+  /** @see https://syncpack.dev/version-groups/highest-semver/#specifiertypes */
+  specifierTypes?: SpecifierType[];
+}
+
+export interface Selector {
+  /** @see https://syncpack.dev/config/selectors/#allof */
+  allOf?: (string | Selector)[];
+  /** @see https://syncpack.dev/config/selectors/#anyof */
+  anyOf?: (string | Selector)[];
+  /** @see https://syncpack.dev/version-groups/highest-semver/#dependencies */
+  dependencies?: string[];
+  /** @see https://syncpack.dev/version-groups/highest-semver/#dependencytypes */
+  dependencyTypes?: DependencyType[];
+  /** @see https://syncpack.dev/config/selectors/#not */
+  not?: string | Selector;
+  /** @see https://syncpack.dev/version-groups/highest-semver/#packages */
+  packages?: string[];
   /** @see https://syncpack.dev/version-groups/highest-semver/#specifiertypes */
   specifierTypes?: SpecifierType[];
 }
@@ -84,6 +117,21 @@ export interface DependencyGroup {
   specifierTypes?: SpecifierType[];
 }
 
+export interface CrossRootVersionGroup {
+  /** @see https://syncpack.dev/config/cross-root-version-groups/#dependencies */
+  dependencies?: string[];
+  /** @see https://syncpack.dev/config/cross-root-version-groups/#dependencytypes */
+  dependencyTypes?: DependencyType[];
+  /** @see https://syncpack.dev/config/cross-root-version-groups/#label */
+  label?: string;
+  /** @see https://syncpack.dev/config/cross-root-version-groups/#packages */
+  packages?: string[];
+  /** @see https://syncpack.dev/config/cross-root-version-groups/#preferversion */
+  preferVersion?: 'highestSemver' | 'lowestSemver';
+  /** @see https://syncpack.dev/config/cross-root-version-groups/#specifiertypes */
+  specifierTypes?: SpecifierType[];
+}
+
 namespace SemverGroup {
   export interface Ignored extends GroupSelector {
     /** @see https://syncpack.dev/semver-groups/ignored/#isignored */
@@ -173,6 +221,7 @@ namespace VersionGroup {
     severity?: {
       SemverRangeMismatch?: Severity;
       DiffersToLocal?: Severity;
+      UsesLocalPath?: Severity;
       DiffersToCatalog?: Severity;
       DiffersToHighestOrLowestSemver?: Severity;
     };
@@ -225,7 +274,32 @@ namespace CustomType {
     /** @see https://syncpack.dev/config/custom-types/#namestrategy */
     strategy: 'versionsByName';
   }
-  export type Any = NameAndVersionProps | NamedVersionString | UnnamedVersionString | VersionsByName;
+  export interface ImportMap {
+    /** @see https://syncpack.dev/config/custom-types/#name */
+    path: string;
+    /** @see https://syncpack.dev/config/custom-types/#namestrategy */
+    strategy: 'importMap';
+  }
+  export interface OverrideTree {
+    /** @see https://syncpack.dev/config/custom-types/#name */
+    path: string;
+    /** @see https://syncpack.dev/config/custom-types/#namestrategy */
+    strategy: 'overrideTree';
+  }
+  export interface ResolutionPaths {
+    /** @see https://syncpack.dev/config/custom-types/#name */
+    path: string;
+    /** @see https://syncpack.dev/config/custom-types/#namestrategy */
+    strategy: 'resolutionPaths';
+  }
+  export type Any =
+    | NameAndVersionProps
+    | NamedVersionString
+    | UnnamedVersionString
+    | VersionsByName
+    | ImportMap
+    | OverrideTree
+    | ResolutionPaths;
 }
 
 type SemverRange = '' | '*' | '>' | '>=' | '.x' | '<' | '<=' | '^' | '~';
@@ -237,7 +311,20 @@ export type Severity = 'fix' | 'warn' | 'error';
  * instances in `syncpack json` and is not writable in rcfile severity maps. */
 export type JsonSeverity = Severity | 'none';
 
-type DependencyType = 'dev' | 'local' | 'overrides' | 'peer' | 'pnpmOverrides' | 'prod' | 'resolutions' | AnyString;
+type DependencyType =
+  | 'deno'
+  | 'dev'
+  | 'engines'
+  | 'local'
+  | 'overrides'
+  | 'packageManager'
+  | 'peer'
+  | 'pnpmOverrides'
+  | 'pnpmOverridesLegacy'
+  | 'prod'
+  | 'resolutions'
+  | 'volta'
+  | AnyString;
 
 type SpecifierType =
   | 'alias'
@@ -266,6 +353,10 @@ export type JsonOutput = {
   dependencyGroup: string;
   dependencyType: DependencyType;
   package: string;
+  /** 1-based line of the version in its file, when known */
+  line: number | null;
+  /** 1-based column of the version in its file, when known */
+  column: number | null;
   property: string[];
   strategy: CustomType.Any['strategy'];
   versionGroup: VersionGroupVariant;
@@ -278,6 +369,8 @@ export type JsonOutput = {
     raw: string;
     type: SpecifierType;
   };
+  /** The version installed according to the lockfile, when one was found */
+  resolved: string | null;
   expected: {
     raw: string;
     type: SpecifierType;
@@ -286,14 +379,65 @@ export type JsonOutput = {
 
 export type StatusType = 'Valid' | 'Fixable' | 'Unfixable' | 'Suspect' | 'Conflict';
 
+/** Each Instance printed by `syncpack lint --reporter json` and `syncpack list --reporter json` */
+export type GroupedJsonOutput = JsonOutput & {
+  /** Position of the version group in the order syncpack checks them, the default group is always last */
+  versionGroupIndex: number;
+  /** The `label` of the version group, or a name for it when it has none */
+  versionGroupLabel: string;
+};
+
+/**
+ * Each update printed by `syncpack update --reporter json`, one per
+ * dependency, version group and current specifier
+ */
+export type UpdateJsonOutput = {
+  dependency: string;
+  versionGroup: VersionGroupVariant;
+  versionGroupIndex: number;
+  versionGroupLabel: string;
+  /** How many instances are written with the `current` specifier */
+  count: number;
+  current: {
+    raw: string;
+    /** How long ago this version was published, eg. `~14d` */
+    age: string | null;
+  };
+  target: {
+    raw: string;
+    age: string | null;
+  };
+  /** `null` when either specifier is not an exact semver version */
+  diff: 'major' | 'minor' | 'patch' | null;
+};
+
 /** Each formatting mismatch printed by `syncpack format --reporter json` */
 export type FormatJsonOutput = {
   package: string;
   filePath: string;
+  /** 1-based line of the property in its file, when known */
+  line: number | null;
+  /** 1-based column of the property in its file, when known */
+  column: number | null;
   property: string[];
   statusCode: FormatStatusCode;
 };
 
+/**
+ * The contents of the file written by `syncpack lint --update-baseline`,
+ * sorted by each property in turn
+ * @see https://syncpack.dev/command/lint/#--baseline
+ */
+export type Baseline = Array<{
+  /** Path of the file containing the instance, relative to the workspace root */
+  package: string;
+  dependencyType: string;
+  dependency: string;
+  statusCode: StatusCode;
+  /** The specifier the instance had when it was recorded */
+  actual: string;
+}>;
+
 export type FormatStatusCode =
   | 'BugsPropertyIsNotFormatted'
   | 'RepositoryPropertyIsNotFormatted'
@@ -342,15 +486,19 @@ export type StatusCode =
   | 'SameMinorOverridesSemverRange'
   | 'SameMinorOverridesSemverRangeMismatch'
   | 'SemverRangeMismatch'
+  | 'UsesLocalPath'
   | 'DependsOnInvalidLocalPackage'
   | 'NonSemverMismatch'
   | 'SameRangeMismatch'
   | 'SameMinorMismatch'
   | 'DependsOnMissingSnapTarget'
   | 'InvalidLocalVersion'
+  | 'OrphanedPatch'
   | 'RefuseToBanLocal'
   | 'RefuseToPinLocal'
   | 'RefuseToSnapLocal'
+  | 'ResolvedVersionMismatch'
+  | 'ResolvedVersionOutOfRange'
   | 'MatchConflictsWithHighestOrLowestSemver'
   | 'MatchConflictsWithLocal'
   | 'MatchConflictsWithSnapTarget'
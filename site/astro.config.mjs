@@ -109,13 +109,18 @@ export default defineConfig({
           label: 'Configuration File',
           items: [
             'config/syncpackrc',
+            'config/check-lockfile',
+            'config/cross-root-version-groups',
             'config/custom-types',
             'config/dependency-groups',
             'config/format-bugs',
             'config/format-repository',
             'config/indent',
+            'config/local-path-policy',
             'config/max-concurrent-requests',
             'config/minimum-release-age',
+            'config/respect-gitignore',
+            'config/selectors',
             { label: 'semverGroups', link: '/semver-groups/' },
             'config/severity',
             'config/sort-az',
@@ -127,6 +132,7 @@ export default defineConfig({
             'config/strict',
             { label: 'updateGroups', link: '/update-groups/', badge: 'New' },
             { label: 'versionGroups', link: '/version-groups/' },
+            'config/workspace-roots',
           ],
         },
         {
@@ -7,15 +7,20 @@ export function linkAliases() {
     COMMAND_JSON: '/command/json/',
     COMMAND_LINT: '/command/lint/',
     COMMAND_LIST: '/command/list/',
+    COMMAND_REPORT: '/command/report/',
     COMMAND_UPDATE: '/command/update/',
 
+    CONFIG_CHECK_LOCKFILE: '/config/check-lockfile/',
+    CONFIG_CROSS_ROOT_VERSION_GROUPS: '/config/cross-root-version-groups/',
     CONFIG_CUSTOM_TYPES: '/config/custom-types/',
     CONFIG_DEPENDENCY_GROUPS: '/config/dependency-groups/',
     CONFIG_FORMAT_BUGS: '/config/format-bugs/',
     CONFIG_FORMAT_REPOSITORY: '/config/format-repository/',
     CONFIG_INDENT: '/config/indent/',
+    CONFIG_LOCAL_PATH_POLICY: '/config/local-path-policy/',
     CONFIG_MAX_CONCURRENT_REQUESTS: '/config/max-concurrent-requests/',
     CONFIG_MINIMUM_RELEASE_AGE: '/config/minimum-release-age/',
+    CONFIG_RESPECT_GITIGNORE: '/config/respect-gitignore/',
     CONFIG_SEMVER_GROUPS: '/semver-groups/',
     CONFIG_SORT_AZ: '/config/sort-az/',
     CONFIG_SORT_EXPORTS: '/config/sort-exports/',
@@ -27,7 +32,9 @@ export function linkAliases() {
     CONFIG_SYNCPACKRC: '/config/syncpackrc/',
     CONFIG_UPDATE_GROUPS: '/update-groups/',
     CONFIG_VERSION_GROUPS: '/version-groups/',
+    CONFIG_WORKSPACE_ROOTS: '/config/workspace-roots/',
 
+    GUIDE_EXIT_CODES: '/guide/exit-codes/',
     GUIDE_GETTING_STARTED: '/guide/getting-started/',
     GUIDE_MIGRATE_V14: '/guide/migrate-v14/',
     GUIDE_MIGRATE_V15: '/guide/migrate-v15/',
@@ -65,9 +72,12 @@ export function linkAliases() {
 
     HREF_ANSI: 'https://en.wikipedia.org/wiki/ANSI_escape_code',
     HREF_AWS_SDK: 'https://aws.amazon.com/sdk-for-javascript/',
+    HREF_BOLT: 'https://github.com/boltpkg/bolt',
     HREF_CATEGORIZE_YOUR_DEPENDENCIES: 'https://antfu.me/posts/categorize-deps',
     HREF_CONDITIONAL_EXPORTS: 'https://nodejs.org/api/packages.html#conditional-exports',
     HREF_COSMICONFIG: 'https://github.com/cosmiconfig/cosmiconfig',
+    HREF_DENO_IMPORTS: 'https://docs.deno.com/runtime/fundamentals/configuration/#dependencies',
+    HREF_DENO_WORKSPACES: 'https://docs.deno.com/runtime/fundamentals/workspaces/',
     HREF_DEPENDENCIES: 'https://docs.npmjs.com/cli/v11/configuring-npm/package-json#dependencies',
     HREF_DEV_DEPENDENCIES: 'https://docs.npmjs.com/cli/v11/configuring-npm/package-json#devDependencies',
     HREF_ENGINES: 'https://docs.npmjs.com/cli/v11/configuring-npm/package-json#engines',
@@ -77,17 +87,22 @@ export function linkAliases() {
     HREF_NPM_EXEC: 'https://docs.npmjs.com/cli/v11/commands/npm-exec',
     HREF_NPM_WORKSPACES: 'https://docs.npmjs.com/cli/v11/using-npm/workspaces',
     HREF_NPX: 'https://docs.npmjs.com/cli/v11/commands/npx',
+    HREF_NX: 'https://nx.dev/',
     HREF_OVERRIDES: 'https://docs.npmjs.com/cli/v11/configuring-npm/package-json#overrides',
     HREF_PACKAGE_MANAGER: 'https://nodejs.org/api/packages.html#packagemanager',
     HREF_PEER_DEPENDENCIES: 'https://docs.npmjs.com/cli/v11/configuring-npm/package-json#peerDependencies',
     HREF_PNPM: 'https://pnpm.js.org/',
     HREF_PNPM_MINIMUM_RELEASE_AGE: 'https://pnpm.io/settings#minimumreleaseage',
     HREF_PNPM_OVERRIDES: 'https://pnpm.io/settings#overrides',
+    HREF_PNPM_PATCHED_DEPENDENCIES: 'https://pnpm.io/settings#patcheddependencies',
+    HREF_PNPM_PACKAGE_OVERRIDES: 'https://pnpm.io/9.x/package_json#pnpmoverrides',
     HREF_RESOLUTIONS: 'https://docs.npmjs.com/cli/v11/configuring-npm/package-json#resolutions',
+    HREF_RUSH: 'https://rushjs.io/',
     HREF_SYNCPACK_GITHUB_ACTION:
       'https://github.com/marketplace/actions/syncpack-synchronise-monorepo-dependency-versions',
     HREF_TYPES: 'https://github.com/DefinitelyTyped/DefinitelyTyped',
     HREF_VERSION: 'https://docs.npmjs.com/cli/v11/configuring-npm/package-json#version',
+    HREF_VOLTA: 'https://docs.volta.sh/guide/understanding#managing-your-project',
     HREF_WORKSPACE_PROTOCOL: 'https://pnpm.io/workspaces#workspace-protocol-workspace',
     HREF_YARN_WORKSPACES: 'https://yarnpkg.com/lang/en/docs/workspaces/',
 
@@ -128,6 +143,7 @@ export function linkAliases() {
     STATUS_MISSING_FROM_CATALOG_AND_NON_SEMVER_MISMATCH: '/status/missing-from-catalog-and-non-semver-mismatch/',
     STATUS_NON_SEMVER_MISMATCH: '/status/non-semver-mismatch/',
     STATUS_NOT_USING_CATALOG: '/status/not-using-catalog/',
+    STATUS_ORPHANED_PATCH: '/status/orphaned-patch/',
     STATUS_NOT_USING_CATALOG_AND_CATALOG_UNKNOWN: '/status/not-using-catalog-and-catalog-unknown/',
     STATUS_PIN_OVERRIDES_SEMVER_RANGE: '/status/pin-overrides-semver-range/',
     STATUS_PIN_OVERRIDES_SEMVER_RANGE_MISMATCH: '/status/pin-overrides-semver-range-mismatch/',
@@ -135,6 +151,8 @@ export function linkAliases() {
     STATUS_REFUSE_TO_CATALOG_LOCAL: '/status/refuse-to-catalog-local/',
     STATUS_REFUSE_TO_PIN_LOCAL: '/status/refuse-to-pin-local/',
     STATUS_REFUSE_TO_SNAP_LOCAL: '/status/refuse-to-snap-local/',
+    STATUS_RESOLVED_VERSION_MISMATCH: '/status/resolved-version-mismatch/',
+    STATUS_RESOLVED_VERSION_OUT_OF_RANGE: '/status/resolved-version-out-of-range/',
     STATUS_SAME_MINOR_HAS_MAJOR_MISMATCH: '/status/same-minor-has-major-mismatch/',
     STATUS_SAME_MINOR_MISMATCH: '/status/same-minor-mismatch/',
     STATUS_SAME_MINOR_OVERRIDES_SEMVER_RANGE: '/status/same-minor-overrides-semver-range/',
@@ -146,6 +164,7 @@ export function linkAliases() {
     STATUS_SATISFIES_SAME_RANGE_GROUP: '/status/satisfies-same-range-group/',
     STATUS_SATISFIES_SNAP_TARGET: '/status/satisfies-snap-target/',
     STATUS_SEMVER_RANGE_MISMATCH: '/status/semver-range-mismatch/',
+    STATUS_USES_LOCAL_PATH: '/status/uses-local-path/',
 
     UPDATE_GROUP_IGNORED: '/update-groups/ignored/',
     UPDATE_GROUP_TARGETED: '/update-groups/targeted/',
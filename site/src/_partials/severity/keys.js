@@ -18,6 +18,7 @@ export const KEYS = {
   highestSemver: [
     { name: 'SemverRangeMismatch', slug: 'semver-range-mismatch' },
     { name: 'DiffersToLocal', slug: 'differs-to-local' },
+    { name: 'UsesLocalPath', slug: 'uses-local-path' },
     { name: 'DiffersToCatalog', slug: 'differs-to-catalog' },
     {
       name: 'DiffersToHighestOrLowestSemver',
@@ -27,6 +28,7 @@ export const KEYS = {
   lowestSemver: [
     { name: 'SemverRangeMismatch', slug: 'semver-range-mismatch' },
     { name: 'DiffersToLocal', slug: 'differs-to-local' },
+    { name: 'UsesLocalPath', slug: 'uses-local-path' },
     { name: 'DiffersToCatalog', slug: 'differs-to-catalog' },
     {
       name: 'DiffersToHighestOrLowestSemver',
@@ -11,11 +11,16 @@ use {
   yaml_serde::Value as YamlValue,
 };
 
-/// All catalog dep types implied by what's parsed onto Disk (pnpm + Bun).
+/// All catalog dep types implied by what's parsed onto Disk (pnpm, Yarn and
+/// Bun).
 ///
 /// Pnpm path: when `disk.pnpm_workspace` is present, generates one dep type
 /// per catalog name found in the yaml.
 ///
+/// Yarn path: when `disk.yarnrc` is present, generates one dep type per
+/// catalog name found in `.yarnrc.yml`, which uses the same `catalog:` /
+/// `catalogs:` layout as pnpm.
+///
 /// Bun path: gated on `disk.package_manager == Some(Bun)`. Uses
 /// `detect_bun_catalogs` (the single source of truth shared with fix-time)
 /// to scan top + `/workspaces`; picks the location with content; errors when
@@ -24,8 +29,13 @@ pub fn make_catalog_dep_types(disk: &Disk) -> Result<Vec<DependencyType>, Syncpa
   let start = Instant::now();
   let mut dep_types = Vec::new();
   if let Some(yaml) = &disk.pnpm_workspace {
-    for catalog_name in pnpm_catalog_names(yaml) {
-      dep_types.push(make_pnpm_dep_type(&catalog_name));
+    for catalog_name in yaml_catalog_names(yaml) {
+      dep_types.push(make_yaml_dep_type(&catalog_name, "pnpmCatalog", SourceKind::PnpmWorkspace));
+    }
+  }
+  if let Some(yaml) = &disk.yarnrc {
+    for catalog_name in yaml_catalog_names(yaml) {
+      dep_types.push(make_yaml_dep_type(&catalog_name, "yarnCatalog", SourceKind::YarnRc));
     }
   }
   if matches!(disk.package_manager, Some(PackageManager::Bun))
@@ -72,27 +82,28 @@ pub(crate) fn collect_bun_catalog_names(contents: &JsonValue, prefix: &str) -> V
   names
 }
 
-/// Build a pnpm catalog `DependencyType`. Bypasses `DependencyType::new`
-/// (which normalises `.` → `/` in paths); catalog names may legitimately
-/// contain dots.
-fn make_pnpm_dep_type(catalog_name: &str) -> DependencyType {
+/// Build a pnpm or Yarn catalog `DependencyType`, named `{name_prefix}` for
+/// the default catalog or `{name_prefix}:{catalog_name}` otherwise. Bypasses
+/// `DependencyType::new` (which normalises `.` → `/` in paths); catalog names
+/// may legitimately contain dots.
+fn make_yaml_dep_type(catalog_name: &str, name_prefix: &str, source: SourceKind) -> DependencyType {
   let (name, path) = if catalog_name == "default" {
-    ("pnpmCatalog".to_string(), "/catalog".to_string())
+    (name_prefix.to_string(), "/catalog".to_string())
   } else {
-    (format!("pnpmCatalog:{catalog_name}"), format!("/catalogs/{catalog_name}"))
+    (format!("{name_prefix}:{catalog_name}"), format!("/catalogs/{catalog_name}"))
   };
   DependencyType {
     name_path: None,
     name,
     path,
     strategy: Strategy::VersionsByName,
-    source: SourceKind::PnpmWorkspace,
+    source,
     is_catalog_definition: true,
   }
 }
 
 /// Build a Bun catalog `DependencyType`. Bypasses `DependencyType::new`
-/// for the same reason as `make_pnpm_dep_type`.
+/// for the same reason as `make_yaml_dep_type`.
 fn make_bun_dep_type(catalog_name: &str, path_prefix: &str) -> DependencyType {
   let (name, path) = if catalog_name == "default" {
     ("bunCatalog".to_string(), format!("{path_prefix}/catalog"))
@@ -113,8 +124,8 @@ fn make_bun_dep_type(catalog_name: &str, path_prefix: &str) -> DependencyType {
 }
 
 /// Discover the catalog names (`"default"` + named) defined in a
-/// `pnpm-workspace.yaml` file.
-pub fn pnpm_catalog_names(file: &YamlFile) -> Vec<String> {
+/// `pnpm-workspace.yaml` or `.yarnrc.yml` file.
+pub fn yaml_catalog_names(file: &YamlFile) -> Vec<String> {
   let mut names = Vec::new();
   if let Some(YamlValue::Mapping(map)) = file.contents.get("catalog")
     && !map.is_empty()
@@ -23,6 +23,12 @@ fn empty_disk() -> Disk {
     package_json_root_idx: None,
     package_manager: None,
     pnpm_workspace: None,
+    yarnrc: None,
+    deno_json_files: Vec::new(),
+    lockfile: None,
+    rush_json: None,
+    nx_workspace_json: None,
+    has_nx_json: false,
   }
 }
 
@@ -43,6 +49,22 @@ fn make_catalog_dep_types_returns_pnpm_dep_types_for_yaml() {
   }
 }
 
+#[test]
+fn make_catalog_dep_types_returns_yarn_dep_types_for_yarnrc() {
+  let mut disk = empty_disk();
+  disk.package_manager = Some(PackageManager::Yarn);
+  let yaml = "catalog:\n  react: ^18.0.0\ncatalogs:\n  legacy:\n    react: ^17.0.0\n";
+  disk.yarnrc = parse_yaml_file(yaml.to_string(), PathBuf::from("/test/.yarnrc.yml"));
+
+  let dep_types = make_catalog_dep_types(&disk).expect("yarn catalog discovery must not error");
+  let names: Vec<&str> = dep_types.iter().map(|dt| dt.name.as_str()).collect();
+  assert_eq!(names, vec!["yarnCatalog", "yarnCatalog:legacy"]);
+  for dt in &dep_types {
+    assert_eq!(dt.source, SourceKind::YarnRc);
+    assert!(dt.is_catalog_definition);
+  }
+}
+
 #[test]
 fn make_catalog_dep_types_returns_bun_dep_types_for_root() {
   let mut disk = empty_disk();
@@ -553,3 +575,46 @@ async fn pnpm_catalog_definition_with_no_siblings_still_valid() {
     },
   ]);
 }
+
+#[tokio::test]
+async fn yarn_catalog_definition_with_consumer_marked_valid() {
+  let yaml = "nodeLinker: node-modules\ncatalogs:\n  react18:\n    react: ^18.0.0\n";
+  let ctx = TestBuilder::new()
+    .with_yarn_catalogs(yaml)
+    .with_packages(vec![json!({
+      "name": "pkg-a",
+      "version": "0.0.0",
+      "dependencies": {"react": "catalog:react18"}
+    })])
+    .run()
+    .await;
+  expect(&ctx).to_have_instances(vec![
+    ExpectedInstance {
+      state: InstanceState::valid(IsLocalAndValid),
+      dependency_name: "pkg-a",
+      id: "pkg-a in /version of pkg-a",
+      actual: "0.0.0",
+      expected: Some("0.0.0"),
+      overridden: None,
+      severity: None,
+    },
+    ExpectedInstance {
+      state: InstanceState::valid(IsCatalog),
+      dependency_name: "react",
+      id: "react in /dependencies of pkg-a",
+      actual: "catalog:react18",
+      expected: Some("catalog:react18"),
+      overridden: None,
+      severity: None,
+    },
+    ExpectedInstance {
+      state: InstanceState::valid(IsCatalogDefinition),
+      dependency_name: "react",
+      id: "react in /catalogs/react18 of .yarnrc.yml",
+      actual: "^18.0.0",
+      expected: Some("^18.0.0"),
+      overridden: None,
+      severity: None,
+    },
+  ]);
+}
@@ -1,17 +1,25 @@
 use {
-  crate::{errors::SyncpackError, group_selector::GroupSelector, rcfile::SourceMode},
+  crate::{
+    errors::SyncpackError,
+    group_selector::GroupSelector,
+    rcfile::{SourceMode, overrides::ConfigOverride},
+  },
   clap::{Arg, ArgMatches, Command, builder::ValueParser, crate_description, crate_name, crate_version},
   color_print::cformat,
   itertools::Itertools,
   log::LevelFilter,
-  std::{env, path::PathBuf, str::FromStr},
+  std::{
+    env,
+    path::{Path, PathBuf},
+    str::FromStr,
+  },
 };
 
 #[cfg(test)]
 #[path = "cli_test.rs"]
 mod cli_test;
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub enum Subcommand {
   Fix,
   FixMismatches,
@@ -22,11 +30,22 @@ pub enum Subcommand {
   List,
   ListMismatches,
   Prompt,
+  Report,
   SetSemverRanges,
   Update,
 }
 
-#[derive(Debug)]
+/// What the user asked syncpack to do
+#[derive(Clone, Debug)]
+pub enum Invocation {
+  /// `migrate-config`, which rewrites the config file and so runs before a
+  /// `Context` could be built from it. Only its own options are set.
+  MigrateConfig(Cli),
+  /// Any other subcommand, which is run against every workspace root
+  Run(Cli),
+}
+
+#[derive(Clone, Debug)]
 pub enum SortBy {
   Count,
   Name,
@@ -34,16 +53,27 @@ pub enum SortBy {
 
 pub use syncpack_specifier::update_target::UpdateTarget;
 
-#[derive(Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum ReporterKind {
   Pretty,
   Json,
+  Sarif,
+  Junit,
+  Github,
+  Markdown,
+  Csv,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Cli {
+  /// Path of the file of known issues which `lint` does not report,
+  /// relative to each workspace root
+  pub baseline_path: Option<PathBuf>,
   /// Whether to check formatting instead of fixing it
   pub check: bool,
+  /// Rcfile options replaced by `SYNCPACK_*` environment variables, then by
+  /// `--set <key>=<value>` in the order given
+  pub config_overrides: Vec<ConfigOverride>,
   /// Absolute path to a specific config file to use
   pub config_path: Option<PathBuf>,
   /// The path to the root of the project
@@ -55,9 +85,24 @@ pub struct Cli {
   /// CLI filter combining --dependencies, --dependency-types, --packages,
   /// and --specifier-types options into a single GroupSelector
   pub filters: Option<GroupSelector>,
+  /// The directory syncpack was run from, which is the parent of `cwd`
+  /// while each of `--workspace-roots` is run
+  pub invocation_cwd: PathBuf,
   /// Which severity levels of logging to display
   #[allow(dead_code)]
   pub log_levels: Vec<LevelFilter>,
+  /// Whether `list` should print a table of dependencies by package instead
+  /// of listing them by version group
+  pub matrix: bool,
+  /// How many instances with a severity of `warn` lint allows before it
+  /// fails, unlimited when `None`
+  pub max_warnings: Option<usize>,
+  /// Path to write the `report` to, relative to each workspace root. The
+  /// report is printed to stdout when `None`
+  pub out_path: Option<PathBuf>,
+  /// Absolute path to write the changes `--dry-run` would make to, as a
+  /// patch which `git apply` accepts
+  pub patch_path: Option<PathBuf>,
   /// Whether to indicate that a dependency is a package developed locally
   pub show_hints: bool,
   /// Whether to output ignored dependencies regardless
@@ -82,24 +127,36 @@ pub struct Cli {
   pub target: UpdateTarget,
   /// Output format for fix and format commands
   pub reporter: ReporterKind,
+  /// Whether `lint` should record every issue it finds in the baseline file
+  pub update_baseline: bool,
   /// Whether `update` should drive an interactive picker. Mutually
   /// exclusive with `check` at the clap level.
   pub interactive: bool,
   /// Whether to bypass the on-disk npm registry cache. Only meaningful
   /// for `update`.
   pub no_cache: bool,
+  /// Directories of independent workspaces to run against one after the
+  /// other, relative to `cwd`
+  pub workspace_roots: Vec<String>,
 }
 
 impl Default for Cli {
   fn default() -> Self {
     Self {
+      baseline_path: None,
       check: false,
+      config_overrides: vec![],
       config_path: None,
       cwd: env::current_dir().unwrap_or_default(),
       disable_ansi: false,
       dry_run: false,
       filters: None,
+      invocation_cwd: env::current_dir().unwrap_or_default(),
       log_levels: vec![LevelFilter::Info, LevelFilter::Warn, LevelFilter::Error],
+      matrix: false,
+      max_warnings: None,
+      out_path: None,
+      patch_path: None,
       reporter: ReporterKind::Pretty,
       show_hints: false,
       show_ignored: false,
@@ -110,17 +167,21 @@ impl Default for Cli {
       source_mode: None,
       subcommand: Subcommand::Lint,
       target: UpdateTarget::Latest,
+      update_baseline: false,
       interactive: false,
       no_cache: false,
+      workspace_roots: vec![],
     }
   }
 }
 
-impl Cli {
-  /// Parse all command-line arguments from the user into a `Cli` struct
+impl Invocation {
+  /// Parse all command-line arguments from the user into an `Invocation`
   pub fn parse(args: &[String]) -> Result<Self, SyncpackError> {
     fn from_arg_matches(subcommand: Subcommand, matches: &ArgMatches) -> Cli {
       let cwd = env::current_dir().unwrap();
+      let patch_path = get_patch_path(&subcommand, matches, &cwd);
+      let update_baseline = matches!(subcommand, Subcommand::Lint) && matches.get_flag("update-baseline");
       let dependencies = get_patterns(matches, "dependencies");
       let dependency_types = get_patterns(matches, "dependency-types");
       let packages = get_patterns(matches, "packages");
@@ -138,22 +199,28 @@ impl Cli {
       };
       Cli {
         check: (matches!(&subcommand, Subcommand::Format | Subcommand::Update)) && matches.get_flag("check"),
-        config_path: matches.get_one::<PathBuf>("config").map(|config_path| {
-          if config_path.is_absolute() {
-            config_path.clone()
-          } else {
-            cwd.join(config_path)
-          }
-        }),
+        config_overrides: matches
+          .try_get_many::<ConfigOverride>("set")
+          .ok()
+          .flatten()
+          .map(|overrides| overrides.cloned().collect())
+          .unwrap_or_default(),
+        config_path: get_config_path(matches, &cwd),
         interactive: matches!(&subcommand, Subcommand::Update)
           && matches.try_get_one::<bool>("interactive").ok().flatten().copied().unwrap_or(false),
-        no_cache: matches!(&subcommand, Subcommand::Update)
+        no_cache: matches!(&subcommand, Subcommand::Report | Subcommand::Update)
           && matches.try_get_one::<bool>("no-cache").ok().flatten().copied().unwrap_or(false),
-        cwd,
+        baseline_path: get_baseline_path(&subcommand, matches, update_baseline),
+        cwd: cwd.clone(),
         disable_ansi: matches.get_flag("no-ansi"),
         dry_run: (matches!(&subcommand, Subcommand::Fix | Subcommand::Format | Subcommand::Update)) && matches.get_flag("dry-run"),
         filters,
+        invocation_cwd: cwd,
         log_levels: get_log_levels(matches),
+        matrix: matches!(&subcommand, Subcommand::List) && matches.get_flag("matrix"),
+        max_warnings: matches.try_get_one::<usize>("max-warnings").ok().flatten().copied(),
+        out_path: get_out_path(&subcommand, matches),
+        patch_path,
         reporter: get_reporter(&subcommand, matches),
         show_hints: should_show(matches, "hints"),
         show_ignored: should_show(matches, "ignored"),
@@ -164,6 +231,21 @@ impl Cli {
         source_mode: get_source_mode(matches),
         subcommand,
         target: get_target(matches),
+        update_baseline,
+        workspace_roots: get_patterns(matches, "workspace-roots"),
+      }
+    }
+
+    fn from_migrate_config(matches: &ArgMatches) -> Cli {
+      let cwd = env::current_dir().unwrap();
+      Cli {
+        config_path: get_config_path(matches, &cwd),
+        cwd: cwd.clone(),
+        disable_ansi: matches.get_flag("no-ansi"),
+        dry_run: matches.get_flag("dry-run"),
+        invocation_cwd: cwd,
+        log_levels: get_log_levels(matches),
+        ..Default::default()
       }
     }
 
@@ -181,17 +263,19 @@ impl Cli {
         matches
           .subcommand()
           .and_then(|subcommand| match subcommand {
-            ("fix", matches) => Some(from_arg_matches(Subcommand::Fix, matches)),
-            ("fix-mismatches", _) => Some(from_deprecated(Subcommand::FixMismatches)),
-            ("format", matches) => Some(from_arg_matches(Subcommand::Format, matches)),
-            ("json", matches) => Some(from_arg_matches(Subcommand::Json, matches)),
-            ("lint", matches) => Some(from_arg_matches(Subcommand::Lint, matches)),
-            ("lint-semver-ranges", _) => Some(from_deprecated(Subcommand::LintSemverRanges)),
-            ("list", matches) => Some(from_arg_matches(Subcommand::List, matches)),
-            ("list-mismatches", _) => Some(from_deprecated(Subcommand::ListMismatches)),
-            ("prompt", _) => Some(from_deprecated(Subcommand::Prompt)),
-            ("set-semver-ranges", _) => Some(from_deprecated(Subcommand::SetSemverRanges)),
-            ("update", matches) => Some(from_arg_matches(Subcommand::Update, matches)),
+            ("fix", matches) => Some(Invocation::Run(from_arg_matches(Subcommand::Fix, matches))),
+            ("fix-mismatches", _) => Some(Invocation::Run(from_deprecated(Subcommand::FixMismatches))),
+            ("format", matches) => Some(Invocation::Run(from_arg_matches(Subcommand::Format, matches))),
+            ("json", matches) => Some(Invocation::Run(from_arg_matches(Subcommand::Json, matches))),
+            ("lint", matches) => Some(Invocation::Run(from_arg_matches(Subcommand::Lint, matches))),
+            ("lint-semver-ranges", _) => Some(Invocation::Run(from_deprecated(Subcommand::LintSemverRanges))),
+            ("list", matches) => Some(Invocation::Run(from_arg_matches(Subcommand::List, matches))),
+            ("list-mismatches", _) => Some(Invocation::Run(from_deprecated(Subcommand::ListMismatches))),
+            ("migrate-config", matches) => Some(Invocation::MigrateConfig(from_migrate_config(matches))),
+            ("prompt", _) => Some(Invocation::Run(from_deprecated(Subcommand::Prompt))),
+            ("report", matches) => Some(Invocation::Run(from_arg_matches(Subcommand::Report, matches))),
+            ("set-semver-ranges", _) => Some(Invocation::Run(from_deprecated(Subcommand::SetSemverRanges))),
+            ("update", matches) => Some(Invocation::Run(from_arg_matches(Subcommand::Update, matches))),
             _ => None,
           })
           .ok_or(SyncpackError::NoSubcommand)
@@ -199,6 +283,15 @@ impl Cli {
   }
 }
 
+impl Cli {
+  /// Apply the `SYNCPACK_*` variables of the environment before any
+  /// `--set` overrides, so the command line wins
+  pub fn with_env_overrides(mut self, vars: impl Iterator<Item = (String, String)>) -> Self {
+    self.config_overrides.splice(0..0, ConfigOverride::from_env(vars));
+    self
+  }
+}
+
 fn create() -> Command {
   Command::new(crate_name!())
     .about(crate_description!())
@@ -207,16 +300,22 @@ fn create() -> Command {
       Command::new("lint")
         .about("Lint all versions and ranges and exit with 0 or 1 based on whether all files match your Syncpack configuration file")
         .after_long_help(additional_help())
+        .arg(baseline_option("lint"))
         .arg(config_option("lint"))
         .arg(dependencies_option("lint"))
         .arg(dependency_types_option("lint"))
         .arg(log_levels_option("lint"))
+        .arg(max_warnings_option("lint"))
         .arg(no_ansi_option("lint"))
+        .arg(reporter_option("lint"))
+        .arg(set_option("lint"))
         .arg(show_option_versions("lint"))
         .arg(sort_option("lint"))
         .arg(source_option("lint"))
         .arg(source_mode_option("lint"))
-        .arg(specifier_types_option("lint")),
+        .arg(specifier_types_option("lint"))
+        .arg(update_baseline_option("lint"))
+        .arg(workspace_roots_option("lint")),
     )
     .subcommand(
       Command::new("fix")
@@ -228,12 +327,15 @@ fn create() -> Command {
         .arg(dry_run_option("fix"))
         .arg(log_levels_option("fix"))
         .arg(no_ansi_option("fix"))
+        .arg(patch_option("fix"))
         .arg(reporter_option("fix"))
+        .arg(set_option("fix"))
         .arg(show_option_versions("fix"))
         .arg(sort_option("fix"))
         .arg(source_option("fix"))
         .arg(source_mode_option("fix"))
-        .arg(specifier_types_option("fix")),
+        .arg(specifier_types_option("fix"))
+        .arg(workspace_roots_option("fix")),
     )
     .subcommand(
       Command::new("format")
@@ -249,9 +351,12 @@ fn create() -> Command {
         .arg(dry_run_option("format"))
         .arg(log_levels_option("format"))
         .arg(no_ansi_option("format"))
+        .arg(patch_option("format"))
         .arg(reporter_option("format"))
+        .arg(set_option("format"))
         .arg(source_option("format"))
-        .arg(source_mode_option("format")),
+        .arg(source_mode_option("format"))
+        .arg(workspace_roots_option("format")),
     )
     .subcommand(
       Command::new("update")
@@ -264,18 +369,7 @@ fn create() -> Command {
             .conflicts_with("interactive")
             .action(clap::ArgAction::SetTrue),
         )
-        .arg(
-          Arg::new("no-cache")
-            .long("no-cache")
-            .long_help(cformat!(
-              r#"Bypass the on-disk cache of npm registry responses
-
-By default Syncpack caches registry responses in the system temp
-directory for 30 minutes to avoid repeat network calls. Pass this flag
-to skip both reading and writing the cache."#
-            ))
-            .action(clap::ArgAction::SetTrue),
-        )
+        .arg(no_cache_option())
         .arg(
           Arg::new("interactive")
             .long("interactive")
@@ -298,10 +392,13 @@ to skip both reading and writing the cache."#
         .arg(dry_run_option("update"))
         .arg(log_levels_option("update"))
         .arg(no_ansi_option("update"))
+        .arg(reporter_option("update"))
+        .arg(set_option("update"))
         .arg(source_option("update"))
         .arg(source_mode_option("update"))
         .arg(specifier_types_option("update"))
-        .arg(target_option("update")),
+        .arg(target_option("update"))
+        .arg(workspace_roots_option("update")),
     )
     .subcommand(
       Command::new("list")
@@ -311,12 +408,36 @@ to skip both reading and writing the cache."#
         .arg(dependencies_option("list"))
         .arg(dependency_types_option("list"))
         .arg(log_levels_option("list"))
+        .arg(matrix_option("list"))
         .arg(no_ansi_option("list"))
+        .arg(packages_option("list"))
+        .arg(reporter_option("list"))
+        .arg(set_option("list"))
         .arg(show_option_list("list"))
         .arg(sort_option("list"))
         .arg(source_option("list"))
         .arg(source_mode_option("list"))
-        .arg(specifier_types_option("list")),
+        .arg(specifier_types_option("list"))
+        .arg(workspace_roots_option("list")),
+    )
+    .subcommand(
+      Command::new("report")
+        .about("Write a self-contained HTML report of every dependency, its status and available updates")
+        .after_long_help(additional_help())
+        .arg(config_option("report"))
+        .arg(dependencies_option("report"))
+        .arg(dependency_types_option("report"))
+        .arg(report_format_option("report"))
+        .arg(log_levels_option("report"))
+        .arg(no_ansi_option("report"))
+        .arg(no_cache_option())
+        .arg(out_option("report"))
+        .arg(set_option("report"))
+        .arg(source_option("report"))
+        .arg(source_mode_option("report"))
+        .arg(specifier_types_option("report"))
+        .arg(target_option("report"))
+        .arg(workspace_roots_option("report")),
     )
     .subcommand(
       Command::new("json")
@@ -327,10 +448,21 @@ to skip both reading and writing the cache."#
         .arg(dependency_types_option("json"))
         .arg(log_levels_option("json"))
         .arg(no_ansi_option("json"))
+        .arg(set_option("json"))
         .arg(sort_option("json"))
         .arg(source_option("json"))
         .arg(source_mode_option("json"))
-        .arg(specifier_types_option("json")),
+        .arg(specifier_types_option("json"))
+        .arg(workspace_roots_option("json")),
+    )
+    .subcommand(
+      Command::new("migrate-config")
+        .about("Rewrite deprecated properties of your Syncpack configuration file into their current equivalents")
+        .after_long_help(additional_help())
+        .arg(config_option("migrate-config"))
+        .arg(dry_run_option("migrate-config"))
+        .arg(log_levels_option("migrate-config"))
+        .arg(no_ansi_option("migrate-config")),
     )
     .subcommand(
       Command::new("list-mismatches")
@@ -369,6 +501,43 @@ to skip both reading and writing the cache."#
     )
 }
 
+fn set_option(command: &str) -> Arg {
+  let short_help = "Override an option from your config file for this run only";
+  Arg::new("set")
+    .long("set")
+    .help(short_help)
+    .long_help(cformat!(
+      r#"{short_help}
+
+Overrides are applied after your config file is read. Every option can also
+be set with an environment variable, which <blue>--set</> takes precedence over.
+
+<bold><underline>Options:</underline></bold>
+<blue>checkLockfile</>          SYNCPACK_CHECK_LOCKFILE
+<blue>formatBugs</>             SYNCPACK_FORMAT_BUGS
+<blue>formatRepository</>       SYNCPACK_FORMAT_REPOSITORY
+<blue>indent</>                 SYNCPACK_INDENT
+<blue>localPathPolicy</>        SYNCPACK_LOCAL_PATH_POLICY
+<blue>maxConcurrentRequests</>  SYNCPACK_MAX_CONCURRENT_REQUESTS
+<blue>minimumReleaseAge</>      SYNCPACK_MINIMUM_RELEASE_AGE
+<blue>respectGitignore</>       SYNCPACK_RESPECT_GITIGNORE
+<blue>sortPackages</>           SYNCPACK_SORT_PACKAGES
+<blue>sourceMode</>             SYNCPACK_SOURCE_MODE
+<blue>strict</>                 SYNCPACK_STRICT
+
+<bold><underline>Examples:</underline></bold>
+<dim>Enable strict mode in CI</dim>
+<dim>$</dim> <blue><bold>syncpack {command}</bold> --set strict=true</>
+<dim>Override more than one option</dim>
+<dim>$</dim> <blue><bold>syncpack {command}</bold> --set strict=true --set minimumReleaseAge=0</>
+<dim>Use an environment variable instead</dim>
+<dim>$</dim> <blue>SYNCPACK_STRICT=true <bold>syncpack {command}</bold></>"#
+    ))
+    .action(clap::ArgAction::Append)
+    .value_parser(ValueParser::new(ConfigOverride::from_cli))
+    .value_name("key=value")
+}
+
 fn config_option(_command: &str) -> Arg {
   Arg::new("config")
     .long("config")
@@ -428,6 +597,30 @@ interpret it.
     .value_name("dependency-name-pattern")
 }
 
+fn packages_option(command: &str) -> Arg {
+  let short_help = "Only include packages whose name matches this glob pattern";
+  Arg::new("packages")
+    .long("packages")
+    .help(short_help)
+    .long_help(cformat!(
+      r#"{short_help}
+
+<bold><underline>Important:</underline></bold>
+You <underline>must</> add quotes around your filter so your shell doesn't
+interpret it.
+
+<bold><underline>Examples:</underline></bold>
+<dim>Exact match for "web"</>
+<dim>$</dim> <blue><bold>syncpack {command}</bold> --packages 'web'</>
+<dim>All packages under the @my-repo scope</>
+<dim>$</dim> <blue><bold>syncpack {command}</bold> --packages '@my-repo/**'</>
+<dim>Every package except "docs"</>
+<dim>$</dim> <blue><bold>syncpack {command}</bold> --packages '!docs'</>"#
+    ))
+    .action(clap::ArgAction::Append)
+    .value_name("package-name-pattern")
+}
+
 fn show_option_versions(command: &str) -> Arg {
   let short_help = "Control what information is displayed in terminal output";
   Arg::new("show")
@@ -579,13 +772,18 @@ fn dependency_types_option(command: &str) -> Arg {
       r#"{short_help}
 
 <bold><underline>Built-in Types:</underline></bold>
-<blue>dev</>            devDependencies
-<blue>local</>          version
-<blue>overrides</>      overrides
-<blue>peer</>           peerDependencies
-<blue>pnpmOverrides</>  overrides (in pnpm-workspace.yaml)
-<blue>prod</>           dependencies
-<blue>resolutions</>    resolutions
+<blue>deno</>                 imports (in deno.json)
+<blue>dev</>                  devDependencies
+<blue>engines</>              engines
+<blue>local</>                version
+<blue>overrides</>            overrides
+<blue>packageManager</>       packageManager
+<blue>peer</>                 peerDependencies
+<blue>pnpmOverrides</>        overrides (in pnpm-workspace.yaml)
+<blue>pnpmOverridesLegacy</>  pnpm.overrides
+<blue>prod</>                 dependencies
+<blue>resolutions</>          resolutions
+<blue>volta</>                volta
 
 <bold><underline>Custom Types:</underline></bold>
 See <blue>https://syncpack.dev/config/custom-types/</>
@@ -602,6 +800,44 @@ See <blue>https://syncpack.dev/config/custom-types/</>
 
 fn reporter_option(command: &str) -> Arg {
   let short_help = "Set the output format for the command";
+  let reporters: &[&str] = match command {
+    "lint" => &["pretty", "json", "sarif", "junit", "github", "markdown"],
+    "format" => &["pretty", "json", "sarif", "junit", "github", "markdown"],
+    "list" => &["pretty", "json", "csv"],
+    "update" => &["pretty", "json", "junit", "github", "markdown"],
+    _ => &["pretty", "json", "junit", "github"],
+  };
+  let examples = reporters
+    .iter()
+    .filter_map(|reporter| match *reporter {
+      "json" => Some(cformat!(
+        r#"<dim>Output as newline-delimited JSON</dim>
+<dim>$</dim> <blue><bold>syncpack {command}</bold> --reporter json</>"#
+      )),
+      "sarif" => Some(cformat!(
+        r#"<dim>Output a SARIF 2.1.0 log for code scanning</dim>
+<dim>$</dim> <blue><bold>syncpack {command}</bold> --reporter sarif > syncpack.sarif</>"#
+      )),
+      "junit" => Some(cformat!(
+        r#"<dim>Output a JUnit XML report for CI test results</dim>
+<dim>$</dim> <blue><bold>syncpack {command}</bold> --reporter junit > syncpack.xml</>"#
+      )),
+      "github" => Some(cformat!(
+        r#"<dim>Annotate files in GitHub Actions</dim>
+<dim>$</dim> <blue><bold>syncpack {command}</bold> --reporter github</>"#
+      )),
+      "markdown" => Some(cformat!(
+        r#"<dim>Add a summary to a GitHub Actions job</dim>
+<dim>$</dim> <blue><bold>syncpack {command}</bold> --reporter markdown >> $GITHUB_STEP_SUMMARY</>"#
+      )),
+      "csv" => Some(cformat!(
+        r#"<dim>Output the table of --matrix as CSV for a spreadsheet</dim>
+<dim>$</dim> <blue><bold>syncpack {command}</bold> --reporter csv > syncpack.csv</>"#
+      )),
+      _ => None,
+    })
+    .collect::<Vec<_>>()
+    .join("\n");
   Arg::new("reporter")
     .long("reporter")
     .help(short_help)
@@ -609,10 +845,9 @@ fn reporter_option(command: &str) -> Arg {
       r#"{short_help}
 
 <bold><underline>Examples:</underline></bold>
-<dim>Output as newline-delimited JSON</dim>
-<dim>$</dim> <blue><bold>syncpack {command}</bold> --reporter json</>"#
+{examples}"#
     ))
-    .value_parser(["pretty", "json"])
+    .value_parser(reporters.to_vec())
     .value_name("reporter-name")
     .default_value("pretty")
 }
@@ -631,6 +866,25 @@ fn dry_run_option(command: &str) -> Arg {
     .action(clap::ArgAction::SetTrue)
 }
 
+fn patch_option(command: &str) -> Arg {
+  let short_help = "Write the changes --dry-run would make to a patch file";
+  Arg::new("patch")
+    .long("patch")
+    .help(short_help)
+    .long_help(cformat!(
+      r#"{short_help}
+
+The patch can be applied with git apply.
+
+<bold><underline>Examples:</underline></bold>
+<dim>$</dim> <blue><bold>syncpack {command}</bold> --dry-run --patch syncpack.patch</>
+<dim>$</dim> <blue><bold>git apply</bold> syncpack.patch</>"#
+    ))
+    .requires("dry-run")
+    .value_parser(clap::value_parser!(PathBuf))
+    .value_name("file-path")
+}
+
 fn log_levels_option(command: &str) -> Arg {
   let short_help = "Control how detailed the log output should be";
   Arg::new("log-levels")
@@ -653,6 +907,89 @@ fn log_levels_option(command: &str) -> Arg {
     .default_value("error,warn,info")
 }
 
+fn baseline_option(command: &str) -> Arg {
+  let short_help = "Only report issues which are not in this baseline file";
+  Arg::new("baseline")
+    .long("baseline")
+    .help(short_help)
+    .long_help(cformat!(
+      r#"{short_help}
+
+Issues in the file are tolerated, so that only new ones fail. Issues in the
+file which have since been fixed are reported as warnings, until the file is
+updated with --update-baseline. The path is relative to each workspace root.
+
+<bold><underline>Examples:</underline></bold>
+<dim>$</dim> <blue><bold>syncpack {command}</bold> --baseline .syncpack-baseline.json</>"#
+    ))
+    .value_parser(clap::value_parser!(PathBuf))
+    .value_name("file-path")
+}
+
+fn update_baseline_option(command: &str) -> Arg {
+  let short_help = "Record every issue found in the baseline file";
+  Arg::new("update-baseline")
+    .long("update-baseline")
+    .help(short_help)
+    .long_help(cformat!(
+      r#"{short_help}
+
+Writes to the file set by --baseline, or .syncpack-baseline.json when it is
+not set, then exits with 0. When filters are set, the issues they match are
+added and every other entry in the file is kept.
+
+<bold><underline>Examples:</underline></bold>
+<dim>Record every issue in .syncpack-baseline.json</dim>
+<dim>$</dim> <blue><bold>syncpack {command}</bold> --update-baseline</>
+<dim>Record every issue in a file of your choosing</dim>
+<dim>$</dim> <blue><bold>syncpack {command}</bold> --baseline baseline.json --update-baseline</>
+<dim>Add the issues with React to the baseline</dim>
+<dim>$</dim> <blue><bold>syncpack {command}</bold> --update-baseline --dependencies 'react'</>"#
+    ))
+    .action(clap::ArgAction::SetTrue)
+}
+
+fn max_warnings_option(command: &str) -> Arg {
+  let short_help = "Exit with code 2 when there are more warnings than this";
+  Arg::new("max-warnings")
+    .long("max-warnings")
+    .help(short_help)
+    .long_help(cformat!(
+      r#"{short_help}
+
+Instances with a severity of "warn" do not fail by default. Errors always
+exit with code 1, whatever this is set to.
+
+<bold><underline>Examples:</underline></bold>
+<dim>Fail on any warning</dim>
+<dim>$</dim> <blue><bold>syncpack {command}</bold> --max-warnings 0</>
+<dim>Allow the warnings which exist today, then lower it over time</dim>
+<dim>$</dim> <blue><bold>syncpack {command}</bold> --max-warnings 25</>"#
+    ))
+    .value_parser(clap::value_parser!(usize))
+    .value_name("count")
+}
+
+fn matrix_option(command: &str) -> Arg {
+  let short_help = "Print a table of dependencies by package";
+  Arg::new("matrix")
+    .long("matrix")
+    .help(short_help)
+    .long_help(cformat!(
+      r#"{short_help}
+
+Each row is a dependency and each column is a package, with the specifiers the
+package uses in each cell. Mismatched cells are coloured by their status, and
+packages which use the same specifiers for every dependency share a column.
+
+<bold><underline>Examples:</underline></bold>
+<dim>$</dim> <blue><bold>syncpack {command}</bold> --matrix</>
+<dim>Compare the versions of React used by each app</dim>
+<dim>$</dim> <blue><bold>syncpack {command}</bold> --matrix --dependencies 'react**' --packages 'app-*'</>"#
+    ))
+    .action(clap::ArgAction::SetTrue)
+}
+
 fn no_ansi_option(command: &str) -> Arg {
   let short_help = "Disable ANSI colored output and terminal hyperlinks";
   Arg::new("no-ansi")
@@ -687,7 +1024,10 @@ Patterns are discovered in the following order, first one wins:
 4. <blue>.workspaces</> property of package.json (npm and yarn)
 5. <blue>.packages</> property of pnpm-workspace.yaml
 6. <blue>.packages</> property of lerna.json
-7. Default to <blue>["package.json","packages/*/package.json"]</>
+7. <blue>.bolt.workspaces</> property of package.json (bolt)
+8. <blue>.projects[].projectFolder</> properties of rush.json
+9. <blue>.projects</> property of workspace.json, or every project.json (nx)
+10. Default to <blue>["package.json","packages/*/package.json"]</>
 
 Pass <blue>--source-mode extend</> to *append* your patterns to the discovered
 ones instead of replacing them."#
@@ -709,7 +1049,7 @@ fn source_mode_option(command: &str) -> Arg {
 - <blue>replace</> (default): your <blue>--source</> patterns replace workspace
   discovery entirely.
 - <blue>extend</>: your <blue>--source</> patterns are appended to the
-  discovered patterns (npm/yarn/pnpm/lerna).
+  discovered patterns (npm/yarn/pnpm/lerna/bolt/rush/nx).
 
 <bold><underline>Example:</underline></bold>
 <dim>$</dim> <blue><bold>syncpack {command}</bold> --source-mode extend --source 'extra/package.json'</>"#
@@ -719,6 +1059,74 @@ fn source_mode_option(command: &str) -> Arg {
     .value_name("mode")
 }
 
+fn workspace_roots_option(command: &str) -> Arg {
+  let short_help = "Directories of independent workspaces to run against one after the other";
+  Arg::new("workspace-roots")
+    .long("workspace-roots")
+    .help(short_help)
+    .long_help(cformat!(
+      r#"{short_help}
+
+Each root is read as its own project, with its own config file, package
+manager and lockfile, and the output of each is headed by its directory.
+
+<bold><underline>Examples:</underline></bold>
+<dim>$</dim> <blue><bold>syncpack {command}</bold> --workspace-roots 'apps/web,apps/mobile'</>
+<dim>$</dim> <blue><bold>syncpack {command}</bold> --workspace-roots apps/web --workspace-roots apps/mobile</>"#
+    ))
+    .action(clap::ArgAction::Append)
+    .value_delimiter(',')
+    .value_name("directory")
+}
+
+fn no_cache_option() -> Arg {
+  Arg::new("no-cache")
+    .long("no-cache")
+    .long_help(cformat!(
+      r#"Bypass the on-disk cache of npm registry responses
+
+By default Syncpack caches registry responses in the system temp
+directory for 30 minutes to avoid repeat network calls. Pass this flag
+to skip both reading and writing the cache."#
+    ))
+    .action(clap::ArgAction::SetTrue)
+}
+
+fn report_format_option(command: &str) -> Arg {
+  let short_help = "The file format of the report";
+  Arg::new("format")
+    .long("format")
+    .help(short_help)
+    .long_help(cformat!(
+      r#"{short_help}
+
+<bold><underline>Examples:</underline></bold>
+<dim>$</dim> <blue><bold>syncpack {command}</bold> --format html --out report.html</>"#
+    ))
+    .action(clap::ArgAction::Set)
+    .value_parser(["html"])
+    .value_name("format")
+    .default_value("html")
+}
+
+fn out_option(command: &str) -> Arg {
+  let short_help = "Write the report to this file instead of stdout";
+  Arg::new("out")
+    .long("out")
+    .help(short_help)
+    .long_help(cformat!(
+      r#"{short_help}
+
+The path is relative to each workspace root.
+
+<bold><underline>Examples:</underline></bold>
+<dim>$</dim> <blue><bold>syncpack {command}</bold> --out report.html</>
+<dim>$</dim> <blue><bold>syncpack {command}</bold> > report.html</>"#
+    ))
+    .value_parser(clap::value_parser!(PathBuf))
+    .value_name("file-path")
+}
+
 fn target_option(command: &str) -> Arg {
   let short_help = "Limit updates to only those within the semver portion";
   Arg::new("target")
@@ -787,9 +1195,17 @@ fn get_source_mode(matches: &ArgMatches) -> Option<SourceMode> {
 }
 
 fn get_reporter(subcommand: &Subcommand, matches: &ArgMatches) -> ReporterKind {
-  if matches!(subcommand, Subcommand::Fix | Subcommand::Format) {
+  if matches!(
+    subcommand,
+    Subcommand::Fix | Subcommand::Format | Subcommand::Lint | Subcommand::List | Subcommand::Update
+  ) {
     match matches.get_one::<String>("reporter").map(|s| s.as_str()) {
       Some("json") => ReporterKind::Json,
+      Some("sarif") => ReporterKind::Sarif,
+      Some("junit") => ReporterKind::Junit,
+      Some("github") => ReporterKind::Github,
+      Some("markdown") => ReporterKind::Markdown,
+      Some("csv") => ReporterKind::Csv,
       _ => ReporterKind::Pretty,
     }
   } else {
@@ -797,6 +1213,40 @@ fn get_reporter(subcommand: &Subcommand, matches: &ArgMatches) -> ReporterKind {
   }
 }
 
+fn get_config_path(matches: &ArgMatches, cwd: &Path) -> Option<PathBuf> {
+  matches.get_one::<PathBuf>("config").map(|config_path| {
+    if config_path.is_absolute() {
+      config_path.clone()
+    } else {
+      cwd.join(config_path)
+    }
+  })
+}
+
+fn get_patch_path(subcommand: &Subcommand, matches: &ArgMatches, cwd: &Path) -> Option<PathBuf> {
+  if !matches!(subcommand, Subcommand::Fix | Subcommand::Format) {
+    return None;
+  }
+  matches.get_one::<PathBuf>("patch").map(|patch_path| cwd.join(patch_path))
+}
+
+fn get_baseline_path(subcommand: &Subcommand, matches: &ArgMatches, update_baseline: bool) -> Option<PathBuf> {
+  if !matches!(subcommand, Subcommand::Lint) {
+    return None;
+  }
+  matches
+    .get_one::<PathBuf>("baseline")
+    .cloned()
+    .or_else(|| update_baseline.then(|| PathBuf::from(".syncpack-baseline.json")))
+}
+
+fn get_out_path(subcommand: &Subcommand, matches: &ArgMatches) -> Option<PathBuf> {
+  if !matches!(subcommand, Subcommand::Report) {
+    return None;
+  }
+  matches.get_one::<PathBuf>("out").cloned()
+}
+
 fn get_target(matches: &ArgMatches) -> UpdateTarget {
   matches
     .try_get_one::<String>("target")
@@ -1,8 +1,18 @@
 use {
-  crate::cli::Cli,
+  crate::{
+    cli::{Cli, Invocation},
+    errors::SyncpackError,
+  },
   std::{env, fs, path::PathBuf},
 };
 
+fn parse(args: &[String]) -> Result<Cli, SyncpackError> {
+  Invocation::parse(args).map(|invocation| match invocation {
+    Invocation::Run(cli) => cli,
+    Invocation::MigrateConfig(_) => panic!("expected a subcommand which runs against workspace roots"),
+  })
+}
+
 fn write_temp_config(name: &str) -> PathBuf {
   let path = env::temp_dir().join(format!("syncpack-cli-test-{}-{name}", std::process::id()));
   fs::write(&path, "{}").expect("write temp config");
@@ -17,7 +27,7 @@ fn config_flag_accepts_existing_file() {
     .map(|s| s.to_string())
     .collect::<Vec<_>>();
 
-  let cli = Cli::parse(&args).expect("--config should parse");
+  let cli = parse(&args).expect("--config should parse");
   let config_path = cli.config_path.expect("config_path should be Some");
   assert!(config_path.is_absolute());
   assert_eq!(config_path, temp);
@@ -32,7 +42,7 @@ fn config_flag_rejects_missing_file_with_clap_error() {
     .map(|s| s.to_string())
     .collect::<Vec<_>>();
 
-  let err = Cli::parse(&args).expect_err("missing file must be rejected");
+  let err = parse(&args).expect_err("missing file must be rejected");
   let msg = format!("{err:?}");
   assert!(msg.contains("file not found"), "expected clap-style error, got: {msg}");
 }
@@ -45,13 +55,13 @@ fn config_flag_rejects_directory_with_clap_error() {
     .map(|s| s.to_string())
     .collect::<Vec<_>>();
 
-  let err = Cli::parse(&args).expect_err("directory must be rejected");
+  let err = parse(&args).expect_err("directory must be rejected");
   let msg = format!("{err:?}");
   assert!(msg.contains("not a file"), "expected clap-style error, got: {msg}");
 }
 
 mod source_mode {
-  use crate::{cli::Cli, rcfile::SourceMode};
+  use {super::parse, crate::rcfile::SourceMode};
 
   fn args(extra: &[&str]) -> Vec<String> {
     let mut v = vec!["syncpack".to_string(), "lint".to_string()];
@@ -61,25 +71,25 @@ mod source_mode {
 
   #[test]
   fn defaults_to_none_when_omitted() {
-    let cli = Cli::parse(&args(&[])).expect("default lint should parse");
+    let cli = parse(&args(&[])).expect("default lint should parse");
     assert_eq!(cli.source_mode, None);
   }
 
   #[test]
   fn parses_extend() {
-    let cli = Cli::parse(&args(&["--source-mode", "extend"])).expect("--source-mode extend should parse");
+    let cli = parse(&args(&["--source-mode", "extend"])).expect("--source-mode extend should parse");
     assert_eq!(cli.source_mode, Some(SourceMode::Extend));
   }
 
   #[test]
   fn parses_replace() {
-    let cli = Cli::parse(&args(&["--source-mode", "replace"])).expect("--source-mode replace should parse");
+    let cli = parse(&args(&["--source-mode", "replace"])).expect("--source-mode replace should parse");
     assert_eq!(cli.source_mode, Some(SourceMode::Replace));
   }
 
   #[test]
   fn rejects_invalid_value() {
-    let err = Cli::parse(&args(&["--source-mode", "merge"])).expect_err("invalid value must fail");
+    let err = parse(&args(&["--source-mode", "merge"])).expect_err("invalid value must fail");
     let msg = format!("{err:?}");
     assert!(
       msg.contains("merge") || msg.contains("invalid value"),
@@ -87,3 +97,87 @@ mod source_mode {
     );
   }
 }
+
+#[test]
+fn set_flag_collects_overrides_in_order() {
+  let args = ["syncpack", "lint", "--set", "strict=true", "--set", "indent=    "]
+    .iter()
+    .map(|s| s.to_string())
+    .collect::<Vec<_>>();
+
+  let cli = parse(&args).expect("--set should parse");
+  let overrides = cli
+    .config_overrides
+    .iter()
+    .map(|o| (o.key.as_str(), o.value.as_str()))
+    .collect::<Vec<_>>();
+  assert_eq!(overrides, vec![("strict", "true"), ("indent", "    ")]);
+}
+
+#[test]
+fn env_overrides_are_applied_before_set_flags() {
+  let args = ["syncpack", "lint", "--set", "strict=true"]
+    .iter()
+    .map(|s| s.to_string())
+    .collect::<Vec<_>>();
+  let vars = [("SYNCPACK_STRICT", "false"), ("HOME", "/home/user")]
+    .into_iter()
+    .map(|(k, v)| (k.to_string(), v.to_string()));
+
+  let cli = parse(&args).expect("--set should parse").with_env_overrides(vars);
+  let overrides = cli
+    .config_overrides
+    .iter()
+    .map(|o| (o.key.as_str(), o.value.as_str(), o.origin.to_string()))
+    .collect::<Vec<_>>();
+  assert_eq!(
+    overrides,
+    vec![
+      ("strict", "false", "SYNCPACK_STRICT".to_string()),
+      ("strict", "true", "--set".to_string()),
+    ]
+  );
+}
+
+#[test]
+fn set_flag_rejects_unknown_option_with_clap_error() {
+  let args = ["syncpack", "lint", "--set", "sortFirst=name"]
+    .iter()
+    .map(|s| s.to_string())
+    .collect::<Vec<_>>();
+
+  let err = parse(&args).expect_err("unknown option must be rejected");
+  let msg = format!("{err:?}");
+  assert!(msg.contains("cannot be overridden"), "expected clap-style error, got: {msg}");
+}
+
+#[test]
+fn workspace_roots_flag_accepts_lists_and_repeats() {
+  let args = [
+    "syncpack",
+    "fix",
+    "--workspace-roots",
+    "apps/web,apps/mobile",
+    "--workspace-roots",
+    "tools",
+  ]
+  .iter()
+  .map(|s| s.to_string())
+  .collect::<Vec<_>>();
+
+  let cli = parse(&args).expect("--workspace-roots should parse");
+  assert_eq!(cli.workspace_roots, vec!["apps/web", "apps/mobile", "tools"]);
+}
+
+#[test]
+fn migrate_config_is_parsed_apart_from_the_subcommands_run_against_workspace_roots() {
+  let args = ["syncpack", "migrate-config", "--dry-run"]
+    .iter()
+    .map(|s| s.to_string())
+    .collect::<Vec<_>>();
+
+  let Invocation::MigrateConfig(cli) = Invocation::parse(&args).expect("migrate-config should parse") else {
+    panic!("expected Invocation::MigrateConfig");
+  };
+  assert!(cli.dry_run);
+}
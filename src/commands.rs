@@ -1,3 +1,5 @@
+/// Tolerate known lint issues so that only new ones fail
+pub mod baseline;
 /// Write fixes to disk
 pub mod fix;
 
@@ -5,8 +7,14 @@ pub mod fix;
 pub mod fix_mismatches;
 /// Lint and fix package.json formatting
 pub mod format;
+/// Print GitHub Actions workflow commands
+pub mod github;
+/// Build self-contained HTML reports
+pub mod html;
 /// Output all dependencies as flattened JSON objects
 pub mod json;
+/// Build JUnit XML reports for CI systems
+pub mod junit;
 /// Write lint messages to the UI
 pub mod lint;
 /// DEPRECATED: Use lint instead
@@ -15,10 +23,22 @@ pub mod lint_semver_ranges;
 pub mod list;
 /// DEPRECATED: Use lint instead
 pub mod list_mismatches;
+/// Build markdown summaries for pull request comments and CI job summaries
+pub mod markdown;
+/// Tabulate which version of each dependency every package uses
+pub mod matrix;
+/// Rewrite deprecated config into the current format
+pub mod migrate_config;
+/// Preview the changes --dry-run keeps from being written to disk
+pub mod patch;
 /// DEPRECATED: Not yet implemented in v14
 pub mod prompt;
-/// Reporter traits for fix and format output
+/// Write an HTML report of every dependency for reviewing them over time
+pub mod report;
+/// Reporter traits for fix, format, lint and update output
 pub mod reporter;
+/// Build SARIF 2.1.0 logs for code-scanning dashboards
+pub mod sarif;
 /// DEPRECATED: Use fix instead
 pub mod set_semver_ranges;
 /// A shared module with methods for printing messages to the console
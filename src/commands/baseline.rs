@@ -0,0 +1,115 @@
+#[cfg(test)]
+#[path = "baseline_test.rs"]
+mod baseline_test;
+
+use {
+  crate::{commands::reporter, context::Context, disk::DiskIo, errors::SyncpackError, instance::Instance},
+  serde::{Deserialize, Serialize},
+  std::{collections::BTreeSet, path::PathBuf},
+};
+
+/// An issue which is known about, identified by what stays the same between
+/// runs. Sorting by these fields keeps the file stable under version control.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BaselineEntry {
+  /// Path of the file containing the instance, relative to the project root
+  pub package: String,
+  pub dependency_type: String,
+  pub dependency: String,
+  pub status_code: String,
+  /// The specifier the instance had when it was recorded
+  pub actual: String,
+}
+
+impl BaselineEntry {
+  pub fn new(ctx: &Context, instance: &Instance) -> Self {
+    let source = &ctx.sources.all[instance.source_idx().0];
+    Self {
+      package: reporter::get_source_file_path(ctx, source)
+        .map(|file_path| reporter::to_uri(&ctx.disk.cwd, &file_path))
+        .unwrap_or_else(|| source.name().to_string()),
+      dependency_type: instance.descriptor.dependency_type.name.clone(),
+      dependency: instance.descriptor.name.clone(),
+      status_code: instance.state.borrow().get_name(),
+      actual: instance.descriptor.specifier.get_raw().to_string(),
+    }
+  }
+}
+
+/// The issues `lint --baseline` tolerates, so that only new ones fail
+pub struct Baseline {
+  /// Absolute path of the baseline file
+  pub path: PathBuf,
+  /// What the file contained when it was read
+  pub entries: BTreeSet<BaselineEntry>,
+  /// Entries matched by an issue during this run
+  pub matched: BTreeSet<BaselineEntry>,
+  /// Whether every issue found should be recorded, rather than only those
+  /// already in the file
+  pub is_updating: bool,
+  /// Whether CLI filters kept some instances from being looked at, so
+  /// entries which were not matched may still be issues
+  pub is_partial: bool,
+}
+
+impl Baseline {
+  /// Read the baseline file set by `--baseline` or `--update-baseline`, if
+  /// any. The file only has to exist when it is not being updated.
+  pub fn read<D: DiskIo>(ctx: &Context, io: &D) -> Result<Option<Self>, SyncpackError> {
+    let Some(baseline_path) = &ctx.config.cli.baseline_path else {
+      return Ok(None);
+    };
+    let path = ctx.disk.cwd.join(baseline_path);
+    let is_updating = ctx.config.cli.update_baseline;
+    let entries = match io.read_bytes(&path).transpose()? {
+      Some(bytes) => serde_json::from_slice(&bytes).map_err(|source| SyncpackError::InvalidBaseline {
+        path: reporter::to_uri(&ctx.disk.cwd, &path),
+        source,
+      })?,
+      None if is_updating => BTreeSet::new(),
+      None => return Err(SyncpackError::BaselineNotFound(reporter::to_uri(&ctx.disk.cwd, &path))),
+    };
+    Ok(Some(Self {
+      path,
+      entries,
+      matched: BTreeSet::new(),
+      is_updating,
+      is_partial: ctx.config.cli.filters.is_some(),
+    }))
+  }
+
+  /// Whether the instance is a known issue which should not be reported.
+  /// Every issue is known when the baseline is being updated.
+  pub fn is_known(&mut self, ctx: &Context, instance: &Instance) -> bool {
+    let entry = BaselineEntry::new(ctx, instance);
+    if self.is_updating || self.entries.contains(&entry) {
+      self.matched.insert(entry);
+      true
+    } else {
+      false
+    }
+  }
+
+  /// Entries which no longer match an issue, and can be removed
+  pub fn get_fixed(&self) -> Vec<&BaselineEntry> {
+    if self.is_updating || self.is_partial {
+      return vec![];
+    }
+    self.entries.difference(&self.matched).collect()
+  }
+
+  /// Replace the contents of the file with every issue found during this
+  /// run. When filters are set, entries which were not looked at are kept.
+  /// Returns how many entries were written.
+  pub fn write<D: DiskIo>(&self, io: &D) -> Result<usize, SyncpackError> {
+    let mut entries = self.matched.clone();
+    if self.is_partial {
+      entries.extend(self.entries.iter().cloned());
+    }
+    let mut json = serde_json::to_string_pretty(&entries).expect("baseline entries are serializable");
+    json.push('\n');
+    io.write_bytes(&self.path, json.as_bytes())?;
+    Ok(entries.len())
+  }
+}
@@ -0,0 +1,136 @@
+use {
+  super::*,
+  crate::{
+    commands::{lint, reporter::MarkdownReporter},
+    group_selector::GroupSelector,
+    test::{builder::TestBuilder, mock_disk::MockDiskIo},
+  },
+  serde_json::{Value, json},
+};
+
+/// package-a and package-c differ to the highest semver version of react
+fn build_context() -> Context {
+  TestBuilder::new()
+    .with_packages(vec![
+      json!({
+        "name": "package-a",
+        "version": "1.0.0",
+        "dependencies": { "react": "17.0.2" }
+      }),
+      json!({
+        "name": "package-b",
+        "version": "1.0.0",
+        "dependencies": { "react": "18.2.0" }
+      }),
+      json!({
+        "name": "package-c",
+        "version": "1.0.0",
+        "devDependencies": { "react": "16.0.0" }
+      }),
+    ])
+    .build_and_visit_packages()
+}
+
+fn lint_with_baseline(mut ctx: Context, io: &MockDiskIo, update_baseline: bool) -> Result<Context, SyncpackError> {
+  ctx.config.cli.baseline_path = Some(PathBuf::from(".syncpack-baseline.json"));
+  ctx.config.cli.update_baseline = update_baseline;
+  lint::run(ctx, &MarkdownReporter::default(), io)
+}
+
+/// Run `lint --update-baseline` and return the file it wrote
+fn update_baseline() -> Value {
+  let ctx = build_context();
+  let baseline_path = ctx.disk.cwd.join(".syncpack-baseline.json");
+  let io = MockDiskIo::new();
+  assert!(lint_with_baseline(ctx, &io, true).is_ok());
+  serde_json::from_str(&io.written_text(&baseline_path).unwrap()).unwrap()
+}
+
+/// A mock file system containing the given baseline file
+fn with_baseline(ctx: &Context, baseline: &Value) -> MockDiskIo {
+  let mut io = MockDiskIo::new();
+  io.add_json(ctx.disk.cwd.join(".syncpack-baseline.json").to_str().unwrap(), baseline);
+  io
+}
+
+#[test]
+fn update_records_every_issue_in_a_stable_order() {
+  let baseline = update_baseline();
+  let entries = baseline.as_array().unwrap();
+  assert_eq!(entries.len(), 2);
+  assert!(entries[0]["package"].as_str().unwrap().ends_with("packages/package-a/package.json"));
+  assert_eq!(entries[0]["dependencyType"], "prod");
+  assert_eq!(entries[0]["dependency"], "react");
+  assert_eq!(entries[0]["statusCode"], "DiffersToHighestOrLowestSemver");
+  assert_eq!(entries[0]["actual"], "17.0.2");
+  assert!(entries[1]["package"].as_str().unwrap().ends_with("packages/package-c/package.json"));
+  assert_eq!(entries[1]["dependencyType"], "dev");
+}
+
+#[test]
+fn known_issues_are_tolerated() {
+  let baseline = update_baseline();
+  let ctx = build_context();
+  let io = with_baseline(&ctx, &baseline);
+  assert!(lint_with_baseline(ctx, &io, false).is_ok());
+}
+
+#[test]
+fn new_issues_still_fail() {
+  let mut baseline = update_baseline();
+  baseline.as_array_mut().unwrap().remove(1);
+  let ctx = build_context();
+  let io = with_baseline(&ctx, &baseline);
+  assert!(matches!(lint_with_baseline(ctx, &io, false), Err(SyncpackError::IssuesFound)));
+}
+
+#[test]
+fn issues_which_have_been_fixed_are_warnings() {
+  let mut baseline = update_baseline();
+  baseline.as_array_mut().unwrap().push(json!({
+    "package": "packages/package-b/package.json",
+    "dependencyType": "prod",
+    "dependency": "lodash",
+    "statusCode": "DiffersToHighestOrLowestSemver",
+    "actual": "4.0.0"
+  }));
+  let mut ctx = build_context();
+  ctx.config.cli.max_warnings = Some(0);
+  let io = with_baseline(&ctx, &baseline);
+  assert!(matches!(
+    lint_with_baseline(ctx, &io, false),
+    Err(SyncpackError::TooManyWarnings { count: 1, max_warnings: 0 })
+  ));
+}
+
+#[test]
+fn baseline_must_exist_unless_it_is_being_updated() {
+  let result = lint_with_baseline(build_context(), &MockDiskIo::new(), false);
+  assert!(matches!(result, Err(SyncpackError::BaselineNotFound(path)) if path == ".syncpack-baseline.json"));
+}
+
+#[test]
+fn update_with_filters_keeps_the_entries_it_did_not_look_at() {
+  let lodash = json!({
+    "package": "packages/package-b/package.json",
+    "dependencyType": "prod",
+    "dependency": "lodash",
+    "statusCode": "DiffersToHighestOrLowestSemver",
+    "actual": "4.0.0"
+  });
+  let mut ctx = build_context();
+  ctx.config.cli.filters = Some(GroupSelector::new(
+    vec!["react".to_string()],
+    vec![],
+    "CLI filters".to_string(),
+    vec![],
+    vec![],
+  ));
+  let baseline_path = ctx.disk.cwd.join(".syncpack-baseline.json");
+  let io = with_baseline(&ctx, &json!([lodash]));
+  assert!(lint_with_baseline(ctx, &io, true).is_ok());
+  let written: Value = serde_json::from_str(&io.written_text(&baseline_path).unwrap()).unwrap();
+  let entries = written.as_array().unwrap();
+  assert_eq!(entries.len(), 3);
+  assert!(entries.contains(&lodash));
+}
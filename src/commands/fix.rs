@@ -5,7 +5,10 @@ mod fix_test;
 use {
   crate::{
     catalogs::detect_bun_catalogs,
-    commands::reporter::FixReporter,
+    commands::{
+      patch::{self, Patch},
+      reporter::FixReporter,
+    },
     context::Context,
     disk::{
       DiskIo, PackageManager, copy_expected_specifier_json, empty_yaml_file, ensure_object_path, insert_catalog_definition,
@@ -13,7 +16,6 @@ use {
     },
     errors::SyncpackError,
     instance::{FixableInstance, InstanceIdx, InstanceState, InvalidInstance, Severity},
-    source::Source,
     specifier::Specifier,
     version_group::{InstanceAction, VersionGroupBehavior},
   },
@@ -21,7 +23,7 @@ use {
   std::rc::Rc,
 };
 
-pub fn run<D: DiskIo>(mut ctx: Context, reporter: &dyn FixReporter, io: &D) -> Result<Context, SyncpackError> {
+pub fn run<D: DiskIo>(mut ctx: Context, reporter: &dyn FixReporter, io: &D, patch: &Patch) -> Result<Context, SyncpackError> {
   let mut contains_unfixable_issues = false;
   let mut was_invalid = false;
   let strict = ctx.config.rcfile.strict;
@@ -69,13 +71,15 @@ pub fn run<D: DiskIo>(mut ctx: Context, reporter: &dyn FixReporter, io: &D) -> R
 
   apply_fix_actions(&mut ctx, &fix_actions);
 
-  if !ctx.config.cli.dry_run {
+  if ctx.config.cli.dry_run {
+    patch::run(&ctx, io, patch)?;
+  } else {
     let indent = ctx.config.rcfile.indent.as_deref();
     let fallback = ctx.disk.formatting_fallback();
-    for file in ctx.disk.package_json_files.iter_mut() {
+    for file in ctx.disk.json_files_mut() {
       write_json_file(file, io, indent, &fallback)?;
     }
-    if let Some(yaml) = ctx.disk.pnpm_workspace.as_mut() {
+    for yaml in ctx.disk.yaml_files_mut() {
       write_yaml_file(yaml, io, indent, &fallback)?;
     }
   }
@@ -87,6 +91,7 @@ pub fn run<D: DiskIo>(mut ctx: Context, reporter: &dyn FixReporter, io: &D) -> R
   if !contains_unfixable_issues && !was_invalid {
     reporter.on_no_issues();
   }
+  reporter.on_complete(&ctx);
 
   if contains_unfixable_issues {
     Err(SyncpackError::IssuesFound)
@@ -97,8 +102,8 @@ pub fn run<D: DiskIo>(mut ctx: Context, reporter: &dyn FixReporter, io: &D) -> R
 
 /// Apply the recorded fix actions in the order they were collected.
 /// `MissingFromCatalog` consumers ALSO trigger an upstream insert into the
-/// target catalog source (pnpm yaml on Disk, or Bun root pkg.json via
-/// `disk.package_json_root_idx`).
+/// target catalog source (pnpm yaml or .yarnrc.yml on Disk, or Bun root
+/// pkg.json via `disk.package_json_root_idx`).
 fn apply_fix_actions(ctx: &mut Context, actions: &[(InstanceIdx, bool)]) {
   for &(inst_idx, is_banned) in actions {
     let state = ctx.instances[inst_idx.0].state.borrow().clone();
@@ -114,32 +119,22 @@ fn apply_fix_actions(ctx: &mut Context, actions: &[(InstanceIdx, bool)]) {
     }
 
     let consumer_source_idx = ctx.instances[inst_idx.0].source_idx();
-    // Copy the file_idx (Package) out of the source under an immutable
-    // borrow; the borrow ends before we mutate ctx.disk below.
-    let consumer_file_idx: Option<usize> = match &ctx.sources.all[consumer_source_idx.0] {
-      Source::Package { file_idx, .. } => Some(*file_idx),
-      Source::PnpmYaml => None,
-    };
-    if is_banned {
-      if let Some(fi) = consumer_file_idx {
-        let file = &mut ctx.disk.package_json_files[fi];
-        let instance = &ctx.instances[inst_idx.0];
+    let consumer_source = &ctx.sources.all[consumer_source_idx.0];
+    let consumer_kind = consumer_source.kind();
+    let instance = &ctx.instances[inst_idx.0];
+    if let Some(file) = ctx.disk.json_source_mut(consumer_source) {
+      // package.json or deno.json source.
+      if is_banned {
         remove_instance_from_disk(file, instance);
-      } else if let Some(yaml) = ctx.disk.pnpm_workspace.as_mut() {
-        let instance = &ctx.instances[inst_idx.0];
-        if let Some(catalog_name) = instance.catalog_name() {
-          crate::disk::remove_catalog_definition(yaml, catalog_name, &instance.descriptor.name);
-        }
+      } else {
+        copy_expected_specifier_json(file, instance);
       }
-    } else if let Some(fi) = consumer_file_idx {
-      let file = &mut ctx.disk.package_json_files[fi];
-      let instance = &ctx.instances[inst_idx.0];
-      copy_expected_specifier_json(file, instance);
-    } else {
-      // PnpmYaml source — route through disk.pnpm_workspace.
-      if let Some(yaml) = ctx.disk.pnpm_workspace.as_mut() {
-        let instance = &ctx.instances[inst_idx.0];
+    } else if let Some(yaml) = ctx.disk.yaml_source_mut(consumer_kind) {
+      // PnpmYaml / YarnRc source — route through the matching yaml on disk.
+      if !is_banned {
         crate::disk::copy_expected_specifier_yaml(yaml, instance);
+      } else if let Some(catalog_name) = instance.catalog_name() {
+        crate::disk::remove_catalog_definition(yaml, catalog_name, &instance.descriptor.name);
       }
     }
   }
@@ -147,8 +142,9 @@ fn apply_fix_actions(ctx: &mut Context, actions: &[(InstanceIdx, bool)]) {
 
 /// Insert `dep_name → winning_specifier` into the right catalog source
 /// before the consumer rewrite runs. Auto-creates the implicit default
-/// catalog file when none exists and PM=Pnpm/Bun. Routing follows
-/// `ctx.disk.package_manager`.
+/// catalog file when none exists and PM=Pnpm/Bun. Yarn catalogs are only
+/// written to an existing `.yarnrc.yml`, since Yarn Classic has no catalogs.
+/// Routing follows `ctx.disk.package_manager`.
 fn apply_missing_from_catalog_insert(ctx: &mut Context, inst_idx: InstanceIdx, catalog_name: &str, winning_specifier: &Rc<Specifier>) {
   let dep_name = ctx.instances[inst_idx.0].descriptor.name.clone();
 
@@ -161,6 +157,11 @@ fn apply_missing_from_catalog_insert(ctx: &mut Context, inst_idx: InstanceIdx, c
       let yaml = ctx.disk.pnpm_workspace.as_mut().unwrap();
       insert_catalog_definition(yaml, catalog_name, &dep_name, winning_specifier);
     }
+    Some(PackageManager::Yarn) => {
+      if let Some(yaml) = ctx.disk.yarnrc.as_mut() {
+        insert_catalog_definition(yaml, catalog_name, &dep_name, winning_specifier);
+      }
+    }
     Some(PackageManager::Bun) => {
       let Some(idx) = ctx.disk.package_json_root_idx else {
         return;
@@ -202,9 +203,9 @@ fn remove_instance_from_disk(file: &mut crate::disk::File<JsonValue>, instance:
         crate::disk::remove_prop(file, parent_pointer, prop_name);
       }
     }
-    Strategy::VersionsByName => {
-      let path_to_obj = &instance.descriptor.dependency_type.path;
-      crate::disk::remove_prop(file, path_to_obj, &instance.descriptor.name);
+    Strategy::VersionsByName | Strategy::ImportMap | Strategy::OverrideTree | Strategy::ResolutionPaths => {
+      let (parent_pointer, key) = instance.descriptor.get_parent_pointer_and_key();
+      crate::disk::remove_prop(file, &parent_pointer, &key);
     }
     Strategy::InvalidConfig => unreachable!("unrecognised strategy"),
   }
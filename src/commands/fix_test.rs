@@ -1,9 +1,10 @@
 use {
   crate::{
-    commands::{fix, reporter::FixReporter},
+    commands::{fix, patch::Patch, reporter::FixReporter},
     context::Context,
-    errors::SyncpackError,
-    instance::Instance,
+    disk::DiskIoError,
+    errors::{ExitCode, SyncpackError},
+    instance::{Instance, InstanceState, SuspectInstance},
     test::{builder::TestBuilder, mock_disk::MockDiskIo},
     version_group::{DependencyCore, VersionGroup},
   },
@@ -22,6 +23,8 @@ impl FixReporter for SilentReporter {
   fn on_no_issues(&self) {}
 
   fn on_unfixable_warning(&self) {}
+
+  fn on_complete(&self, _: &Context) {}
 }
 
 /// Runs `fix::run`. On `Ok`, returns the post-fix `Context`. On
@@ -30,7 +33,7 @@ impl FixReporter for SilentReporter {
 /// fixable.
 fn run_fix_ok(ctx: Context) -> Context {
   let disk = MockDiskIo::new();
-  fix::run(ctx, &SilentReporter, &disk).expect("fix should succeed")
+  fix::run(ctx, &SilentReporter, &disk, &Patch::default()).expect("fix should succeed")
 }
 
 /// Find a consumer instance by id. Convenience used across the tests.
@@ -261,7 +264,7 @@ fn pnpm_fix_writes_yaml_to_disk() {
   ctx.config.cli.dry_run = false;
 
   let disk = recording_disk();
-  let _ = fix::run(ctx, &SilentReporter, &disk).expect("fix should succeed");
+  let _ = fix::run(ctx, &SilentReporter, &disk, &Patch::default()).expect("fix should succeed");
 
   let writes = disk.recorded_writes();
   assert!(
@@ -612,6 +615,94 @@ fn fix_creates_default_catalog_when_zero_catalogs_bun_with_workspaces() {
   );
 }
 
+#[test]
+fn yarn_fix_missing_from_catalog_preserves_yarnrc_formatting() {
+  let yaml = "# Yarn settings\nnodeLinker: node-modules\n\ncatalog:\n  lodash: ^4.0.0 # keep\n";
+  let mut ctx = TestBuilder::new()
+    .with_yarn_catalogs(yaml)
+    .with_packages(vec![json!({
+      "name": "pkg-a",
+      "version": "0.0.0",
+      "dependencies": {"react": "^18.0.0"},
+    })])
+    .with_version_group(json!({
+      "label": "enforce catalog",
+      "dependencies": ["react"],
+      "policy": "catalog",
+    }))
+    .build_and_visit_packages();
+  ctx.config.cli.dry_run = false;
+
+  let disk = MockDiskIo::new();
+  let ctx = fix::run(ctx, &SilentReporter, &disk, &Patch::default()).expect("fix should succeed");
+
+  let written = disk
+    .written_text(std::path::Path::new("/test/.yarnrc.yml"))
+    .expect(".yarnrc.yml should be written");
+  assert!(
+    written.starts_with("# Yarn settings\nnodeLinker: node-modules\n\ncatalog:\n"),
+    "got {written:?}"
+  );
+  assert!(written.contains("lodash: ^4.0.0 # keep"), "got {written:?}");
+  assert!(written.contains("react: ^18.0.0"), "got {written:?}");
+  assert!(pnpm_yaml(&ctx).is_none(), "yarn catalogs must not create pnpm-workspace.yaml");
+
+  let pkg = find_package(&ctx, "pkg-a");
+  assert_eq!(
+    pkg.contents.pointer("/dependencies/react").and_then(|v| v.as_str()),
+    Some("catalog:"),
+  );
+}
+
+#[test]
+fn fix_creates_default_catalog_when_zero_catalogs_yarn_berry() {
+  // `.yarnrc.yml` exists without catalogs: the implicit default catalog is
+  // created inside it.
+  let ctx = TestBuilder::new()
+    .with_yarn_catalogs("nodeLinker: node-modules\n")
+    .with_packages(vec![json!({
+      "name": "pkg-a",
+      "version": "0.0.0",
+      "dependencies": {"react": "^18.0.0"},
+    })])
+    .with_version_group(json!({
+      "label": "enforce catalog",
+      "dependencies": ["react"],
+      "policy": "catalog",
+    }))
+    .build_and_visit_packages();
+  let ctx = run_fix_ok(ctx);
+
+  let yarnrc = ctx.disk.yarnrc.as_ref().expect(".yarnrc.yml is loaded");
+  let inserted = yarnrc
+    .contents
+    .get("catalog")
+    .and_then(|m| m.get("react"))
+    .and_then(|v| v.as_str())
+    .map(str::to_string);
+  assert_eq!(inserted, Some("^18.0.0".to_string()));
+}
+
+#[test]
+fn cannot_infer_catalog_file_when_zero_catalogs_yarn_classic() {
+  // yarn.lock without `.yarnrc.yml` is Yarn Classic, which has no catalogs.
+  let ctx = TestBuilder::new()
+    .with_yarn_package_manager()
+    .with_packages(vec![json!({
+      "name": "pkg-a",
+      "version": "0.0.0",
+      "dependencies": {"react": "^18.0.0"},
+    })])
+    .with_version_group(json!({
+      "label": "enforce catalog",
+      "dependencies": ["react"],
+      "policy": "catalog",
+    }))
+    .build_and_visit_packages();
+  let consumer = find_instance(&ctx, "react in /dependencies of pkg-a");
+  assert_eq!(consumer.state.borrow().get_status_type(), "Unfixable");
+}
+
 #[test]
 fn cannot_infer_catalog_file_when_zero_catalogs_npm_yarn_or_unknown() {
   // PM=npm/yarn/unknown → state is `Unfixable::CannotInferCatalogFile`.
@@ -640,7 +731,7 @@ fn cannot_infer_catalog_file_when_zero_catalogs_npm_yarn_or_unknown() {
   // fix::run returns Err(IssuesFound) for unfixable states. Verify no catalog
   // file was created on disk (we can't inspect ctx after Err — it's consumed).
   let disk = MockDiskIo::new();
-  let result = fix::run(ctx, &SilentReporter, &disk);
+  let result = fix::run(ctx, &SilentReporter, &disk, &Patch::default());
   assert!(
     matches!(result, Err(SyncpackError::IssuesFound)),
     "expected IssuesFound, got {result:?}"
@@ -783,8 +874,8 @@ impl crate::disk::DiskIo for RecordingDiskIo {
     Ok(())
   }
 
-  fn find_package_jsons(&self, root: &Path, patterns: &[String]) -> Vec<PathBuf> {
-    self.inner.find_package_jsons(root, patterns)
+  fn find_package_jsons(&self, root: &Path, patterns: &[String], respect_gitignore: bool) -> Vec<PathBuf> {
+    self.inner.find_package_jsons(root, patterns, respect_gitignore)
   }
 }
 
@@ -855,3 +946,183 @@ fn bun_fix_banned_removes_from_pkg_json() {
   );
   assert!(root.is_dirty(), "bun root should be marked dirty after banned removal");
 }
+
+#[tokio::test]
+async fn deno_fix_syncs_jsr_imports_across_workspace_members() {
+  let mut ctx = TestBuilder::new()
+    .with_file_at("deno.json", r#"{ "workspace": ["./app", "./lib"] }"#)
+    .with_file_at(
+      "app/deno.json",
+      r#"{ "name": "@acme/app", "imports": { "@std/path": "jsr:@std/path@^1.1.0", "preact": "https://esm.sh/preact@10" } }"#,
+    )
+    .with_file_at(
+      "lib/deno.jsonc",
+      "{\n  // Shared code\n  \"name\": \"@acme/lib\",\n  \"imports\": { \"@std/path\": \"jsr:@std/path@^1.0.0\" }, /* pinned */\n}\n",
+    )
+    .run()
+    .await;
+  assert!(
+    ctx.instances.iter().all(|instance| instance.descriptor.name != "preact"),
+    "url imports are not managed"
+  );
+  assert!(find_instance(&ctx, "@std/path in /imports of @acme/lib").is_invalid());
+  ctx.config.cli.dry_run = false;
+
+  let cwd = ctx.disk.cwd.clone();
+
+  let disk = MockDiskIo::new();
+  fix::run(ctx, &SilentReporter, &disk, &Patch::default()).expect("fix should succeed");
+
+  assert!(disk.written_text(&cwd.join("app/deno.json")).is_none());
+  assert_eq!(
+    disk.written_text(&cwd.join("lib/deno.jsonc")).as_deref(),
+    Some("{\n  // Shared code\n  \"name\": \"@acme/lib\",\n  \"imports\": { \"@std/path\": \"jsr:@std/path@^1.1.0\" }, /* pinned */\n}\n"),
+    "only the specifier should change"
+  );
+}
+
+#[tokio::test]
+async fn deno_fix_fails_on_jsonc_files_which_would_lose_their_comments() {
+  for dry_run in [false, true] {
+    let mut ctx = TestBuilder::new()
+      .with_file_at("deno.json", r#"{ "workspace": ["./lib"] }"#)
+      .with_file_at(
+        "lib/deno.jsonc",
+        "{\n  // Shared code\n  \"name\": \"@acme/lib\",\n  \"imports\": { \"left-pad\": \"npm:left-pad@1.3.0\" },\n}\n",
+      )
+      .with_version_group(json!({ "dependencies": ["left-pad"], "isBanned": true }))
+      .run()
+      .await;
+    assert!(find_instance(&ctx, "left-pad in /imports of @acme/lib").is_banned());
+    ctx.config.cli.dry_run = dry_run;
+    let cwd = ctx.disk.cwd.clone();
+    let disk = MockDiskIo::new();
+    let Err(err) = fix::run(ctx, &SilentReporter, &disk, &Patch::default()) else {
+      panic!("fix should fail rather than leave the file unfixed");
+    };
+    assert!(matches!(err, SyncpackError::DiskIoError(DiskIoError::JsoncRewrite(_))), "{err:?}");
+    assert_eq!(err.exit_code(), ExitCode::Io);
+    assert!(disk.written_text(&cwd.join("lib/deno.jsonc")).is_none());
+  }
+}
+
+#[test]
+fn fix_syncs_nested_npm_overrides_with_direct_dependencies() {
+  let ctx = TestBuilder::new()
+    .with_packages(vec![json!({
+      "name": "pkg-a",
+      "version": "0.0.0",
+      "dependencies": {"bar": "2.0.0", "foo": "1.1.0"},
+      "overrides": {
+        "foo@1": {".": "1.0.0", "bar": "1.0.0"}
+      }
+    })])
+    .build_and_visit_packages();
+  assert_eq!(
+    find_instance(&ctx, "bar in /overrides/foo@1/bar of pkg-a").descriptor.internal_name,
+    "bar"
+  );
+  let ctx = run_fix_ok(ctx);
+  assert_eq!(
+    find_package(&ctx, "pkg-a").contents["overrides"],
+    json!({"foo@1": {".": "1.1.0", "bar": "2.0.0"}})
+  );
+}
+
+#[test]
+fn fix_syncs_yarn_resolution_paths_with_direct_dependencies() {
+  let ctx = TestBuilder::new()
+    .with_packages(vec![json!({
+      "name": "pkg-a",
+      "version": "0.0.0",
+      "dependencies": {"@scope/bar": "2.0.0", "foo": "1.1.0"},
+      "resolutions": {
+        "**/foo": "1.0.0",
+        "a/@scope/bar": "1.0.0"
+      }
+    })])
+    .build_and_visit_packages();
+  let ctx = run_fix_ok(ctx);
+  assert_eq!(
+    find_package(&ctx, "pkg-a").contents["resolutions"],
+    json!({"**/foo": "1.1.0", "a/@scope/bar": "2.0.0"})
+  );
+}
+
+#[test]
+fn fix_removes_banned_nested_npm_override() {
+  let ctx = TestBuilder::new()
+    .with_packages(vec![json!({
+      "name": "pkg-a",
+      "version": "0.0.0",
+      "overrides": {
+        "foo": {".": "1.0.0", "bar": "1.0.0"}
+      }
+    })])
+    .with_version_group(json!({
+      "dependencies": ["bar"],
+      "isBanned": true,
+    }))
+    .build_and_visit_packages();
+  let ctx = run_fix_ok(ctx);
+  assert_eq!(find_package(&ctx, "pkg-a").contents["overrides"], json!({"foo": {".": "1.0.0"}}));
+}
+
+#[test]
+fn fix_flags_pnpm_patch_orphaned_by_a_version_change() {
+  let yaml = "patchedDependencies:\n  lodash@4.17.20: patches/lodash@4.17.20.patch\n";
+  let ctx = TestBuilder::new()
+    .with_pnpm_workspace_yaml(yaml)
+    .with_packages(vec![
+      json!({"name": "pkg-a", "version": "0.0.0", "dependencies": {"lodash": "4.17.20"}}),
+      json!({"name": "pkg-b", "version": "0.0.0", "dependencies": {"lodash": "4.17.21"}}),
+    ])
+    .build_and_visit_packages();
+  assert!(find_instance(&ctx, "lodash in /dependencies of pkg-a").is_fixable());
+  assert!(matches!(
+    *find_instance(&ctx, "lodash in /dependencies of pkg-b").state.borrow(),
+    InstanceState::Suspect(SuspectInstance::OrphanedPatch)
+  ));
+  let ctx = run_fix_ok(ctx);
+  assert_eq!(
+    find_package(&ctx, "pkg-a").contents.pointer("/dependencies/lodash"),
+    Some(&json!("4.17.21"))
+  );
+}
+
+#[test]
+fn pnpm_patch_is_kept_while_an_instance_can_install_the_patched_version() {
+  let yaml = "patchedDependencies:\n  lodash@4.17.20: patches/lodash@4.17.20.patch\n  react: patches/react.patch\n";
+  let ctx = TestBuilder::new()
+    .with_pnpm_workspace_yaml(yaml)
+    .with_packages(vec![
+      json!({"name": "pkg-a", "version": "0.0.0", "dependencies": {"lodash": "^4.17.0", "react": "18.0.0"}}),
+      json!({"name": "pkg-b", "version": "0.0.0", "dependencies": {"lodash": "^4.17.0", "react": "18.0.0"}}),
+    ])
+    .build_and_visit_packages();
+  assert!(ctx.instances.iter().all(|instance| instance.is_valid()));
+}
+
+#[tokio::test]
+async fn pnpm_overrides_and_patches_are_read_from_root_package_json() {
+  let ctx = TestBuilder::new()
+    .with_pnpm_package_manager()
+    .with_file_at(
+      "package.json",
+      r#"{
+        "name": "root",
+        "pnpm": {
+          "overrides": { "lodash": "4.17.20" },
+          "patchedDependencies": { "lodash@4.17.20": "patches/lodash@4.17.20.patch" }
+        }
+      }"#,
+    )
+    .with_packages(vec![json!({"name": "pkg-a", "dependencies": {"lodash": "4.17.21"}})])
+    .run()
+    .await;
+  assert!(find_instance(&ctx, "lodash in /pnpm/overrides of root").is_fixable());
+  assert!(matches!(
+    *find_instance(&ctx, "lodash in /dependencies of pkg-a").state.borrow(),
+    InstanceState::Suspect(SuspectInstance::OrphanedPatch)
+  ));
+}
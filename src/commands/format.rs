@@ -1,10 +1,19 @@
-use crate::{commands::reporter::FormatReporter, context::Context, disk::DiskIo, errors::SyncpackError, source::Source};
+use crate::{
+  commands::{
+    patch::{self, Patch},
+    reporter::FormatReporter,
+  },
+  context::Context,
+  disk::DiskIo,
+  errors::SyncpackError,
+  source::Source,
+};
 
-pub fn run<D: DiskIo>(ctx: Context, reporter: &dyn FormatReporter, io: &D) -> Result<Context, SyncpackError> {
+pub fn run<D: DiskIo>(ctx: Context, reporter: &dyn FormatReporter, io: &D, patch: &Patch) -> Result<Context, SyncpackError> {
   if ctx.config.cli.check {
     check_formatting(ctx, reporter)
   } else {
-    fix_formatting(ctx, reporter, io)
+    fix_formatting(ctx, reporter, io, patch)
   }
 }
 
@@ -32,10 +41,11 @@ fn check_formatting(ctx: Context, reporter: &dyn FormatReporter) -> Result<Conte
   if !is_invalid {
     reporter.on_no_issues();
   }
+  reporter.on_complete(&ctx);
   if is_invalid { Err(SyncpackError::IssuesFound) } else { Ok(ctx) }
 }
 
-fn fix_formatting<D: DiskIo>(mut ctx: Context, reporter: &dyn FormatReporter, io: &D) -> Result<Context, SyncpackError> {
+fn fix_formatting<D: DiskIo>(mut ctx: Context, reporter: &dyn FormatReporter, io: &D, patch: &Patch) -> Result<Context, SyncpackError> {
   let mut was_invalid = false;
   // Tuples of (sources arena slot, file_idx, name, mismatch_count) so the
   // immutable borrow of sources ends before we re-borrow mutably below.
@@ -69,7 +79,7 @@ fn fix_formatting<D: DiskIo>(mut ctx: Context, reporter: &dyn FormatReporter, io
     // when mutating disk.
     let mismatches = match &mut ctx.sources.all[source_idx] {
       Source::Package { formatting_mismatches, .. } => std::mem::take(formatting_mismatches),
-      Source::PnpmYaml => continue,
+      Source::PnpmYaml | Source::YarnRc | Source::DenoJson { .. } => continue,
     };
     let file = &mut ctx.disk.package_json_files[file_idx];
     for mismatch in mismatches {
@@ -77,7 +87,9 @@ fn fix_formatting<D: DiskIo>(mut ctx: Context, reporter: &dyn FormatReporter, io
     }
   }
 
-  if !ctx.config.cli.dry_run {
+  if ctx.config.cli.dry_run {
+    patch::run(&ctx, io, patch)?;
+  } else {
     let indent = ctx.config.rcfile.indent.as_deref();
     let fallback = ctx.disk.formatting_fallback();
     for file in ctx.disk.package_json_files.iter_mut() {
@@ -87,5 +99,6 @@ fn fix_formatting<D: DiskIo>(mut ctx: Context, reporter: &dyn FormatReporter, io
   if !was_invalid {
     reporter.on_no_issues();
   }
+  reporter.on_complete(&ctx);
   Ok(ctx)
 }
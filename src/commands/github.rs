@@ -0,0 +1,104 @@
+#[cfg(test)]
+#[path = "github_test.rs"]
+mod github_test;
+
+use {
+  crate::{
+    commands::reporter,
+    context::Context,
+    instance::{Instance, Severity},
+    source::FormatMismatch,
+    span::Span,
+  },
+  std::{cell::RefCell, path::Path},
+};
+
+/// Prints GitHub Actions workflow commands, opening a collapsible group
+/// whenever the label changes so annotations are listed under the version
+/// group (or package) they belong to
+#[derive(Default)]
+pub struct Annotations {
+  open_group: RefCell<Option<String>>,
+}
+
+impl Annotations {
+  pub fn group(&self, label: &str) {
+    let mut open_group = self.open_group.borrow_mut();
+    if open_group.as_deref() == Some(label) {
+      return;
+    }
+    if open_group.is_some() {
+      println!("::endgroup::");
+    }
+    println!("::group::{}", escape_data(label));
+    *open_group = Some(label.to_string());
+  }
+
+  pub fn end(&self) {
+    if self.open_group.borrow_mut().take().is_some() {
+      println!("::endgroup::");
+    }
+  }
+
+  pub fn instance(&self, ctx: &Context, instance: &Instance, severity: Option<Severity>) {
+    println!("{}", instance_annotation(ctx, instance, severity));
+  }
+
+  pub fn mismatch(&self, ctx: &Context, filepath: &Path, mismatch: &FormatMismatch) {
+    println!("{}", mismatch_annotation(ctx, filepath, mismatch));
+  }
+}
+
+/// eg. `::error file=packages/a/package.json,line=5,col=14,title=DiffersToLocal::react is ...`
+///
+/// The level follows the severity of the instance unless one is given.
+pub fn instance_annotation(ctx: &Context, instance: &Instance, severity: Option<Severity>) -> String {
+  let severity = severity.or(*instance.severity.borrow()).unwrap_or(Severity::None);
+  let level = match severity {
+    Severity::Fix | Severity::Error => "error",
+    Severity::Warn => "warning",
+    Severity::None => "notice",
+  };
+  let file_path = reporter::get_source_file_path(ctx, &ctx.sources.all[instance.source_idx().0]);
+  to_annotation(
+    ctx,
+    level,
+    file_path.as_deref(),
+    instance.span(),
+    &instance.state.borrow().get_name(),
+    &reporter::describe_instance(instance),
+  )
+}
+
+pub fn mismatch_annotation(ctx: &Context, filepath: &Path, mismatch: &FormatMismatch) -> String {
+  to_annotation(
+    ctx,
+    "error",
+    Some(filepath),
+    mismatch.span,
+    &format!("{:?}", mismatch.variant),
+    &reporter::describe_mismatch(mismatch),
+  )
+}
+
+fn to_annotation(ctx: &Context, level: &str, file_path: Option<&Path>, span: Option<Span>, title: &str, message: &str) -> String {
+  let mut properties: Vec<String> = vec![];
+  if let Some(file_path) = file_path {
+    properties.push(format!("file={}", escape_property(&reporter::get_uri(ctx, file_path))));
+  }
+  if let Some(span) = span {
+    properties.push(format!("line={}", span.value.line));
+    properties.push(format!("col={}", span.value.column));
+  }
+  properties.push(format!("title={}", escape_property(title)));
+  format!("::{level} {}::{}", properties.join(","), escape_data(message))
+}
+
+/// https://github.com/actions/toolkit/blob/main/packages/core/src/command.ts
+fn escape_data(text: &str) -> String {
+  text.replace('%', "%25").replace('\r', "%0D").replace('\n', "%0A")
+}
+
+fn escape_property(text: &str) -> String {
+  escape_data(text).replace(':', "%3A").replace(',', "%2C")
+}
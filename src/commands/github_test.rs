@@ -0,0 +1,61 @@
+use {
+  super::*,
+  crate::{
+    source::{FormatMismatch, FormatMismatchVariant},
+    test::builder::TestBuilder,
+  },
+  serde_json::json,
+};
+
+#[tokio::test]
+async fn annotates_instances_at_the_line_and_column_of_their_version() {
+  let ctx = TestBuilder::new()
+    .with_packages(vec![
+      json!({
+        "name": "package-a",
+        "version": "1.0.0",
+        "dependencies": { "react": "17.0.2" }
+      }),
+      json!({
+        "name": "package-b",
+        "version": "1.0.0",
+        "dependencies": { "react": "18.2.0" }
+      }),
+    ])
+    .run()
+    .await;
+  let instance = ctx
+    .instances
+    .iter()
+    .find(|instance| instance.id == "react in /dependencies of package-a")
+    .unwrap();
+  assert_eq!(
+    instance_annotation(&ctx, instance, None),
+    r#"::error file=packages/package-a/package.json,line=5,col=14,title=DiffersToHighestOrLowestSemver::react is "17.0.2" at .dependencies but should be "18.2.0""#
+  );
+  assert_eq!(
+    instance_annotation(&ctx, instance, Some(Severity::Warn)),
+    r#"::warning file=packages/package-a/package.json,line=5,col=14,title=DiffersToHighestOrLowestSemver::react is "17.0.2" at .dependencies but should be "18.2.0""#
+  );
+}
+
+#[test]
+fn annotates_formatting_mismatches() {
+  let ctx = TestBuilder::new().build();
+  let mismatch = FormatMismatch {
+    expected: json!(["a", "b"]),
+    property_path: "/keywords".to_string(),
+    variant: FormatMismatchVariant::PropertyIsNotSortedAz,
+    span: None,
+  };
+  assert_eq!(
+    mismatch_annotation(&ctx, &ctx.disk.cwd.join("package.json"), &mismatch),
+    "::error file=package.json,title=PropertyIsNotSortedAz::Property is not sorted az at .keywords"
+  );
+}
+
+#[test]
+fn escapes_workflow_command_values() {
+  assert_eq!(escape_data("50%\nof it"), "50%25%0Aof it");
+  assert_eq!(escape_property("a,b:c"), "a%2Cb%3Ac");
+}
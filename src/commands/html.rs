@@ -0,0 +1,286 @@
+use {
+  crate::{
+    commands::{report::Report, ui},
+    instance::InstanceState,
+  },
+  serde_json::Value,
+};
+
+const STYLE: &str = r#"
+:root { color-scheme: light dark; --border: #8884; --muted: #888; }
+body { font: 14px/1.5 system-ui, sans-serif; margin: 2rem; }
+h1 { margin-top: 0; }
+section { margin-bottom: 3rem; }
+summary { cursor: pointer; font-size: 1.1em; margin: 1rem 0 0.5rem; }
+input[type=search] { display: block; margin: 0.5rem 0; padding: 0.3rem 0.5rem; width: 20rem; }
+.scroll { overflow-x: auto; }
+table { border-collapse: collapse; }
+th, td { border: 1px solid var(--border); padding: 0.25rem 0.5rem; text-align: left; vertical-align: top; }
+th { cursor: pointer; user-select: none; white-space: nowrap; }
+th[aria-sort=ascending]::after { content: " \25B2"; }
+th[aria-sort=descending]::after { content: " \25BC"; }
+code { font-size: 0.95em; }
+.muted { color: var(--muted); }
+.badge { border-radius: 1em; color: #fff; display: inline-block; font-size: 0.85em; padding: 0 0.6em; text-decoration: none; white-space: nowrap; }
+.badge.valid { background: #2da44e; }
+.badge.suspect { background: #bf8700; }
+.badge.fixable { background: #cf222e; }
+.badge.unfixable { background: #a40e26; }
+.badge.conflict { background: #8250df; }
+td.suspect, td.fixable, td.unfixable, td.conflict { box-shadow: inset 4px 0 0; }
+td.suspect { color: #bf8700; }
+td.fixable, td.unfixable { color: #cf222e; }
+td.conflict { color: #8250df; }
+"#;
+
+const SCRIPT: &str = r#"
+document.querySelectorAll("input[data-filter]").forEach((input) => {
+  const table = document.getElementById(input.dataset.filter);
+  input.addEventListener("input", () => {
+    const query = input.value.trim().toLowerCase();
+    table.querySelectorAll("tbody tr").forEach((row) => {
+      row.hidden = query !== "" && !row.textContent.toLowerCase().includes(query);
+    });
+  });
+});
+document.querySelectorAll("table.sortable th").forEach((th) => {
+  th.addEventListener("click", () => {
+    const table = th.closest("table");
+    const column = th.cellIndex;
+    const ascending = th.getAttribute("aria-sort") !== "ascending";
+    table.querySelectorAll("th").forEach((other) => other.removeAttribute("aria-sort"));
+    th.setAttribute("aria-sort", ascending ? "ascending" : "descending");
+    const tbody = table.tBodies[0];
+    const text = (row) => row.cells[column].textContent.trim();
+    const rows = Array.from(tbody.rows).sort((a, b) =>
+      text(a).localeCompare(text(b), undefined, { numeric: true }) * (ascending ? 1 : -1));
+    tbody.append(...rows);
+  });
+});
+"#;
+
+/// A single HTML file with every style and script inline, so it can be
+/// attached to a ticket or opened offline
+pub fn report(report: &Report) -> String {
+  let mut html = String::from("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+  html.push_str("<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n");
+  html.push_str("<title>Syncpack report</title>\n");
+  html.push_str(&format!("<style>{STYLE}</style>\n</head>\n<body>\n<h1>Syncpack report</h1>\n"));
+  html.push_str(&summary(report));
+  html.push_str(&version_groups(report));
+  html.push_str(&matrix(report));
+  html.push_str(&updates(report));
+  html.push_str(&format!("<script>{SCRIPT}</script>\n</body>\n</html>\n"));
+  html
+}
+
+/// How many instances have each status type, and how many are outdated
+fn summary(report: &Report) -> String {
+  let instances: Vec<&Value> = report.groups.iter().flat_map(|group| &group.instances).collect();
+  let mut rows = vec![
+    vec!["Packages".to_string(), report.packages.len().to_string()],
+    vec!["Instances".to_string(), instances.len().to_string()],
+  ];
+  for status_type in InstanceState::STATUS_TYPES {
+    let count = instances.iter().filter(|instance| instance["statusType"] == *status_type).count();
+    rows.push(vec![badge(status_type, status_type, None), count.to_string()]);
+  }
+  if let Some(updates) = &report.updates {
+    let count: u64 = updates.iter().filter_map(|update| update["count"].as_u64()).sum();
+    rows.push(vec!["Outdated".to_string(), count.to_string()]);
+  }
+  section("summary", "Summary", &table("summary-table", &["", "Count"], &rows))
+}
+
+/// A filterable table of every instance per version group
+fn version_groups(report: &Report) -> String {
+  let mut html = String::new();
+  for (idx, group) in report.groups.iter().enumerate() {
+    let rows = group
+      .instances
+      .iter()
+      .map(|instance| {
+        vec![
+          escape(str_of(&instance["dependency"])),
+          format!(
+            "{} <span class=\"muted\">{}</span>",
+            escape(str_of(&instance["packageName"])),
+            escape(str_of(&instance["package"]))
+          ),
+          escape(str_of(&instance["dependencyType"])),
+          code(str_of(&instance["actual"]["raw"])),
+          code(str_of(&instance["expected"]["raw"])),
+          status_badge(instance),
+        ]
+      })
+      .collect::<Vec<_>>();
+    let id = format!("group-{idx}");
+    html.push_str(&format!(
+      "<details open>\n<summary><strong>{}</strong> ({})</summary>\n{}{}</details>\n",
+      escape(&group.label),
+      group.instances.len(),
+      filter(&id),
+      table(&id, &["Dependency", "Package", "Type", "Actual", "Expected", "Status"], &rows)
+    ));
+  }
+  section("version-groups", "Version groups", &html)
+}
+
+/// Every dependency against every package which uses it, to spot which
+/// packages are behind at a glance
+fn matrix(report: &Report) -> String {
+  let mut headers = vec!["Dependency"];
+  headers.extend(report.matrix.packages.keys().map(String::as_str));
+  let mut dependencies = report.matrix.dependencies.iter().collect::<Vec<_>>();
+  dependencies.sort();
+  let rows = dependencies
+    .into_iter()
+    .map(|dependency| {
+      let mut row = vec![escape(dependency)];
+      row.extend(report.matrix.packages.values().map(|cells| match cells.get(dependency) {
+        Some(cell) => {
+          let specifiers = cell
+            .specifiers
+            .iter()
+            .map(|specifier| code(specifier))
+            .collect::<Vec<_>>()
+            .join("<br>");
+          format!("<td class=\"{}\">{specifiers}", cell.status_type.to_lowercase())
+        }
+        None => String::new(),
+      }));
+      row
+    })
+    .collect::<Vec<_>>();
+  let html = format!(
+    "{}<div class=\"scroll\">{}</div>\n",
+    filter("matrix-table"),
+    table("matrix-table", &headers, &rows)
+  );
+  section("matrix", "Dependencies by package", &html)
+}
+
+/// The updates `syncpack update` would make, when the npm registry could be
+/// reached
+fn updates(report: &Report) -> String {
+  let mut html = match &report.updates {
+    None => "<p>No versions could be fetched from the npm registry.</p>\n".to_string(),
+    Some(updates) if updates.is_empty() => "<p>Every dependency is up to date.</p>\n".to_string(),
+    Some(updates) => {
+      let rows = updates
+        .iter()
+        .map(|update| {
+          vec![
+            escape(str_of(&update["dependency"])),
+            escape(str_of(&update["versionGroupLabel"])),
+            code(str_of(&update["current"]["raw"])),
+            escape(str_of(&update["current"]["age"])),
+            code(str_of(&update["target"]["raw"])),
+            escape(str_of(&update["target"]["age"])),
+            escape(str_of(&update["diff"])),
+            update["count"].to_string(),
+          ]
+        })
+        .collect::<Vec<_>>();
+      format!(
+        "{}{}",
+        filter("updates-table"),
+        table(
+          "updates-table",
+          &[
+            "Dependency",
+            "Version group",
+            "Current",
+            "Age",
+            "Latest",
+            "Age",
+            "Update",
+            "Instances"
+          ],
+          &rows
+        )
+      )
+    }
+  };
+  if !report.failed.is_empty() {
+    html.push_str(&format!(
+      "<p class=\"muted\">Versions could not be fetched for {}</p>\n",
+      report.failed.iter().map(|name| code(name)).collect::<Vec<_>>().join(", ")
+    ));
+  }
+  section("updates", "Available updates", &html)
+}
+
+fn section(id: &str, heading: &str, body: &str) -> String {
+  format!("<section id=\"{id}\">\n<h2>{heading}</h2>\n{body}</section>\n")
+}
+
+/// A search box which hides the rows of a table which do not contain it
+fn filter(table_id: &str) -> String {
+  format!("<input type=\"search\" placeholder=\"Filter\" aria-label=\"Filter\" data-filter=\"{table_id}\">\n")
+}
+
+/// A table which is sorted by clicking its headers. Cells which start with
+/// `<td` bring their own opening tag, so they can set attributes.
+fn table(id: &str, headers: &[&str], rows: &[Vec<String>]) -> String {
+  let mut html = format!("<table class=\"sortable\" id=\"{id}\">\n<thead>\n<tr>");
+  headers
+    .iter()
+    .for_each(|header| html.push_str(&format!("<th>{}</th>", escape(header))));
+  html.push_str("</tr>\n</thead>\n<tbody>\n");
+  for row in rows {
+    html.push_str("<tr>");
+    row.iter().for_each(|cell| {
+      if cell.starts_with("<td") {
+        html.push_str(&format!("{cell}</td>"));
+      } else {
+        html.push_str(&format!("<td>{cell}</td>"));
+      }
+    });
+    html.push_str("</tr>\n");
+  }
+  html.push_str("</tbody>\n</table>\n");
+  html
+}
+
+/// The status code of an instance, linked to its documentation
+fn status_badge(instance: &Value) -> String {
+  let status_code = str_of(&instance["statusCode"]);
+  badge(
+    status_code,
+    str_of(&instance["statusType"]),
+    Some(&ui::util::get_status_code_url(status_code)),
+  )
+}
+
+fn badge(text: &str, status_type: &str, href: Option<&str>) -> String {
+  let class = format!("badge {}", status_type.to_lowercase());
+  match href {
+    Some(href) => format!("<a class=\"{class}\" href=\"{}\">{}</a>", escape(href), escape(text)),
+    None => format!("<span class=\"{class}\">{}</span>", escape(text)),
+  }
+}
+
+/// Inline code, or nothing when the value is empty, eg. a missing version
+fn code(text: &str) -> String {
+  if text.is_empty() {
+    String::new()
+  } else {
+    format!("<code>{}</code>", escape(text))
+  }
+}
+
+/// A string from the JSON of an instance or update, or `""` when it is
+/// `null`
+fn str_of(value: &Value) -> &str {
+  value.as_str().unwrap_or_default()
+}
+
+fn escape(text: &str) -> String {
+  text
+    .replace('&', "&amp;")
+    .replace('<', "&lt;")
+    .replace('>', "&gt;")
+    .replace('"', "&quot;")
+    .replace('\'', "&#39;")
+}
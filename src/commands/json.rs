@@ -12,7 +12,8 @@ use {
 pub fn instance_to_json(ctx: &Context, instance: &Instance, variant_label: &str) -> Value {
   let package_path = match &ctx.sources.all[instance.source_idx().0] {
     Source::Package { file_idx, .. } => ctx.disk.package_json_files[*file_idx].filepath.to_string_lossy().to_string(),
-    Source::PnpmYaml => "pnpm-workspace.yaml".to_string(),
+    Source::DenoJson { file_idx, .. } => ctx.disk.deno_json_files[*file_idx].filepath.to_string_lossy().to_string(),
+    source @ (Source::PnpmYaml | Source::YarnRc) => source.name().to_string(),
   };
   let severity = instance.severity.borrow().unwrap_or(Severity::None);
   json!({
@@ -20,6 +21,8 @@ pub fn instance_to_json(ctx: &Context, instance: &Instance, variant_label: &str)
     "dependencyGroup": instance.descriptor.internal_name,
     "dependencyType": instance.descriptor.dependency_type.name,
     "package": package_path,
+    "line": instance.span().map(|span| span.value.line),
+    "column": instance.span().map(|span| span.value.column),
     "property": instance.descriptor.dependency_type.path.split('/').filter(|part| !part.is_empty()).collect::<Vec<&str>>(),
     "strategy": instance.descriptor.dependency_type.strategy,
     "versionGroup": variant_label,
@@ -31,6 +34,7 @@ pub fn instance_to_json(ctx: &Context, instance: &Instance, variant_label: &str)
       "raw": instance.descriptor.specifier.get_raw(),
       "type": instance.descriptor.specifier.get_config_identifier(),
     }),
+    "resolved": instance.resolved_version,
     "expected": instance.expected_specifier.borrow().as_ref().map(|expected|
       json!({
         "raw": expected.get_raw(),
@@ -0,0 +1,181 @@
+#[cfg(test)]
+#[path = "junit_test.rs"]
+mod junit_test;
+
+use {
+  crate::{
+    commands::reporter,
+    context::Context,
+    instance::{Instance, InstanceId, Severity},
+    source::{FormatMismatch, Source},
+  },
+  std::{collections::HashMap, path::PathBuf},
+};
+
+/// One package in a JUnit XML report
+pub struct TestSuite {
+  pub name: String,
+  /// Path to the file relative to the directory syncpack was run from
+  pub file: Option<String>,
+  pub cases: Vec<TestCase>,
+}
+
+/// One instance or formatting check in a JUnit XML report
+#[derive(Clone, Debug)]
+pub struct TestCase {
+  pub name: String,
+  pub line: Option<usize>,
+  pub outcome: Outcome,
+}
+
+#[derive(Clone, Debug)]
+pub enum Outcome {
+  Passed,
+  /// Reported but does not fail the command, written to `<system-out>`
+  Warning {
+    status_code: String,
+    text: String,
+  },
+  Failed {
+    status_code: String,
+    text: String,
+  },
+}
+
+impl Outcome {
+  /// The outcome of an instance which was reported as an issue
+  pub fn of_instance(instance: &Instance) -> Outcome {
+    let status_code = instance.state.borrow().get_name();
+    let text = reporter::describe_instance(instance);
+    match instance.severity.borrow().unwrap_or(Severity::None) {
+      Severity::Warn => Outcome::Warning { status_code, text },
+      _ => Outcome::Failed { status_code, text },
+    }
+  }
+
+  pub fn of_mismatch(mismatch: &FormatMismatch) -> Outcome {
+    Outcome::Failed {
+      status_code: format!("{:?}", mismatch.variant),
+      text: reporter::describe_mismatch(mismatch),
+    }
+  }
+}
+
+/// A suite per package with a test case for every instance it contains,
+/// both sorted by name. Instances missing from `outcomes` passed.
+pub fn instance_suites(ctx: &Context, outcomes: &HashMap<InstanceId, Outcome>) -> Vec<TestSuite> {
+  let mut suites: Vec<TestSuite> = ctx.sources.all.iter().map(|source| to_suite(ctx, source)).collect();
+  ctx.instances.iter().for_each(|instance| {
+    suites[instance.source_idx().0].cases.push(TestCase {
+      name: format!("{} in {}", instance.descriptor.name, instance.descriptor.dependency_type.path),
+      line: instance.span().map(|span| span.value.line),
+      outcome: outcomes.get(&instance.id).cloned().unwrap_or(Outcome::Passed),
+    });
+  });
+  suites.retain(|suite| !suite.cases.is_empty());
+  suites.sort_by(|a, b| a.name.cmp(&b.name));
+  suites.iter_mut().for_each(|suite| suite.cases.sort_by(|a, b| a.name.cmp(&b.name)));
+  suites
+}
+
+/// A suite per package, sorted by name, with a test case for every
+/// formatting mismatch, or a single passing test case when it is formatted
+/// correctly
+pub fn format_suites(ctx: &Context, mismatches: &HashMap<PathBuf, Vec<TestCase>>) -> Vec<TestSuite> {
+  let mut suites: Vec<TestSuite> = ctx
+    .sources
+    .all
+    .iter()
+    .filter_map(|source| {
+      let Source::Package { file_idx, .. } = source else {
+        return None;
+      };
+      let mut suite = to_suite(ctx, source);
+      let filepath = &ctx.disk.package_json_files[*file_idx].filepath;
+      suite.cases = match mismatches.get(filepath) {
+        Some(cases) => cases.clone(),
+        None => vec![TestCase {
+          name: "is formatted".to_string(),
+          line: None,
+          outcome: Outcome::Passed,
+        }],
+      };
+      Some(suite)
+    })
+    .collect();
+  suites.sort_by(|a, b| a.name.cmp(&b.name));
+  suites
+}
+
+fn to_suite(ctx: &Context, source: &Source) -> TestSuite {
+  TestSuite {
+    name: source.name().to_string(),
+    file: reporter::get_source_file_path(ctx, source).map(|file_path| reporter::get_uri(ctx, &file_path)),
+    cases: vec![],
+  }
+}
+
+/// Render a complete JUnit XML document
+pub fn to_xml(name: &str, suites: &[TestSuite]) -> String {
+  let count = |suite: &TestSuite| suite.cases.len();
+  let failures = |suite: &TestSuite| {
+    suite
+      .cases
+      .iter()
+      .filter(|case| matches!(case.outcome, Outcome::Failed { .. }))
+      .count()
+  };
+  let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+  xml.push_str(&format!(
+    "<testsuites name=\"{}\" tests=\"{}\" failures=\"{}\">\n",
+    escape(name),
+    suites.iter().map(count).sum::<usize>(),
+    suites.iter().map(failures).sum::<usize>()
+  ));
+  for suite in suites {
+    let file = suite
+      .file
+      .as_deref()
+      .map(|file| format!(" file=\"{}\"", escape(file)))
+      .unwrap_or_default();
+    xml.push_str(&format!(
+      "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\"{file}>\n",
+      escape(&suite.name),
+      count(suite),
+      failures(suite)
+    ));
+    for case in &suite.cases {
+      let line = case.line.map(|line| format!(" line=\"{line}\"")).unwrap_or_default();
+      let attributes = format!("name=\"{}\" classname=\"{}\"{file}{line}", escape(&case.name), escape(&suite.name));
+      match &case.outcome {
+        Outcome::Passed => xml.push_str(&format!("    <testcase {attributes} />\n")),
+        Outcome::Warning { status_code, text } => {
+          xml.push_str(&format!("    <testcase {attributes}>\n"));
+          xml.push_str(&format!("      <system-out>warning {status_code}: {}</system-out>\n", escape(text)));
+          xml.push_str("    </testcase>\n");
+        }
+        Outcome::Failed { status_code, text } => {
+          xml.push_str(&format!("    <testcase {attributes}>\n"));
+          xml.push_str(&format!(
+            "      <failure message=\"{}\" type=\"{status_code}\">{}</failure>\n",
+            escape(text),
+            escape(text)
+          ));
+          xml.push_str("    </testcase>\n");
+        }
+      }
+    }
+    xml.push_str("  </testsuite>\n");
+  }
+  xml.push_str("</testsuites>");
+  xml
+}
+
+fn escape(text: &str) -> String {
+  text
+    .replace('&', "&amp;")
+    .replace('<', "&lt;")
+    .replace('>', "&gt;")
+    .replace('"', "&quot;")
+    .replace('\'', "&apos;")
+}
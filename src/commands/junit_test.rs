@@ -0,0 +1,128 @@
+use {super::*, crate::test::builder::TestBuilder, serde_json::json};
+
+fn outcomes_of_invalid_instances(ctx: &Context) -> HashMap<InstanceId, Outcome> {
+  ctx
+    .instances
+    .iter()
+    .filter(|instance| instance.state.borrow().is_invalid())
+    .map(|instance| (instance.id.clone(), Outcome::of_instance(instance)))
+    .collect()
+}
+
+#[test]
+fn renders_counts_and_escapes_text() {
+  let suites = vec![TestSuite {
+    name: "@scope/a".to_string(),
+    file: Some("packages/a/package.json".to_string()),
+    cases: vec![
+      TestCase {
+        name: "react in /dependencies".to_string(),
+        line: Some(5),
+        outcome: Outcome::Failed {
+          status_code: "DiffersToPin".to_string(),
+          text: "react is \"<1\" & should be \"1.0.0\"".to_string(),
+        },
+      },
+      TestCase {
+        name: "vue in /dependencies".to_string(),
+        line: None,
+        outcome: Outcome::Passed,
+      },
+    ],
+  }];
+  assert_eq!(
+    to_xml("syncpack", &suites),
+    r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites name="syncpack" tests="2" failures="1">
+  <testsuite name="@scope/a" tests="2" failures="1" file="packages/a/package.json">
+    <testcase name="react in /dependencies" classname="@scope/a" file="packages/a/package.json" line="5">
+      <failure message="react is &quot;&lt;1&quot; &amp; should be &quot;1.0.0&quot;" type="DiffersToPin">react is &quot;&lt;1&quot; &amp; should be &quot;1.0.0&quot;</failure>
+    </testcase>
+    <testcase name="vue in /dependencies" classname="@scope/a" file="packages/a/package.json" />
+  </testsuite>
+</testsuites>"#
+  );
+}
+
+#[tokio::test]
+async fn has_a_suite_per_package_and_a_case_per_instance() {
+  let ctx = TestBuilder::new()
+    .with_packages(vec![
+      json!({
+        "name": "package-a",
+        "version": "1.0.0",
+        "dependencies": { "react": "17.0.2" }
+      }),
+      json!({
+        "name": "package-b",
+        "version": "1.0.0",
+        "dependencies": { "react": "18.2.0" }
+      }),
+    ])
+    .run()
+    .await;
+  let suites = instance_suites(&ctx, &outcomes_of_invalid_instances(&ctx));
+  let summary: Vec<(String, Vec<(String, bool)>)> = suites
+    .iter()
+    .map(|suite| {
+      let cases = suite
+        .cases
+        .iter()
+        .map(|case| (case.name.clone(), matches!(case.outcome, Outcome::Failed { .. })))
+        .collect();
+      (suite.name.clone(), cases)
+    })
+    .collect();
+  assert_eq!(
+    summary,
+    vec![
+      (
+        "package-a".to_string(),
+        vec![
+          ("package-a in /version".to_string(), false),
+          ("react in /dependencies".to_string(), true)
+        ]
+      ),
+      (
+        "package-b".to_string(),
+        vec![
+          ("package-b in /version".to_string(), false),
+          ("react in /dependencies".to_string(), false)
+        ]
+      ),
+    ]
+  );
+  assert_eq!(suites[0].file.as_deref(), Some("packages/package-a/package.json"));
+  assert_eq!(suites[0].cases[1].line, Some(5));
+}
+
+#[test]
+fn warnings_pass_but_are_written_to_system_out() {
+  let suites = vec![TestSuite {
+    name: "a".to_string(),
+    file: None,
+    cases: vec![TestCase {
+      name: "a in /version".to_string(),
+      line: None,
+      outcome: Outcome::Warning {
+        status_code: "InvalidLocalVersion".to_string(),
+        text: "a is \"\" at .version".to_string(),
+      },
+    }],
+  }];
+  let xml = to_xml("syncpack", &suites);
+  assert!(xml.contains(r#"<testsuites name="syncpack" tests="1" failures="0">"#));
+  assert!(xml.contains("<system-out>warning InvalidLocalVersion: a is &quot;&quot; at .version</system-out>"));
+}
+
+#[test]
+fn formatted_packages_have_a_single_passing_case() {
+  let ctx = TestBuilder::new()
+    .with_packages(vec![json!({ "name": "package-a", "version": "1.0.0" })])
+    .build_and_visit_formatting();
+  let suites = format_suites(&ctx, &HashMap::new());
+  assert_eq!(suites.len(), 1);
+  assert_eq!(suites[0].cases.len(), 1);
+  assert_eq!(suites[0].cases[0].name, "is formatted");
+  assert!(matches!(suites[0].cases[0].outcome, Outcome::Passed));
+}
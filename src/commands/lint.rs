@@ -1,9 +1,29 @@
-use crate::{commands::ui, context::Context, errors::SyncpackError, instance::Severity, version_group::InstanceAction};
+#[cfg(test)]
+#[path = "lint_test.rs"]
+mod lint_test;
+
+use {
+  crate::{
+    commands::{
+      baseline::{Baseline, BaselineEntry},
+      reporter::{self, LintReporter},
+      ui,
+    },
+    context::Context,
+    disk::DiskIo,
+    errors::SyncpackError,
+    instance::Severity,
+    version_group::InstanceAction,
+  },
+  log::{info, warn},
+};
 
 /// Run the lint command side effects
-pub fn run(ctx: Context) -> Result<Context, SyncpackError> {
+pub fn run<D: DiskIo>(ctx: Context, reporter: &dyn LintReporter, io: &D) -> Result<Context, SyncpackError> {
   let mut is_invalid = false;
+  let mut warning_count = 0;
   let strict = ctx.config.rcfile.strict;
+  let mut baseline = Baseline::read(&ctx, io)?;
 
   ctx.version_groups.iter().for_each(|group| {
     let mut has_printed_group = false;
@@ -16,28 +36,75 @@ pub fn run(ctx: Context) -> Result<Context, SyncpackError> {
           if matches!(action, InstanceAction::Valid) {
             return;
           }
+          if baseline.as_mut().is_some_and(|baseline| baseline.is_known(&ctx, instance)) {
+            return;
+          }
           if !has_printed_group {
-            ui::group::print_header(&ctx, group);
+            reporter.on_group_header(&ctx, group);
             has_printed_group = true;
           }
           if !has_printed_dependency {
-            ui::dependency::print(&ctx, dependency, group.variant_label());
+            reporter.on_dependency(&ctx, dependency, group.variant_label());
             has_printed_dependency = true;
           }
-          if ctx.config.cli.show_instances {
-            ui::instance::print(&ctx, instance);
-          }
-          if matches!(action, InstanceAction::Render(Severity::Error) | InstanceAction::Fix(_)) {
-            is_invalid = true;
+          reporter.on_instance(&ctx, instance);
+          match action {
+            InstanceAction::Render(Severity::Error) | InstanceAction::Fix(_) => is_invalid = true,
+            InstanceAction::Render(Severity::Warn) => warning_count += 1,
+            _ => {}
           }
         });
     });
   });
 
+  if let Some(baseline) = &baseline {
+    if baseline.is_updating {
+      let count = baseline.write(io)?;
+      info!(
+        "{} Wrote {} issues to {}",
+        ui::icon::ok(),
+        count,
+        reporter::to_uri(&ctx.disk.cwd, &baseline.path)
+      );
+    } else {
+      let fixed = baseline.get_fixed();
+      warn_of_fixed_entries(&ctx, baseline, &fixed);
+      warning_count += fixed.len();
+    }
+  }
+
+  let too_many_warnings = ctx.config.cli.max_warnings.filter(|max_warnings| warning_count > *max_warnings);
+  if !is_invalid && too_many_warnings.is_none() {
+    reporter.on_no_issues();
+  }
+  reporter.on_complete(&ctx);
   if is_invalid {
     Err(SyncpackError::IssuesFound)
+  } else if let Some(max_warnings) = too_many_warnings {
+    Err(SyncpackError::TooManyWarnings {
+      count: warning_count,
+      max_warnings,
+    })
   } else {
-    ui::util::print_no_issues_found();
     Ok(ctx)
   }
 }
+
+/// Baseline entries which have been fixed are warnings, so that the file
+/// shrinks over time instead of tolerating the issues again
+fn warn_of_fixed_entries(ctx: &Context, baseline: &Baseline, fixed: &[&BaselineEntry]) {
+  if fixed.is_empty() {
+    return;
+  }
+  warn!(
+    "{} issues in {} have been fixed, run syncpack lint --update-baseline to remove them",
+    fixed.len(),
+    reporter::to_uri(&ctx.disk.cwd, &baseline.path)
+  );
+  fixed.iter().for_each(|entry| {
+    warn!(
+      "{} \"{}\" ({}) in {} is no longer {}",
+      entry.dependency, entry.actual, entry.dependency_type, entry.package, entry.status_code
+    );
+  });
+}
@@ -0,0 +1,55 @@
+use {
+  super::*,
+  crate::{
+    commands::reporter::MarkdownReporter,
+    test::{builder::TestBuilder, mock_disk::MockDiskIo},
+  },
+  serde_json::json,
+};
+
+/// Three instances which differ to the pinned version, reported as warnings
+fn lint_with_max_warnings(max_warnings: Option<usize>) -> Result<Context, SyncpackError> {
+  let mut ctx = TestBuilder::new()
+    .with_packages(vec![
+      json!({
+        "name": "package-a",
+        "version": "1.0.0",
+        "dependencies": { "react": "17.0.2" }
+      }),
+      json!({
+        "name": "package-b",
+        "version": "1.0.0",
+        "dependencies": { "react": "17.0.2" }
+      }),
+      json!({
+        "name": "package-c",
+        "version": "1.0.0",
+        "dependencies": { "react": "16.0.0" }
+      }),
+    ])
+    .with_version_group(json!({
+      "dependencies": ["react"],
+      "pinVersion": "18.2.0",
+      "severity": { "DiffersToPin": "warn" }
+    }))
+    .build_and_visit_packages();
+  ctx.config.cli.max_warnings = max_warnings;
+  run(ctx, &MarkdownReporter::default(), &MockDiskIo::new())
+}
+
+#[test]
+fn warnings_pass_without_max_warnings() {
+  assert!(lint_with_max_warnings(None).is_ok());
+}
+
+#[test]
+fn warnings_pass_up_to_max_warnings() {
+  assert!(lint_with_max_warnings(Some(3)).is_ok());
+}
+
+#[test]
+fn more_warnings_than_max_warnings_fail_with_their_own_exit_code() {
+  let err = lint_with_max_warnings(Some(2)).unwrap_err();
+  assert!(matches!(err, SyncpackError::TooManyWarnings { count: 3, max_warnings: 2 }));
+  assert_eq!(err.exit_code() as i32, 2);
+}
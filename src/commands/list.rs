@@ -1,6 +1,6 @@
-use crate::{commands::ui, context::Context, errors::SyncpackError, instance::Severity, version_group::InstanceAction};
+use crate::{commands::reporter::LintReporter, context::Context, errors::SyncpackError, instance::Severity, version_group::InstanceAction};
 
-pub fn run(ctx: Context) -> Result<Context, SyncpackError> {
+pub fn run(ctx: Context, reporter: &dyn LintReporter) -> Result<Context, SyncpackError> {
   let mut is_invalid = false;
   let strict = ctx.config.rcfile.strict;
 
@@ -9,16 +9,14 @@ pub fn run(ctx: Context) -> Result<Context, SyncpackError> {
     .iter()
     .filter(|group| !group.is_ignored() || ctx.config.cli.show_ignored)
     .for_each(|group| {
-      ui::group::print_header(&ctx, group);
+      reporter.on_group_header(&ctx, group);
       group.get_sorted_dependencies(&ctx.config.cli.sort).for_each(|dependency| {
-        ui::dependency::print(&ctx, dependency, group.variant_label());
+        reporter.on_dependency(&ctx, dependency, group.variant_label());
         dependency
           .get_sorted_instances(&ctx.instances, &ctx.sources.all)
           .for_each(|(_, instance)| {
             let action = group.resolve_action(instance, strict);
-            if ctx.config.cli.show_instances {
-              ui::instance::print(&ctx, instance);
-            }
+            reporter.on_instance(&ctx, instance);
             if matches!(action, InstanceAction::Render(Severity::Error) | InstanceAction::Fix(_)) {
               is_invalid = true;
             }
@@ -27,8 +25,8 @@ pub fn run(ctx: Context) -> Result<Context, SyncpackError> {
     });
 
   if !is_invalid {
-    ui::util::print_no_issues_found();
+    reporter.on_no_issues();
   }
-
+  reporter.on_complete(&ctx);
   if is_invalid { Err(SyncpackError::IssuesFound) } else { Ok(ctx) }
 }
@@ -0,0 +1,260 @@
+#[cfg(test)]
+#[path = "markdown_test.rs"]
+mod markdown_test;
+
+use crate::{
+  commands::ui::{
+    self,
+    update_row::{self, DiffKind},
+  },
+  context::Context,
+  instance::{Instance, Severity},
+  source::{FormatMismatch, FormatMismatchVariant},
+  tui::UpdateRow,
+};
+
+/// Instances in one version group which were reported as an issue
+pub struct IssueGroup {
+  pub label: String,
+  pub issues: Vec<Issue>,
+}
+
+/// Every instance of a dependency with the same status and expected version
+pub struct Issue {
+  pub dependency: String,
+  pub expected: Option<String>,
+  pub status_code: String,
+  pub severity: Severity,
+  /// eg. `` pkg-a `1.0.0` ``
+  pub packages: Vec<String>,
+}
+
+impl IssueGroup {
+  pub fn new(label: String) -> Self {
+    Self { label, issues: vec![] }
+  }
+
+  /// Add the instance to the row of the matching issue, or start a new row
+  pub fn add(&mut self, ctx: &Context, instance: &Instance) {
+    let dependency = instance.descriptor.name.clone();
+    let expected = instance
+      .expected_specifier
+      .borrow()
+      .as_ref()
+      .map(|expected| expected.get_raw().to_string());
+    let status_code = instance.state.borrow().get_name();
+    let package = format!(
+      "{} {}",
+      escape(ctx.sources.all[instance.source_idx().0].name()),
+      code(instance.descriptor.specifier.get_raw())
+    );
+    match self
+      .issues
+      .iter_mut()
+      .find(|issue| issue.dependency == dependency && issue.expected == expected && issue.status_code == status_code)
+    {
+      Some(issue) => issue.packages.push(package),
+      None => self.issues.push(Issue {
+        dependency,
+        expected,
+        status_code,
+        severity: instance.severity.borrow().unwrap_or(Severity::None),
+        packages: vec![package],
+      }),
+    }
+  }
+
+  fn instance_count(&self) -> usize {
+    self.issues.iter().map(|issue| issue.packages.len()).sum()
+  }
+}
+
+/// Formatting mismatches in one package which were not fixed
+pub struct MismatchGroup {
+  pub package: String,
+  /// Property and check of each mismatch
+  pub mismatches: Vec<(String, FormatMismatchVariant)>,
+}
+
+impl MismatchGroup {
+  pub fn new(package: &str) -> Self {
+    Self {
+      package: package.to_string(),
+      mismatches: vec![],
+    }
+  }
+
+  pub fn add(&mut self, mismatch: &FormatMismatch) {
+    self
+      .mismatches
+      .push((ui::util::get_formatted_path(&mismatch.property_path), mismatch.variant.clone()));
+  }
+}
+
+/// Totals by severity, then a collapsible table of issues per version group
+pub fn lint_report(groups: &[IssueGroup]) -> String {
+  let groups: Vec<&IssueGroup> = groups.iter().filter(|group| !group.issues.is_empty()).collect();
+  let count = |severity: Severity| {
+    groups
+      .iter()
+      .flat_map(|group| &group.issues)
+      .filter(|issue| issue.severity == severity)
+      .map(|issue| issue.packages.len())
+      .sum::<usize>()
+  };
+  let mut markdown = String::from("## Syncpack lint\n\n");
+  if groups.is_empty() {
+    markdown.push_str("No issues found\n");
+    return markdown;
+  }
+  markdown.push_str(&totals(
+    "Severity",
+    &[
+      ("fix", count(Severity::Fix)),
+      ("error", count(Severity::Error)),
+      ("warn", count(Severity::Warn)),
+    ],
+  ));
+  for group in groups {
+    let rows = group
+      .issues
+      .iter()
+      .map(|issue| {
+        vec![
+          escape(&issue.dependency),
+          code(issue.expected.as_deref().unwrap_or_default()),
+          issue.packages.join("<br>"),
+          link(&issue.status_code, &ui::util::get_status_code_url(&issue.status_code)),
+        ]
+      })
+      .collect::<Vec<_>>();
+    markdown.push_str(&details(
+      &group.label,
+      group.instance_count(),
+      &table(&["Dependency", "Expected", "Packages", "Status"], &rows),
+    ));
+  }
+  markdown
+}
+
+/// A collapsible table of the mismatches in each package
+pub fn format_report(groups: &[MismatchGroup]) -> String {
+  let groups: Vec<&MismatchGroup> = groups.iter().filter(|group| !group.mismatches.is_empty()).collect();
+  let mut markdown = String::from("## Syncpack format\n\n");
+  if groups.is_empty() {
+    markdown.push_str("No issues found\n");
+    return markdown;
+  }
+  let count = groups.iter().map(|group| group.mismatches.len()).sum();
+  markdown.push_str(&totals("Severity", &[("error", count)]));
+  for group in groups {
+    let rows = group
+      .mismatches
+      .iter()
+      .map(|(property, variant)| {
+        let config_key = ui::util::to_kebab_case(variant.get_config_key());
+        vec![
+          code(property),
+          link(&format!("{variant:?}"), &format!("https://syncpack.dev/config/{config_key}")),
+        ]
+      })
+      .collect::<Vec<_>>();
+    markdown.push_str(&details(
+      &group.package,
+      group.mismatches.len(),
+      &table(&["Property", "Status"], &rows),
+    ));
+  }
+  markdown
+}
+
+/// Totals by kind of update, then a collapsible table of updates per
+/// version group, by the label each row was given by `MarkdownReporter`
+pub fn update_report(rows: &[UpdateRow]) -> String {
+  let mut markdown = String::from("## Syncpack update\n\n");
+  if rows.is_empty() {
+    markdown.push_str("No issues found\n");
+    return markdown;
+  }
+  let counts = update_row::count_diffs(rows);
+  markdown.push_str(&totals(
+    "Update",
+    &[("major", counts.major), ("minor", counts.minor), ("patch", counts.patch)],
+  ));
+  let mut group_labels: Vec<&str> = vec![];
+  rows.iter().for_each(|row| {
+    if !group_labels.contains(&row.group_label.as_str()) {
+      group_labels.push(&row.group_label);
+    }
+  });
+  for group_label in group_labels {
+    let group_rows: Vec<&UpdateRow> = rows.iter().filter(|row| row.group_label == group_label).collect();
+    let table_rows = group_rows
+      .iter()
+      .map(|row| {
+        let diff = match update_row::diff_kind_of_row(row) {
+          DiffKind::Major => "major",
+          DiffKind::Minor => "minor",
+          DiffKind::Patch => "patch",
+          DiffKind::None => "",
+        };
+        vec![
+          escape(&row.dependency_name),
+          code(&row.current_raw),
+          code(&row.target_raw),
+          diff.to_string(),
+          row.bucket_count.to_string(),
+        ]
+      })
+      .collect::<Vec<_>>();
+    markdown.push_str(&details(
+      group_label,
+      group_rows.iter().map(|row| row.bucket_count).sum(),
+      &table(&["Dependency", "Current", "Target", "Update", "Instances"], &table_rows),
+    ));
+  }
+  markdown
+}
+
+fn totals(heading: &str, counts: &[(&str, usize)]) -> String {
+  let rows = counts
+    .iter()
+    .map(|(label, count)| vec![label.to_string(), count.to_string()])
+    .collect::<Vec<_>>();
+  format!("{}\n", table(&[heading, "Count"], &rows))
+}
+
+/// A `<details>` element, which GitHub renders as a collapsed section
+fn details(summary: &str, count: usize, body: &str) -> String {
+  format!(
+    "<details>\n<summary><strong>{}</strong> ({count})</summary>\n\n{body}\n</details>\n\n",
+    escape(summary)
+  )
+}
+
+fn table(headers: &[&str], rows: &[Vec<String>]) -> String {
+  let mut markdown = format!("| {} |\n", headers.join(" | "));
+  markdown.push_str(&format!("|{}\n", " --- |".repeat(headers.len())));
+  for row in rows {
+    markdown.push_str(&format!("| {} |\n", row.join(" | ")));
+  }
+  markdown
+}
+
+fn link(text: &str, url: &str) -> String {
+  format!("[{text}]({url})")
+}
+
+/// Inline code, or `-` when the value is empty, eg. a missing version
+fn code(text: &str) -> String {
+  if text.is_empty() {
+    "-".to_string()
+  } else {
+    format!("`{}`", text.replace('|', "\\|"))
+  }
+}
+
+/// Keep names from breaking out of a table cell or being read as HTML
+fn escape(text: &str) -> String {
+  text.replace('|', "\\|").replace('<', "&lt;").replace('>', "&gt;")
+}
@@ -0,0 +1,125 @@
+use {
+  super::*,
+  crate::{
+    commands::{
+      format, lint,
+      patch::Patch,
+      reporter::{MarkdownReporter, UpdateReporter},
+      update::build_update_rows,
+    },
+    test::{builder::TestBuilder, mock_disk::MockDiskIo},
+  },
+  serde_json::json,
+};
+
+#[tokio::test]
+async fn lint_groups_packages_with_the_same_issue_into_one_row() {
+  let ctx = TestBuilder::new()
+    .with_packages(vec![
+      json!({
+        "name": "package-a",
+        "version": "1.0.0",
+        "dependencies": { "react": "17.0.2" }
+      }),
+      json!({
+        "name": "package-b",
+        "version": "1.0.0",
+        "dependencies": { "react": "17.0.2" }
+      }),
+      json!({
+        "name": "package-c",
+        "version": "1.0.0",
+        "dependencies": { "react": "18.2.0" }
+      }),
+    ])
+    .with_version_group(json!({
+      "label": "Pin <React>",
+      "dependencies": ["react"],
+      "pinVersion": "18.2.0",
+      "severity": { "DiffersToPin": "warn" }
+    }))
+    .run()
+    .await;
+  let reporter = MarkdownReporter::default();
+  assert!(lint::run(ctx, &reporter, &MockDiskIo::new()).is_ok());
+  let markdown = reporter.to_lint_markdown();
+  assert!(markdown.starts_with("## Syncpack lint\n\n| Severity | Count |\n| --- | --- |\n| fix | 0 |\n| error | 0 |\n| warn | 2 |\n"));
+  assert!(markdown.contains("<summary><strong>Pin &lt;React&gt;</strong> (2)</summary>"));
+  let row = markdown.lines().find(|line| line.starts_with("| react ")).unwrap();
+  assert!(row.starts_with("| react | `18.2.0` | "));
+  assert!(row.contains("package-a `17.0.2`"));
+  assert!(row.contains("package-b `17.0.2`"));
+  assert!(row.ends_with(" | [DiffersToPin](https://syncpack.dev/status/differs-to-pin) |"));
+}
+
+#[test]
+fn lint_without_issues_says_so() {
+  assert_eq!(
+    lint_report(&[IssueGroup::new("Default Version Group".to_string())]),
+    "## Syncpack lint\n\nNo issues found\n"
+  );
+}
+
+#[test]
+fn format_lists_unfixed_mismatches_per_package() {
+  let ctx = TestBuilder::new()
+    .with_packages(vec![json!({
+      "name": "package-a",
+      "version": "1.0.0",
+      "keywords": ["b", "a"]
+    })])
+    .with_config(json!({ "sortPackages": false }))
+    .build_and_visit_formatting();
+  let reporter = MarkdownReporter::default();
+  assert!(format::run(ctx, &reporter, &MockDiskIo::new(), &Patch::default()).is_err());
+  assert_eq!(
+    reporter.to_format_markdown(),
+    [
+      "## Syncpack format",
+      "",
+      "| Severity | Count |",
+      "| --- | --- |",
+      "| error | 1 |",
+      "",
+      "<details>",
+      "<summary><strong>package-a</strong> (1)</summary>",
+      "",
+      "| Property | Status |",
+      "| --- | --- |",
+      "| `.keywords` | [PropertyIsNotSortedAz](https://syncpack.dev/config/sort-az) |",
+      "",
+      "</details>",
+      "",
+      "",
+    ]
+    .join("\n")
+  );
+}
+
+#[tokio::test]
+async fn update_totals_each_kind_of_update() {
+  let (ctx, updates) = TestBuilder::new()
+    .with_packages(vec![
+      json!({
+        "name": "package-a",
+        "version": "1.0.0",
+        "dependencies": { "foo": "^1.0.0", "bar": "1.0.0" }
+      }),
+      json!({
+        "name": "package-b",
+        "version": "1.0.0",
+        "dependencies": { "foo": "^1.0.0" }
+      }),
+    ])
+    .with_registry_updates(json!({ "foo": ["1.0.0", "2.0.0"], "bar": ["1.0.0", "1.0.1"] }))
+    .run_with_updates()
+    .await;
+  let rows = build_update_rows(&ctx, &updates.unwrap(), 0);
+  let reporter = MarkdownReporter::default();
+  UpdateReporter::on_rows(&reporter, &ctx, &rows);
+  let markdown = reporter.to_update_markdown();
+  assert!(markdown.contains("| major | 2 |\n| minor | 0 |\n| patch | 1 |\n"));
+  assert!(markdown.contains("<summary><strong>Default Version Group</strong> (3)</summary>"));
+  assert!(markdown.contains("| foo | `^1.0.0` | `^2.0.0` | major | 2 |"));
+  assert!(markdown.contains("| bar | `1.0.0` | `1.0.1` | patch | 1 |"));
+}
@@ -0,0 +1,195 @@
+#[cfg(test)]
+#[path = "matrix_test.rs"]
+mod matrix_test;
+
+use {
+  crate::{
+    context::Context,
+    instance::{Instance, InstanceState},
+  },
+  colored::*,
+  std::collections::BTreeMap,
+};
+
+/// The specifiers one package uses for one dependency
+#[derive(Clone, Debug, PartialEq)]
+pub struct Cell {
+  /// Each distinct specifier, eg. when it is both a dev and peer dependency
+  pub specifiers: Vec<String>,
+  /// The worst status type of its instances
+  pub status_type: &'static str,
+}
+
+impl Cell {
+  pub fn text(&self) -> String {
+    self.specifiers.join(", ")
+  }
+}
+
+/// Packages which use the same specifiers for every dependency
+pub struct Column<'a> {
+  pub packages: Vec<&'a str>,
+  /// Dependency name → cell
+  pub cells: &'a BTreeMap<String, Cell>,
+}
+
+impl Column<'_> {
+  /// Every package in the column, eg. "pkg-a, pkg-b, pkg-c"
+  pub fn heading(&self) -> String {
+    self.packages.join(", ")
+  }
+
+  /// The first package in the column and how many others share it, eg.
+  /// "pkg-a +2", to keep the table narrow
+  pub fn short_heading(&self) -> String {
+    match self.packages.len() {
+      1 => self.packages[0].to_string(),
+      len => format!("{} +{}", self.packages[0], len - 1),
+    }
+  }
+}
+
+/// Every dependency against every package which uses it
+#[derive(Default)]
+pub struct Matrix {
+  /// Dependency names in the order they were listed
+  pub dependencies: Vec<String>,
+  /// Package name → dependency name → cell
+  pub packages: BTreeMap<String, BTreeMap<String, Cell>>,
+}
+
+impl Matrix {
+  pub fn add(&mut self, ctx: &Context, instance: &Instance) {
+    self.insert(
+      ctx.sources.all[instance.source_idx().0].name(),
+      &instance.descriptor.name,
+      instance.descriptor.specifier.get_raw(),
+      instance.state.borrow().get_status_type(),
+    );
+  }
+
+  pub fn insert(&mut self, package: &str, dependency: &str, specifier: &str, status_type: &'static str) {
+    if !self.dependencies.iter().any(|name| name == dependency) {
+      self.dependencies.push(dependency.to_string());
+    }
+    let cells = self.packages.entry(package.to_string()).or_default();
+    match cells.get_mut(dependency) {
+      Some(cell) => {
+        if !cell.specifiers.iter().any(|existing| existing == specifier) {
+          cell.specifiers.push(specifier.to_string());
+        }
+        if InstanceState::get_status_type_rank(status_type) > InstanceState::get_status_type_rank(cell.status_type) {
+          cell.status_type = status_type;
+        }
+      }
+      None => {
+        cells.insert(
+          dependency.to_string(),
+          Cell {
+            specifiers: vec![specifier.to_string()],
+            status_type,
+          },
+        );
+      }
+    }
+  }
+
+  /// One column per package A-Z, with packages whose cells are identical
+  /// collapsed into the column of the first of them
+  pub fn get_columns(&self) -> Vec<Column<'_>> {
+    let mut columns: Vec<Column> = vec![];
+    for (package, cells) in &self.packages {
+      match columns.iter_mut().find(|column| column.cells == cells) {
+        Some(column) => column.packages.push(package),
+        None => columns.push(Column {
+          packages: vec![package],
+          cells,
+        }),
+      }
+    }
+    columns
+  }
+
+  /// An aligned table for the terminal, with mismatched cells coloured by
+  /// their status type, followed by the packages in each collapsed column
+  pub fn to_table(&self) -> Vec<String> {
+    let columns = self.get_columns();
+    let dependency_width = self
+      .dependencies
+      .iter()
+      .map(|name| name.chars().count())
+      .max()
+      .unwrap_or(0)
+      .max("Dependency".len());
+    let widths = columns
+      .iter()
+      .map(|column| {
+        column
+          .cells
+          .values()
+          .map(|cell| cell.text().chars().count())
+          .chain([column.short_heading().chars().count()])
+          .max()
+          .unwrap_or(0)
+      })
+      .collect::<Vec<_>>();
+    let mut header = vec![format!("{:<dependency_width$}", "Dependency").bold().to_string()];
+    columns
+      .iter()
+      .zip(&widths)
+      .for_each(|(column, width)| header.push(format!("{:<width$}", column.short_heading()).bold().to_string()));
+    let mut lines = vec![header.join("  ").trim_end().to_string()];
+    for dependency in &self.dependencies {
+      let mut line = vec![format!("{dependency:<dependency_width$}")];
+      columns.iter().zip(&widths).for_each(|(column, width)| {
+        line.push(match column.cells.get(dependency) {
+          Some(cell) => colorize(format!("{:<width$}", cell.text()), cell.status_type),
+          None => format!("{:<width$}", "-").dimmed().to_string(),
+        });
+      });
+      lines.push(line.join("  ").trim_end().to_string());
+    }
+    columns
+      .iter()
+      .filter(|column| column.packages.len() > 1)
+      .for_each(|column| lines.push(format!("{}: {}", column.short_heading(), column.heading()).dimmed().to_string()));
+    lines
+  }
+
+  /// The same table as CSV, with an empty field where a package does not use
+  /// a dependency
+  pub fn to_csv(&self) -> String {
+    let columns = self.get_columns();
+    let mut header = vec![csv_field("Dependency")];
+    header.extend(columns.iter().map(|column| csv_field(&column.heading())));
+    let mut csv = format!("{}\n", header.join(","));
+    for dependency in &self.dependencies {
+      let mut row = vec![csv_field(dependency)];
+      row.extend(
+        columns
+          .iter()
+          .map(|column| column.cells.get(dependency).map(|cell| csv_field(&cell.text())).unwrap_or_default()),
+      );
+      csv.push_str(&format!("{}\n", row.join(",")));
+    }
+    csv
+  }
+}
+
+fn colorize(text: String, status_type: &str) -> String {
+  match status_type {
+    "Suspect" => text.yellow().to_string(),
+    "Fixable" | "Unfixable" => text.red().to_string(),
+    "Conflict" => text.magenta().to_string(),
+    _ => text,
+  }
+}
+
+/// Quote a field when it contains a delimiter, a quote or a line break
+fn csv_field(text: &str) -> String {
+  if text.contains([',', '"', '\n', '\r']) {
+    format!("\"{}\"", text.replace('"', "\"\""))
+  } else {
+    text.to_string()
+  }
+}
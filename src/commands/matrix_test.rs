@@ -0,0 +1,73 @@
+use {
+  super::*,
+  crate::{
+    commands::{list, reporter::MatrixReporter},
+    test::builder::TestBuilder,
+  },
+  serde_json::json,
+};
+
+#[test]
+fn packages_with_the_same_specifiers_share_a_column() {
+  let mut matrix = Matrix::default();
+  matrix.insert("pkg-c", "react", "18.2.0", "Valid");
+  matrix.insert("pkg-a", "react", "17.0.2", "Fixable");
+  matrix.insert("pkg-b", "react", "18.2.0", "Valid");
+  matrix.insert("pkg-b", "lodash", "4.17.21", "Valid");
+  matrix.insert("pkg-c", "lodash", "4.17.21", "Valid");
+  let columns = matrix.get_columns();
+  assert_eq!(
+    columns.iter().map(|column| column.heading()).collect::<Vec<_>>(),
+    vec!["pkg-a", "pkg-b, pkg-c"]
+  );
+  assert_eq!(columns[1].short_heading(), "pkg-b +1");
+  assert_eq!(
+    matrix.to_csv(),
+    "Dependency,pkg-a,\"pkg-b, pkg-c\"\nreact,17.0.2,18.2.0\nlodash,,4.17.21\n"
+  );
+}
+
+#[test]
+fn a_cell_has_every_distinct_specifier_and_the_worst_status_type() {
+  let mut matrix = Matrix::default();
+  matrix.insert("pkg-a", "react", "^18.0.0", "Suspect");
+  matrix.insert("pkg-a", "react", "18.2.0", "Conflict");
+  matrix.insert("pkg-a", "react", "18.2.0", "Valid");
+  assert_eq!(
+    matrix.packages["pkg-a"]["react"],
+    Cell {
+      specifiers: vec!["^18.0.0".to_string(), "18.2.0".to_string()],
+      status_type: "Conflict",
+    }
+  );
+}
+
+#[test]
+fn csv_fields_are_quoted_when_needed() {
+  assert_eq!(csv_field("1.0.0"), "1.0.0");
+  assert_eq!(csv_field(">=1.0.0 <2.0.0, 3.0.0"), "\">=1.0.0 <2.0.0, 3.0.0\"");
+  assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+}
+
+#[test]
+fn cells_have_the_status_type_of_their_instances() {
+  let ctx = TestBuilder::new()
+    .with_packages(vec![
+      json!({
+        "name": "package-a",
+        "version": "1.0.0",
+        "dependencies": { "react": "17.0.2" }
+      }),
+      json!({
+        "name": "package-b",
+        "version": "1.0.0",
+        "dependencies": { "react": "18.2.0" }
+      }),
+    ])
+    .build_and_visit_packages();
+  let mut matrix = Matrix::default();
+  ctx.instances.iter().for_each(|instance| matrix.add(&ctx, instance));
+  assert_eq!(matrix.packages["package-a"]["react"].status_type, "Fixable");
+  assert_eq!(matrix.packages["package-b"]["react"].status_type, "Valid");
+  assert!(list::run(ctx, &MatrixReporter::new(true)).is_err());
+}
@@ -0,0 +1,220 @@
+#[cfg(test)]
+#[path = "migrate_config_test.rs"]
+mod migrate_config_test;
+
+use {
+  crate::{
+    cli::Cli,
+    commands::ui,
+    disk::{Disk, DiskIo, DiskIoError, File, PendingYamlOp, detect_formatting, get_pretty_json_bytes, render_yaml_bytes, yaml_to_json},
+    errors::SyncpackError,
+    rcfile::{
+      from_disk::{RCFILE_NAMES, RcfileFormat},
+      migrate::{Migration, migrate},
+    },
+    span::SpanIndex,
+  },
+  log::{debug, info},
+  serde_json::{Map, Value},
+  std::path::{Path, PathBuf},
+};
+
+/// Where the config being migrated lives
+#[derive(Debug)]
+enum ConfigLocation {
+  /// A standalone config file such as `.syncpackrc.yaml`
+  File { filepath: PathBuf, format: RcfileFormat },
+  /// The `syncpack` or `config.syncpack` property of the root package.json
+  PackageJson { filepath: PathBuf, pointer: &'static str },
+}
+
+/// The original and rewritten contents of the config file
+struct Rewrite {
+  before: String,
+  after: String,
+  migrations: Vec<Migration>,
+}
+
+/// Run the migrate-config command side effects
+pub fn run<D: DiskIo>(cli: &Cli, io: &D) -> Result<(), SyncpackError> {
+  let disk = Disk::from_workspace(io, &cli.cwd);
+  let location = find_config(cli, &disk, io).ok_or(SyncpackError::NoConfigFile)?;
+  debug!("Migrating config at {location:?}");
+  let (filepath, rewrite) = match &location {
+    ConfigLocation::File {
+      filepath,
+      format: RcfileFormat::JavaScript,
+    } => {
+      return Err(SyncpackError::CannotMigrateJavaScriptConfig(relative_path(&cli.cwd, filepath)));
+    }
+    ConfigLocation::File {
+      filepath,
+      format: format @ (RcfileFormat::Json5 | RcfileFormat::Toml),
+    } => {
+      let relative = relative_path(&cli.cwd, filepath);
+      let migrations = read_migrations(io, filepath, *format)?;
+      if migrations.is_empty() {
+        info!("{} {relative} is already up to date", ui::icon::ok());
+        return Ok(());
+      }
+      migrations.iter().for_each(|migration| info!("{} {migration}", ui::icon::warn()));
+      return Err(SyncpackError::CannotRewriteConfig(relative));
+    }
+    ConfigLocation::File {
+      filepath,
+      format: RcfileFormat::Yaml,
+    } => (filepath, rewrite_yaml(io, filepath)?),
+    ConfigLocation::File {
+      filepath,
+      format: RcfileFormat::Json,
+    } => (filepath, rewrite_json(io, filepath, "")?),
+    ConfigLocation::PackageJson { filepath, pointer } => (filepath, rewrite_json(io, filepath, pointer)?),
+  };
+  let relative = relative_path(&cli.cwd, filepath);
+
+  if rewrite.migrations.is_empty() {
+    info!("{} {relative} is already up to date", ui::icon::ok());
+    return Ok(());
+  }
+
+  rewrite.migrations.iter().for_each(|migration| {
+    let icon = if migration.is_manual() { ui::icon::warn() } else { ui::icon::ok() };
+    info!("{icon} {migration}");
+  });
+
+  if cli.dry_run {
+    ui::diff::print_unified_diff(&relative, &rewrite.before, &rewrite.after);
+  } else if rewrite.before != rewrite.after {
+    io.write_bytes(filepath, rewrite.after.as_bytes())?;
+    info!("{} Updated {relative}", ui::icon::ok());
+  }
+
+  Ok(())
+}
+
+/// Locate the config file using the same order as `Rcfile::from_disk`
+fn find_config<D: DiskIo>(cli: &Cli, disk: &Disk, io: &D) -> Option<ConfigLocation> {
+  let as_file = |filepath: PathBuf| ConfigLocation::File {
+    format: RcfileFormat::from_path(&filepath),
+    filepath,
+  };
+  cli
+    .config_path
+    .clone()
+    .map(as_file)
+    .or_else(|| {
+      RCFILE_NAMES
+        .iter()
+        .map(|name| disk.cwd.join(name))
+        .find(|filepath| io.path_exists(filepath))
+        .map(as_file)
+    })
+    .or_else(|| {
+      disk.package_json_root().and_then(|file| {
+        ["/syncpack", "/config/syncpack"]
+          .into_iter()
+          .find(|pointer| file.contents.pointer(pointer).is_some())
+          .map(|pointer| ConfigLocation::PackageJson {
+            filepath: file.filepath.clone(),
+            pointer,
+          })
+      })
+    })
+}
+
+/// Migrate a JSON file, or the config object at `pointer` within it when the
+/// config is embedded in package.json. An empty `pointer` is the whole file.
+fn rewrite_json<D: DiskIo>(io: &D, filepath: &Path, pointer: &str) -> Result<Rewrite, SyncpackError> {
+  let before = read_text(io, filepath)?;
+  let mut contents: Value = serde_json::from_str(&before).map_err(DiskIoError::JsonParse)?;
+  let migrations = contents
+    .pointer_mut(pointer)
+    .and_then(|config| config.as_object_mut())
+    .map(migrate)
+    .unwrap_or_default();
+  let after = if migrations.is_empty() {
+    before.clone()
+  } else {
+    let file = File {
+      filepath: filepath.to_path_buf(),
+      formatting: detect_formatting(&before),
+      contents,
+      dirty: true,
+      spans: SpanIndex::default(),
+      raw: None,
+    };
+    String::from_utf8_lossy(&get_pretty_json_bytes(&file)?).into_owned()
+  };
+  Ok(Rewrite { before, after, migrations })
+}
+
+/// List the changes needed by a format which cannot be rewritten without
+/// losing comments, such as JSON5 and TOML.
+fn read_migrations<D: DiskIo>(io: &D, filepath: &Path, format: RcfileFormat) -> Result<Vec<Migration>, SyncpackError> {
+  let file = format
+    .read::<Map<String, Value>, D>(io, filepath)
+    .ok_or(SyncpackError::NoConfigFile)??;
+  let mut config = file.contents;
+  Ok(migrate(&mut config))
+}
+
+/// Migrate a YAML file. Only the top-level properties which change are
+/// rewritten via `yamlpatch`, so comments and formatting elsewhere survive.
+fn rewrite_yaml<D: DiskIo>(io: &D, filepath: &Path) -> Result<Rewrite, SyncpackError> {
+  let mut file = io.read_yaml_file(filepath).ok_or(SyncpackError::NoConfigFile)??;
+  let before = file.raw.clone();
+  let Value::Object(original) = yaml_to_json(&file.contents) else {
+    return Ok(Rewrite {
+      after: before.clone(),
+      before,
+      migrations: vec![],
+    });
+  };
+  let mut migrated = original.clone();
+  let migrations = migrate(&mut migrated);
+  file.patches = top_level_patches(&original, &migrated)?;
+  let after = if file.patches.is_empty() {
+    before.clone()
+  } else {
+    String::from_utf8_lossy(&render_yaml_bytes(&file)?).into_owned()
+  };
+  Ok(Rewrite { before, after, migrations })
+}
+
+/// Describe the difference between two config objects as edits to their
+/// top-level properties.
+fn top_level_patches(original: &Map<String, Value>, migrated: &Map<String, Value>) -> Result<Vec<PendingYamlOp>, DiskIoError> {
+  let mut patches = vec![];
+  for key in original.keys().filter(|key| !migrated.contains_key(*key)) {
+    patches.push(PendingYamlOp::Remove {
+      segments: vec![key.clone()],
+    });
+  }
+  for (key, value) in migrated {
+    let yaml_value = yaml_serde::to_value(value).map_err(DiskIoError::YamlSerialize)?;
+    match original.get(key) {
+      None => patches.push(PendingYamlOp::Add {
+        segments: vec![],
+        key: key.clone(),
+        value: yaml_value,
+      }),
+      Some(previous) if previous != value => patches.push(PendingYamlOp::Replace {
+        segments: vec![key.clone()],
+        value: yaml_value,
+      }),
+      Some(_) => {}
+    }
+  }
+  Ok(patches)
+}
+
+fn read_text<D: DiskIo>(io: &D, filepath: &Path) -> Result<String, SyncpackError> {
+  io.read_textfile(filepath)
+    .ok_or(SyncpackError::NoConfigFile)?
+    .map(|file| file.contents)
+    .map_err(SyncpackError::DiskIoError)
+}
+
+fn relative_path(cwd: &Path, filepath: &Path) -> String {
+  filepath.strip_prefix(cwd).unwrap_or(filepath).to_string_lossy().to_string()
+}
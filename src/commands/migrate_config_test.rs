@@ -0,0 +1,121 @@
+use {
+  crate::{
+    commands::migrate_config,
+    errors::SyncpackError,
+    test::{mock, mock_disk::MockDiskIo},
+  },
+  serde_json::{Value, json},
+};
+
+fn cli(dry_run: bool) -> crate::cli::Cli {
+  let mut cli = mock::cli();
+  cli.dry_run = dry_run;
+  cli
+}
+
+fn written_json(io: &MockDiskIo, relative_path: &str) -> Value {
+  let text = io.written_text(&io.root().join(relative_path)).expect("file should be written");
+  serde_json::from_str(&text).unwrap()
+}
+
+#[test]
+fn rewrites_a_json_rcfile() {
+  let mut io = MockDiskIo::new();
+  io.add_json("package.json", &json!({ "name": "root" }));
+  io.add_json(
+    ".syncpackrc.json",
+    &json!({
+      "lintVersions": true,
+      "versionGroups": [{ "dependencyTypes": ["workspace"], "isIgnored": true }]
+    }),
+  );
+  migrate_config::run(&cli(false), &io).unwrap();
+  assert_eq!(
+    written_json(&io, ".syncpackrc.json"),
+    json!({
+      "versionGroups": [{ "dependencyTypes": ["local"], "isIgnored": true }]
+    })
+  );
+}
+
+#[test]
+fn rewrites_a_yaml_rcfile_and_keeps_comments() {
+  let mut io = MockDiskIo::new();
+  io.add_json("package.json", &json!({ "name": "root" }));
+  io.add_file(
+    ".syncpackrc.yaml",
+    "# shared settings\nlintFormatting: false\nsortAz:\n  - name\n".to_string(),
+  );
+  migrate_config::run(&cli(false), &io).unwrap();
+  let text = io
+    .written_text(&io.root().join(".syncpackrc.yaml"))
+    .expect("file should be written");
+  assert!(text.contains("# shared settings"));
+  assert!(!text.contains("lintFormatting"));
+  assert!(text.contains("sortAz"));
+}
+
+#[test]
+fn rewrites_config_embedded_in_package_json() {
+  let mut io = MockDiskIo::new();
+  io.add_json(
+    "package.json",
+    &json!({
+      "name": "root",
+      "syncpack": { "lintSemverRanges": true, "sortFirst": ["name"] }
+    }),
+  );
+  migrate_config::run(&cli(false), &io).unwrap();
+  assert_eq!(
+    written_json(&io, "package.json"),
+    json!({
+      "name": "root",
+      "syncpack": { "sortFirst": ["name"] }
+    })
+  );
+}
+
+#[test]
+fn dry_run_does_not_write() {
+  let mut io = MockDiskIo::new();
+  io.add_json("package.json", &json!({ "name": "root" }));
+  io.add_json(".syncpackrc", &json!({ "lintVersions": true }));
+  migrate_config::run(&cli(true), &io).unwrap();
+  assert!(io.written_text(&io.root().join(".syncpackrc")).is_none());
+}
+
+#[test]
+fn up_to_date_config_is_not_written() {
+  let mut io = MockDiskIo::new();
+  io.add_json("package.json", &json!({ "name": "root" }));
+  io.add_json(".syncpackrc", &json!({ "sortFirst": ["name"] }));
+  migrate_config::run(&cli(false), &io).unwrap();
+  assert!(io.written_text(&io.root().join(".syncpackrc")).is_none());
+}
+
+#[test]
+fn javascript_config_is_rejected() {
+  let mut io = MockDiskIo::new();
+  io.add_json("package.json", &json!({ "name": "root" }));
+  io.add_file("syncpack.config.js", "module.exports = {};".to_string());
+  let result = migrate_config::run(&cli(false), &io);
+  assert!(matches!(result, Err(SyncpackError::CannotMigrateJavaScriptConfig(_))));
+}
+
+#[test]
+fn missing_config_is_an_error() {
+  let mut io = MockDiskIo::new();
+  io.add_json("package.json", &json!({ "name": "root" }));
+  let result = migrate_config::run(&cli(false), &io);
+  assert!(matches!(result, Err(SyncpackError::NoConfigFile)));
+}
+
+#[test]
+fn toml_config_lists_changes_without_rewriting() {
+  let mut io = MockDiskIo::new();
+  io.add_json("package.json", &json!({ "name": "root" }));
+  io.add_file("syncpack.toml", "# keep me\nlintVersions = true\n".to_string());
+  let result = migrate_config::run(&cli(false), &io);
+  assert!(matches!(result, Err(SyncpackError::CannotRewriteConfig(_))));
+  assert!(io.written_text(&io.root().join("syncpack.toml")).is_none());
+}
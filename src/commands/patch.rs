@@ -0,0 +1,80 @@
+#[cfg(test)]
+#[path = "patch_test.rs"]
+mod patch_test;
+
+use {
+  crate::{
+    cli::{Cli, ReporterKind},
+    commands::{reporter, ui},
+    context::Context,
+    disk::{self, DiskIo},
+    errors::SyncpackError,
+  },
+  log::info,
+  std::{cell::RefCell, path::Path},
+};
+
+/// The diffs of every workspace root, saved as one `.patch` file once they
+/// have all run
+#[derive(Default)]
+pub struct Patch {
+  diffs: RefCell<Vec<String>>,
+}
+
+impl Patch {
+  /// Save every diff as one `.patch` file when `--patch` is set
+  pub fn write<D: DiskIo>(&self, cli: &Cli, io: &D) -> Result<(), SyncpackError> {
+    if let Some(patch_path) = &cli.patch_path {
+      io.write_bytes(patch_path, self.diffs.borrow().concat().as_bytes())?;
+      if matches!(cli.reporter, ReporterKind::Pretty) {
+        info!("{} Wrote {}", ui::icon::ok(), reporter::to_uri(&cli.invocation_cwd, patch_path));
+      }
+    }
+    Ok(())
+  }
+}
+
+/// Print a unified diff of every file `--dry-run` kept from being written,
+/// and add them to the `patch`
+pub fn run<D: DiskIo>(ctx: &Context, io: &D, patch: &Patch) -> Result<(), SyncpackError> {
+  let diffs = get_diffs(ctx, io)?;
+  if matches!(ctx.config.cli.reporter, ReporterKind::Pretty) {
+    diffs.iter().for_each(|diff| ui::diff::print_diff(diff));
+  }
+  patch.diffs.borrow_mut().extend(diffs);
+  Ok(())
+}
+
+/// A diff per file which has changed since it was read, in the order they
+/// would be written
+pub fn get_diffs<D: DiskIo>(ctx: &Context, io: &D) -> Result<Vec<String>, SyncpackError> {
+  let indent = ctx.config.rcfile.indent.as_deref();
+  let fallback = ctx.disk.formatting_fallback();
+  let mut diffs: Vec<String> = vec![];
+  for file in ctx.disk.package_json_files.iter().chain(ctx.disk.deno_json_files.iter()) {
+    if file.dirty {
+      let after = disk::render_json_bytes(file, indent, &fallback)?;
+      diffs.push(to_diff(ctx, io, &file.filepath, &after)?);
+    }
+  }
+  for file in ctx.disk.pnpm_workspace.iter().chain(ctx.disk.yarnrc.iter()) {
+    if file.dirty {
+      let after = disk::render_yaml_bytes(file)?;
+      diffs.push(to_diff(ctx, io, &file.filepath, &after)?);
+    }
+  }
+  diffs.retain(|diff| !diff.is_empty());
+  Ok(diffs)
+}
+
+/// Compare what is on disk now with what would be written
+fn to_diff<D: DiskIo>(ctx: &Context, io: &D, filepath: &Path, after: &[u8]) -> Result<String, SyncpackError> {
+  let before = io.read_bytes(filepath).transpose()?;
+  let before = before.as_deref().map(String::from_utf8_lossy);
+  let relative_path = reporter::get_uri(ctx, filepath);
+  Ok(ui::diff::file_diff(
+    &relative_path,
+    before.as_deref(),
+    &String::from_utf8_lossy(after),
+  ))
+}
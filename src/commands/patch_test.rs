@@ -0,0 +1,109 @@
+use {
+  super::*,
+  crate::{
+    commands::{fix, reporter::JsonFixReporter},
+    test::{builder::TestBuilder, mock_disk::MockDiskIo},
+  },
+  serde_json::json,
+  std::path::PathBuf,
+};
+
+/// Run `fix --dry-run`, with the files it read still on disk
+fn fix_dry_run(builder: TestBuilder) -> (Context, MockDiskIo) {
+  let ctx = builder.build_and_visit_packages();
+  let mut io = MockDiskIo::new();
+  ctx.disk.package_json_files.iter().for_each(|file| {
+    io.add_file(
+      file.filepath.to_str().unwrap(),
+      String::from_utf8(disk::render_json_bytes(file, None, &file.formatting).unwrap()).unwrap(),
+    );
+  });
+  ctx.disk.pnpm_workspace.iter().for_each(|file| {
+    io.add_file(file.filepath.to_str().unwrap(), file.raw.clone());
+  });
+  let ctx = fix::run(ctx, &JsonFixReporter, &io, &Patch::default()).unwrap();
+  (ctx, io)
+}
+
+#[test]
+fn diffs_each_file_which_would_be_written() {
+  let (ctx, io) = fix_dry_run(TestBuilder::new().with_packages(vec![
+    json!({
+      "name": "package-a",
+      "version": "1.0.0",
+      "dependencies": { "react": "17.0.2" }
+    }),
+    json!({
+      "name": "package-b",
+      "version": "1.0.0",
+      "dependencies": { "react": "18.2.0" }
+    }),
+  ]));
+  let diffs = get_diffs(&ctx, &io).unwrap();
+  assert_eq!(diffs.len(), 1);
+  assert!(diffs[0].starts_with("--- a/"));
+  assert!(diffs[0].contains("/packages/package-a/package.json\n+++ b/"));
+  assert!(diffs[0].contains("\n-    \"react\": \"17.0.2\"\n+    \"react\": \"18.2.0\"\n"));
+}
+
+#[test]
+fn yaml_files_keep_their_comments() {
+  let (ctx, io) = fix_dry_run(
+    TestBuilder::new()
+      .with_pnpm_catalogs("# shared versions\ncatalog:\n  lodash: ^4.0.0\n")
+      .with_packages(vec![json!({
+        "name": "package-a",
+        "version": "1.0.0",
+        "dependencies": { "react": "^18.0.0" }
+      })])
+      .with_version_group(json!({
+        "dependencies": ["react"],
+        "policy": "catalog"
+      })),
+  );
+  let diffs = get_diffs(&ctx, &io).unwrap();
+  let yaml_diff = diffs.iter().find(|diff| diff.contains("pnpm-workspace.yaml")).unwrap();
+  assert!(yaml_diff.contains("\n # shared versions\n"));
+}
+
+#[test]
+fn files_which_do_not_exist_yet_are_created_by_the_patch() {
+  let (ctx, _) = fix_dry_run(
+    TestBuilder::new()
+      .with_pnpm_package_manager()
+      .with_packages(vec![json!({
+        "name": "package-a",
+        "version": "1.0.0",
+        "dependencies": { "react": "^18.0.0" }
+      })])
+      .with_version_group(json!({
+        "dependencies": ["react"],
+        "policy": "catalog"
+      })),
+  );
+  let diffs = get_diffs(&ctx, &MockDiskIo::new()).unwrap();
+  let yaml_diff = diffs.iter().find(|diff| diff.contains("pnpm-workspace.yaml")).unwrap();
+  assert!(yaml_diff.starts_with("--- /dev/null\n+++ b/"));
+}
+
+#[test]
+fn writes_every_diff_to_the_patch_file() {
+  let (mut ctx, io) = fix_dry_run(TestBuilder::new().with_packages(vec![
+    json!({
+      "name": "package-a",
+      "version": "1.0.0",
+      "dependencies": { "react": "17.0.2" }
+    }),
+    json!({
+      "name": "package-b",
+      "version": "1.0.0",
+      "dependencies": { "react": "18.2.0" }
+    }),
+  ]));
+  let patch_path = PathBuf::from("/test/syncpack.patch");
+  ctx.config.cli.patch_path = Some(patch_path.clone());
+  let patch = Patch::default();
+  run(&ctx, &io, &patch).unwrap();
+  patch.write(&ctx.config.cli, &io).unwrap();
+  assert_eq!(io.written_text(&patch_path), Some(get_diffs(&ctx, &io).unwrap().concat()));
+}
@@ -0,0 +1,121 @@
+#[cfg(test)]
+#[path = "report_test.rs"]
+mod report_test;
+
+use {
+  crate::{
+    commands::{
+      html,
+      json::instance_to_json,
+      matrix::Matrix,
+      reporter::{self, JsonUpdateReporter},
+      ui::{self, update_row},
+      update::build_update_rows,
+    },
+    context::Context,
+    disk::DiskIo,
+    errors::SyncpackError,
+    registry::updates::RegistryUpdates,
+    visit_packages::visit_packages,
+  },
+  log::info,
+  serde_json::{Value, json},
+};
+
+/// Everything in the report, built from the same JSON as `syncpack json` and
+/// `update --reporter json` so that they never disagree
+pub struct Report {
+  /// Name of every package with at least one instance, A-Z
+  pub packages: Vec<String>,
+  pub groups: Vec<ReportGroup>,
+  /// Every dependency against every package which uses it
+  pub matrix: Matrix,
+  /// Every available update, or `None` when no versions could be fetched
+  /// from the npm registry
+  pub updates: Option<Vec<Value>>,
+  /// Dependencies whose versions could not be fetched from the npm registry
+  pub failed: Vec<String>,
+}
+
+/// Every instance in one version group
+pub struct ReportGroup {
+  pub label: String,
+  /// Each is the output of `instance_to_json`, with the package's
+  /// `packageName` and its `package` path relative to the project root
+  pub instances: Vec<Value>,
+}
+
+impl Report {
+  /// Read the status of every instance. Must be called before the instances
+  /// are visited again with registry updates, which replaces their status.
+  pub fn new(ctx: &Context) -> Self {
+    let mut matrix = Matrix::default();
+    let groups: Vec<ReportGroup> = ctx
+      .version_groups
+      .iter()
+      .map(|group| ReportGroup {
+        label: reporter::get_group_label(group),
+        instances: group
+          .get_sorted_dependencies(&ctx.config.cli.sort)
+          .flat_map(|dependency| dependency.get_sorted_instances(&ctx.instances, &ctx.sources.all))
+          .map(|(_, instance)| {
+            matrix.add(ctx, instance);
+            let mut value = instance_to_json(ctx, instance, group.variant_label());
+            let source = &ctx.sources.all[instance.source_idx().0];
+            value["packageName"] = json!(source.name());
+            if let Some(file_path) = reporter::get_source_file_path(ctx, source) {
+              value["package"] = json!(reporter::to_uri(&ctx.disk.cwd, &file_path));
+            }
+            value
+          })
+          .collect(),
+      })
+      .filter(|group| !group.instances.is_empty())
+      .collect();
+    Self {
+      packages: matrix.packages.keys().cloned().collect(),
+      groups,
+      matrix,
+      updates: None,
+      failed: vec![],
+    }
+  }
+
+  /// Add the rows `update` would show for the versions fetched from the npm
+  /// registry
+  pub fn set_updates(&mut self, ctx: &Context, registry_updates: &RegistryUpdates) {
+    let mut failed = registry_updates.failed.clone();
+    failed.sort();
+    self.failed = failed;
+    if registry_updates.updates_by_internal_name.is_empty() {
+      self.updates = None;
+      return;
+    }
+    let rows = build_update_rows(ctx, registry_updates, update_row::unix_now());
+    self.updates = Some(rows.iter().map(|row| JsonUpdateReporter::row_to_json(ctx, row)).collect());
+  }
+}
+
+/// Write an HTML report of every instance, grouped by version group and by
+/// package, and of the updates available from the npm registry
+pub fn run<D: DiskIo>(ctx: Context, registry_updates: Option<RegistryUpdates>, io: &D) -> Result<Context, SyncpackError> {
+  let mut report = Report::new(&ctx);
+  let ctx = match &registry_updates {
+    Some(updates) => {
+      let ctx = visit_packages(ctx, &registry_updates);
+      report.set_updates(&ctx, updates);
+      ctx
+    }
+    None => ctx,
+  };
+  let html = html::report(&report);
+  match &ctx.config.cli.out_path {
+    Some(out_path) => {
+      let out_path = ctx.disk.cwd.join(out_path);
+      io.write_bytes(&out_path, html.as_bytes())?;
+      info!("{} Wrote {}", ui::icon::ok(), reporter::to_uri(&ctx.disk.cwd, &out_path));
+    }
+    None => print!("{html}"),
+  }
+  Ok(ctx)
+}
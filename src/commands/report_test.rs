@@ -0,0 +1,95 @@
+use {super::*, crate::test::builder::TestBuilder, serde_json::json};
+
+fn packages() -> Vec<Value> {
+  vec![
+    json!({
+      "name": "package-b",
+      "version": "1.0.0",
+      "dependencies": { "react": "17.0.2" }
+    }),
+    json!({
+      "name": "package-a",
+      "version": "1.0.0",
+      "dependencies": { "react": "18.2.0", "lodash": "4.0.0" }
+    }),
+  ]
+}
+
+#[test]
+fn every_instance_is_reported_with_its_package() {
+  let ctx = TestBuilder::new().with_packages(packages()).build_and_visit_packages();
+  let report = Report::new(&ctx);
+  assert_eq!(report.packages, vec!["package-a", "package-b"]);
+  assert_eq!(report.groups.len(), 1);
+  let react_b = report.groups[0]
+    .instances
+    .iter()
+    .find(|instance| instance["dependency"] == "react" && instance["packageName"] == "package-b")
+    .unwrap();
+  assert_eq!(react_b["statusCode"], "DiffersToHighestOrLowestSemver");
+  assert!(react_b["package"].as_str().unwrap().ends_with("packages/package-b/package.json"));
+  assert!(report.updates.is_none());
+}
+
+#[test]
+fn html_has_a_badge_per_status_and_a_column_per_package() {
+  let ctx = TestBuilder::new().with_packages(packages()).build_and_visit_packages();
+  let html = html::report(&Report::new(&ctx));
+  assert!(html.starts_with("<!DOCTYPE html>"));
+  assert!(html.contains(
+    "<a class=\"badge fixable\" href=\"https://syncpack.dev/status/differs-to-highest-or-lowest-semver\">DiffersToHighestOrLowestSemver</a>"
+  ));
+  assert!(html.contains("<tr><th>Dependency</th><th>package-a</th><th>package-b</th></tr>"));
+  assert!(
+    html.contains("<tr><td>react</td><td class=\"valid\"><code>18.2.0</code></td><td class=\"fixable\"><code>17.0.2</code></td></tr>")
+  );
+  assert!(html.contains("No versions could be fetched from the npm registry"));
+  assert!(
+    !html.contains("src=") && !html.contains("<link"),
+    "the report should not load any external assets"
+  );
+}
+
+#[tokio::test]
+async fn updates_are_added_without_replacing_the_status_of_each_instance() {
+  let (ctx, registry_updates) = TestBuilder::new()
+    .with_packages(packages())
+    .with_subcommand("report")
+    .with_registry_updates(json!({ "react": ["17.0.2", "18.2.0", "19.0.0"] }))
+    .run_with_updates()
+    .await;
+  let mut report = Report::new(&ctx);
+  let ctx = visit_packages(ctx, &registry_updates);
+  report.set_updates(&ctx, registry_updates.as_ref().unwrap());
+  let updates = report.updates.as_ref().unwrap();
+  assert_eq!(updates.len(), 2);
+  assert!(
+    updates
+      .iter()
+      .all(|update| update["dependency"] == "react" && update["target"]["raw"] == "19.0.0")
+  );
+  assert_eq!(report.failed, vec!["lodash"]);
+  assert!(
+    report.groups[0]
+      .instances
+      .iter()
+      .all(|instance| instance["statusCode"] != "DiffersToNpmRegistry")
+  );
+  let html = html::report(&report);
+  assert!(html.contains("<tr><td>Outdated</td><td>2</td></tr>"));
+  assert!(html.contains("Versions could not be fetched for <code>lodash</code>"));
+}
+
+#[test]
+fn names_are_escaped() {
+  let ctx = TestBuilder::new()
+    .with_packages(vec![json!({
+      "name": "package-a",
+      "version": "1.0.0",
+      "dependencies": { "<script>alert(1)</script>": "1.0.0" }
+    })])
+    .build_and_visit_packages();
+  let html = html::report(&Report::new(&ctx));
+  assert!(!html.contains("<script>alert"));
+  assert!(html.contains("&lt;script&gt;alert(1)&lt;/script&gt;"));
+}
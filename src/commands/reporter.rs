@@ -1,13 +1,29 @@
 use {
   crate::{
-    commands::{json::instance_to_json, ui},
+    commands::{
+      github::Annotations,
+      json::instance_to_json,
+      junit::{self, Outcome, TestCase, TestSuite},
+      markdown::{self, IssueGroup, MismatchGroup},
+      matrix::Matrix,
+      sarif,
+      ui::{
+        self,
+        update_row::{self, DiffKind},
+      },
+    },
     context::Context,
-    instance::Instance,
-    source::FormatMismatch,
-    version_group::{DependencyCore, VersionGroup},
+    instance::{Instance, InstanceId, Severity},
+    source::{FormatMismatch, Source},
+    tui::UpdateRow,
+    version_group::{DependencyCore, VersionGroup, VersionGroupBehavior},
+  },
+  serde_json::{Value, json},
+  std::{
+    cell::RefCell,
+    collections::HashMap,
+    path::{Path, PathBuf},
   },
-  serde_json::json,
-  std::path::Path,
 };
 
 pub trait FixReporter {
@@ -16,6 +32,10 @@ pub trait FixReporter {
   fn on_instance(&self, ctx: &Context, instance: &Instance, variant: &str);
   fn on_no_issues(&self);
   fn on_unfixable_warning(&self);
+  /// Called once every instance has been visited
+  fn on_complete(&self, ctx: &Context);
+  /// Called once every workspace root has completed
+  fn on_finish(&self) {}
 }
 
 pub trait FormatReporter {
@@ -23,6 +43,34 @@ pub trait FormatReporter {
   fn on_mismatch_fixed(&self, ctx: &Context, name: &str, filepath: &Path, mismatch: &FormatMismatch);
   fn on_mismatch_unfixed(&self, ctx: &Context, name: &str, filepath: &Path, mismatch: &FormatMismatch);
   fn on_no_issues(&self);
+  /// Called once every package has been visited
+  fn on_complete(&self, ctx: &Context);
+  /// Called once every workspace root has completed
+  fn on_finish(&self) {}
+}
+
+/// Used by `lint` to report invalid instances and by `list` to report all
+/// of them
+pub trait LintReporter {
+  fn on_group_header(&self, ctx: &Context, group: &VersionGroup);
+  fn on_dependency(&self, ctx: &Context, dependency: &DependencyCore, variant: &str);
+  fn on_instance(&self, ctx: &Context, instance: &Instance);
+  fn on_no_issues(&self);
+  /// Called once every instance has been visited
+  fn on_complete(&self, ctx: &Context);
+  /// Called once every workspace root has completed
+  fn on_finish(&self) {}
+}
+
+pub trait UpdateReporter {
+  /// Called once with every outdated instance, bucketed by version group,
+  /// dependency and current specifier
+  fn on_rows(&self, ctx: &Context, rows: &[UpdateRow]);
+  fn on_no_issues(&self);
+  /// Called once the registry has been checked
+  fn on_complete(&self, ctx: &Context);
+  /// Called once every workspace root has completed
+  fn on_finish(&self) {}
 }
 
 // — Pretty implementations —
@@ -52,6 +100,8 @@ impl FixReporter for PrettyFixReporter {
     println!(" ");
     log::warn!("Some issues remain which cannot be fixed automatically, run syncpack lint to view them");
   }
+
+  fn on_complete(&self, _ctx: &Context) {}
 }
 
 pub struct PrettyFormatReporter;
@@ -61,17 +111,58 @@ impl FormatReporter for PrettyFormatReporter {
     ui::package::print_invalid_package(ctx, name, filepath, mismatch_count);
   }
 
-  fn on_mismatch_fixed(&self, ctx: &Context, _name: &str, _filepath: &Path, mismatch: &FormatMismatch) {
-    ui::package::print_fixed(ctx, mismatch);
+  fn on_mismatch_fixed(&self, ctx: &Context, _name: &str, filepath: &Path, mismatch: &FormatMismatch) {
+    ui::package::print_fixed(ctx, filepath, mismatch);
   }
 
-  fn on_mismatch_unfixed(&self, ctx: &Context, _name: &str, _filepath: &Path, mismatch: &FormatMismatch) {
-    ui::package::print_invalid(ctx, mismatch);
+  fn on_mismatch_unfixed(&self, ctx: &Context, _name: &str, filepath: &Path, mismatch: &FormatMismatch) {
+    ui::package::print_invalid(ctx, filepath, mismatch);
   }
 
   fn on_no_issues(&self) {
     ui::util::print_no_issues_found();
   }
+
+  fn on_complete(&self, _ctx: &Context) {}
+}
+
+pub struct PrettyLintReporter;
+
+impl LintReporter for PrettyLintReporter {
+  fn on_group_header(&self, ctx: &Context, group: &VersionGroup) {
+    ui::group::print_header(ctx, group);
+  }
+
+  fn on_dependency(&self, ctx: &Context, dependency: &DependencyCore, variant: &str) {
+    ui::dependency::print(ctx, dependency, variant);
+  }
+
+  fn on_instance(&self, ctx: &Context, instance: &Instance) {
+    if ctx.config.cli.show_instances {
+      ui::instance::print(ctx, instance);
+    }
+  }
+
+  fn on_no_issues(&self) {
+    ui::util::print_no_issues_found();
+  }
+
+  fn on_complete(&self, _ctx: &Context) {}
+}
+
+pub struct PrettyUpdateReporter;
+
+impl UpdateReporter for PrettyUpdateReporter {
+  fn on_rows(&self, _ctx: &Context, rows: &[UpdateRow]) {
+    update_row::render_rows(rows, None);
+    update_row::render_summary(update_row::count_diffs(rows));
+  }
+
+  fn on_no_issues(&self) {
+    ui::util::print_no_issues_found();
+  }
+
+  fn on_complete(&self, _ctx: &Context) {}
 }
 
 // — JSON implementations —
@@ -91,6 +182,38 @@ impl FixReporter for JsonFixReporter {
   fn on_no_issues(&self) {}
 
   fn on_unfixable_warning(&self) {}
+
+  fn on_complete(&self, _ctx: &Context) {}
+}
+
+/// Prints an instance per line, with the version group it belongs to
+#[derive(Default)]
+pub struct JsonLintReporter {
+  /// Index of the version group whose instances are being visited
+  group_idx: RefCell<Option<usize>>,
+}
+
+impl LintReporter for JsonLintReporter {
+  fn on_group_header(&self, ctx: &Context, group: &VersionGroup) {
+    *self.group_idx.borrow_mut() = ctx.version_groups.iter().position(|other| std::ptr::eq(other, group));
+  }
+
+  fn on_dependency(&self, _ctx: &Context, _dependency: &DependencyCore, _variant: &str) {}
+
+  fn on_instance(&self, ctx: &Context, instance: &Instance) {
+    let Some(group_idx) = *self.group_idx.borrow() else {
+      return;
+    };
+    let group = &ctx.version_groups[group_idx];
+    let mut value = instance_to_json(ctx, instance, group.variant_label());
+    value["versionGroupIndex"] = json!(group_idx);
+    value["versionGroupLabel"] = json!(get_group_label(group));
+    println!("{}", serde_json::to_string(&value).unwrap());
+  }
+
+  fn on_no_issues(&self) {}
+
+  fn on_complete(&self, _ctx: &Context) {}
 }
 
 pub struct JsonFormatReporter;
@@ -101,6 +224,8 @@ impl JsonFormatReporter {
       "package": name,
       "filePath": filepath.to_string_lossy(),
       "property": mismatch.property_path.split('/').filter(|part| !part.is_empty()).collect::<Vec<&str>>(),
+      "line": mismatch.span.map(|span| span.value.line),
+      "column": mismatch.span.map(|span| span.value.column),
       "statusCode": format!("{:?}", mismatch.variant),
     });
     println!("{}", serde_json::to_string(&value).unwrap());
@@ -119,4 +244,533 @@ impl FormatReporter for JsonFormatReporter {
   }
 
   fn on_no_issues(&self) {}
+
+  fn on_complete(&self, _ctx: &Context) {}
+}
+
+/// Prints an update per line, one for every bucket of instances which share
+/// a dependency, version group and current specifier
+pub struct JsonUpdateReporter;
+
+impl JsonUpdateReporter {
+  pub fn row_to_json(ctx: &Context, row: &UpdateRow) -> Value {
+    let group = &ctx.version_groups[row.group_idx];
+    json!({
+      "dependency": row.dependency_name,
+      "versionGroup": group.variant_label(),
+      "versionGroupIndex": row.group_idx,
+      "versionGroupLabel": get_group_label(group),
+      "count": row.bucket_count,
+      "current": {
+        "raw": row.current_raw,
+        "age": row.current_time_label,
+      },
+      "target": {
+        "raw": row.target_raw,
+        "age": row.target_time_label,
+      },
+      "diff": match update_row::diff_kind_of_row(row) {
+        DiffKind::Major => Some("major"),
+        DiffKind::Minor => Some("minor"),
+        DiffKind::Patch => Some("patch"),
+        DiffKind::None => None,
+      },
+    })
+  }
+}
+
+impl UpdateReporter for JsonUpdateReporter {
+  fn on_rows(&self, ctx: &Context, rows: &[UpdateRow]) {
+    rows.iter().for_each(|row| {
+      println!("{}", serde_json::to_string(&Self::row_to_json(ctx, row)).unwrap());
+    });
+  }
+
+  fn on_no_issues(&self) {}
+
+  fn on_complete(&self, _ctx: &Context) {}
+}
+
+// — SARIF implementations —
+
+/// Collects a result per issue and prints them all as one SARIF log once
+/// every workspace root has completed
+#[derive(Default)]
+pub struct SarifLintReporter {
+  results: RefCell<Vec<Value>>,
+}
+
+impl SarifLintReporter {
+  pub fn to_log(&self) -> Value {
+    sarif::to_log(sarif::instance_rules(), self.results.borrow().clone())
+  }
+}
+
+impl LintReporter for SarifLintReporter {
+  fn on_group_header(&self, _ctx: &Context, _group: &VersionGroup) {}
+
+  fn on_dependency(&self, _ctx: &Context, _dependency: &DependencyCore, _variant: &str) {}
+
+  fn on_instance(&self, ctx: &Context, instance: &Instance) {
+    self.results.borrow_mut().push(sarif::instance_result(ctx, instance));
+  }
+
+  fn on_no_issues(&self) {}
+
+  fn on_complete(&self, _ctx: &Context) {}
+
+  fn on_finish(&self) {
+    println!("{}", serde_json::to_string_pretty(&self.to_log()).unwrap());
+  }
+}
+
+/// Collects a result per formatting mismatch and prints them all as one
+/// SARIF log once every workspace root has completed
+#[derive(Default)]
+pub struct SarifFormatReporter {
+  results: RefCell<Vec<Value>>,
+}
+
+impl SarifFormatReporter {
+  pub fn to_log(&self) -> Value {
+    sarif::to_log(sarif::format_rules(), self.results.borrow().clone())
+  }
+}
+
+impl FormatReporter for SarifFormatReporter {
+  fn on_package_header(&self, _ctx: &Context, _name: &str, _filepath: &Path, _mismatch_count: usize) {}
+
+  /// Fixed mismatches are no longer issues, so are left out of the log
+  fn on_mismatch_fixed(&self, _ctx: &Context, _name: &str, _filepath: &Path, _mismatch: &FormatMismatch) {}
+
+  fn on_mismatch_unfixed(&self, ctx: &Context, _name: &str, filepath: &Path, mismatch: &FormatMismatch) {
+    self.results.borrow_mut().push(sarif::mismatch_result(ctx, filepath, mismatch));
+  }
+
+  fn on_no_issues(&self) {}
+
+  fn on_complete(&self, _ctx: &Context) {}
+
+  fn on_finish(&self) {
+    println!("{}", serde_json::to_string_pretty(&self.to_log()).unwrap());
+  }
+}
+
+// — JUnit implementations —
+
+/// Collects the outcome of each issue and prints every package as a test
+/// suite, with a test case per instance, once every workspace root has
+/// completed
+#[derive(Default)]
+pub struct JunitReporter {
+  /// Instances of the current root which were reported as an issue, all
+  /// others passed
+  instances: RefCell<HashMap<InstanceId, Outcome>>,
+  /// Formatting mismatches in each package.json file of the current root
+  mismatches: RefCell<HashMap<PathBuf, Vec<TestCase>>>,
+  /// The packages of every root which has completed
+  suites: RefCell<Vec<TestSuite>>,
+}
+
+impl JunitReporter {
+  pub fn to_xml(&self) -> String {
+    junit::to_xml("syncpack", &self.suites.borrow())
+  }
+
+  /// Turn the outcomes of a root into suites, before the next root reuses
+  /// its instance ids
+  fn complete_instances(&self, ctx: &Context) {
+    let suites = junit::instance_suites(ctx, &self.instances.borrow());
+    self.suites.borrow_mut().extend(suites);
+    self.instances.borrow_mut().clear();
+  }
+
+  fn complete_mismatches(&self, ctx: &Context) {
+    let suites = junit::format_suites(ctx, &self.mismatches.borrow());
+    self.suites.borrow_mut().extend(suites);
+    self.mismatches.borrow_mut().clear();
+  }
+
+  fn record_instance(&self, instance: &Instance) {
+    self
+      .instances
+      .borrow_mut()
+      .insert(instance.id.clone(), Outcome::of_instance(instance));
+  }
+}
+
+impl LintReporter for JunitReporter {
+  fn on_group_header(&self, _ctx: &Context, _group: &VersionGroup) {}
+
+  fn on_dependency(&self, _ctx: &Context, _dependency: &DependencyCore, _variant: &str) {}
+
+  fn on_instance(&self, _ctx: &Context, instance: &Instance) {
+    self.record_instance(instance);
+  }
+
+  fn on_no_issues(&self) {}
+
+  fn on_complete(&self, ctx: &Context) {
+    self.complete_instances(ctx);
+  }
+
+  fn on_finish(&self) {
+    println!("{}", self.to_xml());
+  }
+}
+
+impl FixReporter for JunitReporter {
+  fn on_group_header(&self, _ctx: &Context, _group: &VersionGroup) {}
+
+  fn on_dependency(&self, _ctx: &Context, _dependency: &DependencyCore, _variant: &str) {}
+
+  fn on_instance(&self, _ctx: &Context, instance: &Instance, _variant: &str) {
+    self.record_instance(instance);
+  }
+
+  fn on_no_issues(&self) {}
+
+  fn on_unfixable_warning(&self) {}
+
+  fn on_complete(&self, ctx: &Context) {
+    self.complete_instances(ctx);
+  }
+
+  fn on_finish(&self) {
+    println!("{}", self.to_xml());
+  }
+}
+
+impl FormatReporter for JunitReporter {
+  fn on_package_header(&self, _ctx: &Context, _name: &str, _filepath: &Path, _mismatch_count: usize) {}
+
+  /// Fixed mismatches are no longer issues, so their packages pass
+  fn on_mismatch_fixed(&self, _ctx: &Context, _name: &str, _filepath: &Path, _mismatch: &FormatMismatch) {}
+
+  fn on_mismatch_unfixed(&self, _ctx: &Context, _name: &str, filepath: &Path, mismatch: &FormatMismatch) {
+    self
+      .mismatches
+      .borrow_mut()
+      .entry(filepath.to_path_buf())
+      .or_default()
+      .push(TestCase {
+        name: ui::util::get_formatted_path(&mismatch.property_path),
+        line: mismatch.span.map(|span| span.value.line),
+        outcome: Outcome::of_mismatch(mismatch),
+      });
+  }
+
+  fn on_no_issues(&self) {}
+
+  fn on_complete(&self, ctx: &Context) {
+    self.complete_mismatches(ctx);
+  }
+
+  fn on_finish(&self) {
+    println!("{}", self.to_xml());
+  }
+}
+
+impl UpdateReporter for JunitReporter {
+  fn on_rows(&self, ctx: &Context, rows: &[UpdateRow]) {
+    rows
+      .iter()
+      .flat_map(|row| row.instance_indices.iter())
+      .for_each(|idx| self.record_instance(&ctx.instances[idx.0]));
+  }
+
+  fn on_no_issues(&self) {}
+
+  fn on_complete(&self, ctx: &Context) {
+    self.complete_instances(ctx);
+  }
+
+  fn on_finish(&self) {
+    println!("{}", self.to_xml());
+  }
+}
+
+// — GitHub Actions implementations —
+
+/// Prints an annotation per issue, grouped by the label of its version group
+#[derive(Default)]
+pub struct GithubReporter {
+  annotations: Annotations,
+}
+
+impl LintReporter for GithubReporter {
+  fn on_group_header(&self, _ctx: &Context, group: &VersionGroup) {
+    self.annotations.group(&get_group_label(group));
+  }
+
+  fn on_dependency(&self, _ctx: &Context, _dependency: &DependencyCore, _variant: &str) {}
+
+  fn on_instance(&self, ctx: &Context, instance: &Instance) {
+    self.annotations.instance(ctx, instance, None);
+  }
+
+  fn on_no_issues(&self) {}
+
+  fn on_complete(&self, _ctx: &Context) {
+    self.annotations.end();
+  }
+}
+
+impl FixReporter for GithubReporter {
+  fn on_group_header(&self, _ctx: &Context, group: &VersionGroup) {
+    self.annotations.group(&get_group_label(group));
+  }
+
+  fn on_dependency(&self, _ctx: &Context, _dependency: &DependencyCore, _variant: &str) {}
+
+  fn on_instance(&self, ctx: &Context, instance: &Instance, _variant: &str) {
+    self.annotations.instance(ctx, instance, None);
+  }
+
+  fn on_no_issues(&self) {}
+
+  fn on_unfixable_warning(&self) {}
+
+  fn on_complete(&self, _ctx: &Context) {
+    self.annotations.end();
+  }
+}
+
+/// Formatting has no version groups, so annotations are grouped by package
+impl FormatReporter for GithubReporter {
+  fn on_package_header(&self, _ctx: &Context, name: &str, _filepath: &Path, _mismatch_count: usize) {
+    self.annotations.group(name);
+  }
+
+  fn on_mismatch_fixed(&self, _ctx: &Context, _name: &str, _filepath: &Path, _mismatch: &FormatMismatch) {}
+
+  fn on_mismatch_unfixed(&self, ctx: &Context, _name: &str, filepath: &Path, mismatch: &FormatMismatch) {
+    self.annotations.mismatch(ctx, filepath, mismatch);
+  }
+
+  fn on_no_issues(&self) {}
+
+  fn on_complete(&self, _ctx: &Context) {
+    self.annotations.end();
+  }
+}
+
+impl UpdateReporter for GithubReporter {
+  fn on_rows(&self, ctx: &Context, rows: &[UpdateRow]) {
+    rows.iter().for_each(|row| {
+      self.annotations.group(&row.group_label);
+      row
+        .instance_indices
+        .iter()
+        .for_each(|idx| self.annotations.instance(ctx, &ctx.instances[idx.0], Some(Severity::Error)));
+    });
+  }
+
+  fn on_no_issues(&self) {}
+
+  fn on_complete(&self, _ctx: &Context) {
+    self.annotations.end();
+  }
+}
+
+// — Markdown implementations —
+
+/// Collects every issue and prints one markdown summary once every
+/// workspace root has completed
+#[derive(Default)]
+pub struct MarkdownReporter {
+  /// Issues in each version group, for lint
+  issues: RefCell<Vec<IssueGroup>>,
+  /// Unfixed formatting mismatches in each package, for format
+  mismatches: RefCell<Vec<MismatchGroup>>,
+  /// Available updates, for update, labelled with their version group
+  rows: RefCell<Vec<UpdateRow>>,
+}
+
+impl MarkdownReporter {
+  pub fn to_lint_markdown(&self) -> String {
+    markdown::lint_report(&self.issues.borrow())
+  }
+
+  pub fn to_format_markdown(&self) -> String {
+    markdown::format_report(&self.mismatches.borrow())
+  }
+
+  pub fn to_update_markdown(&self) -> String {
+    markdown::update_report(&self.rows.borrow())
+  }
+}
+
+impl LintReporter for MarkdownReporter {
+  fn on_group_header(&self, _ctx: &Context, group: &VersionGroup) {
+    self.issues.borrow_mut().push(IssueGroup::new(get_group_label(group)));
+  }
+
+  fn on_dependency(&self, _ctx: &Context, _dependency: &DependencyCore, _variant: &str) {}
+
+  fn on_instance(&self, ctx: &Context, instance: &Instance) {
+    if let Some(group) = self.issues.borrow_mut().last_mut() {
+      group.add(ctx, instance);
+    }
+  }
+
+  fn on_no_issues(&self) {}
+
+  fn on_complete(&self, _ctx: &Context) {}
+
+  fn on_finish(&self) {
+    print!("{}", self.to_lint_markdown());
+  }
+}
+
+impl FormatReporter for MarkdownReporter {
+  fn on_package_header(&self, _ctx: &Context, name: &str, _filepath: &Path, _mismatch_count: usize) {
+    self.mismatches.borrow_mut().push(MismatchGroup::new(name));
+  }
+
+  /// Fixed mismatches are no longer issues, so are left out of the summary
+  fn on_mismatch_fixed(&self, _ctx: &Context, _name: &str, _filepath: &Path, _mismatch: &FormatMismatch) {}
+
+  fn on_mismatch_unfixed(&self, _ctx: &Context, _name: &str, _filepath: &Path, mismatch: &FormatMismatch) {
+    if let Some(group) = self.mismatches.borrow_mut().last_mut() {
+      group.add(mismatch);
+    }
+  }
+
+  fn on_no_issues(&self) {}
+
+  fn on_complete(&self, _ctx: &Context) {}
+
+  fn on_finish(&self) {
+    print!("{}", self.to_format_markdown());
+  }
+}
+
+impl UpdateReporter for MarkdownReporter {
+  /// Version group indexes are only meaningful within one root, so rows are
+  /// kept with the label of their group instead
+  fn on_rows(&self, ctx: &Context, rows: &[UpdateRow]) {
+    self.rows.borrow_mut().extend(rows.iter().map(|row| UpdateRow {
+      group_label: get_group_label(&ctx.version_groups[row.group_idx]),
+      ..row.clone()
+    }));
+  }
+
+  fn on_no_issues(&self) {}
+
+  fn on_complete(&self, _ctx: &Context) {}
+
+  fn on_finish(&self) {
+    print!("{}", self.to_update_markdown());
+  }
+}
+
+// — Matrix implementations —
+
+/// Collects every instance `list` visits and prints a table of dependencies
+/// by package for each workspace root, or one CSV document for all of them
+pub struct MatrixReporter {
+  /// Whether to print CSV rather than a table for the terminal
+  csv: bool,
+  matrix: RefCell<Matrix>,
+}
+
+impl MatrixReporter {
+  pub fn new(csv: bool) -> Self {
+    Self {
+      csv,
+      matrix: RefCell::new(Matrix::default()),
+    }
+  }
+}
+
+impl LintReporter for MatrixReporter {
+  fn on_group_header(&self, _ctx: &Context, _group: &VersionGroup) {}
+
+  fn on_dependency(&self, _ctx: &Context, _dependency: &DependencyCore, _variant: &str) {}
+
+  fn on_instance(&self, ctx: &Context, instance: &Instance) {
+    self.matrix.borrow_mut().add(ctx, instance);
+  }
+
+  /// Valid cells are not coloured, so the table speaks for itself
+  fn on_no_issues(&self) {}
+
+  fn on_complete(&self, _ctx: &Context) {
+    if !self.csv {
+      self.matrix.take().to_table().iter().for_each(|line| log::info!("{line}"));
+    }
+  }
+
+  fn on_finish(&self) {
+    if self.csv {
+      print!("{}", self.matrix.borrow().to_csv());
+    }
+  }
+}
+
+// — Helpers for machine-readable reporters —
+
+/// Version groups without a label are named after their variant
+pub fn get_group_label(group: &VersionGroup) -> String {
+  let label = &group.selector().label;
+  if label.is_empty() {
+    group.variant_label().to_string()
+  } else {
+    label.clone()
+  }
+}
+
+/// The file an instance was read from
+pub fn get_source_file_path(ctx: &Context, source: &Source) -> Option<PathBuf> {
+  match source {
+    Source::Package { file_idx, .. } => Some(ctx.disk.package_json_files[*file_idx].filepath.clone()),
+    Source::DenoJson { file_idx, .. } => Some(ctx.disk.deno_json_files[*file_idx].filepath.clone()),
+    Source::PnpmYaml => ctx.disk.pnpm_workspace.as_ref().map(|file| file.filepath.clone()),
+    Source::YarnRc => ctx.disk.yarnrc.as_ref().map(|file| file.filepath.clone()),
+  }
+}
+
+/// Paths relative to the project root with `/` separators, which is what
+/// CI systems and code-scanning dashboards expect
+pub fn to_uri(cwd: &Path, file_path: &Path) -> String {
+  match file_path.strip_prefix(cwd) {
+    Ok(relative) => relative
+      .components()
+      .map(|component| component.as_os_str().to_string_lossy())
+      .collect::<Vec<_>>()
+      .join("/"),
+    Err(_) => file_path.to_string_lossy().to_string(),
+  }
+}
+
+/// Path of a file relative to the directory syncpack was run from, so the
+/// files of every workspace root can be told apart
+pub fn get_uri(ctx: &Context, file_path: &Path) -> String {
+  let uri = to_uri(&ctx.disk.cwd, file_path);
+  match ctx.config.cli.cwd.strip_prefix(&ctx.config.cli.invocation_cwd) {
+    Ok(root) if !root.as_os_str().is_empty() && !Path::new(&uri).is_absolute() => {
+      format!("{}/{uri}", to_uri(Path::new(""), root))
+    }
+    _ => uri,
+  }
+}
+
+/// eg. `react is "17.0.2" at .dependencies but should be "18.2.0"`
+pub fn describe_instance(instance: &Instance) -> String {
+  let name = &instance.descriptor.name;
+  let actual = instance.descriptor.specifier.get_raw();
+  let property_path = ui::util::get_formatted_path(&instance.descriptor.dependency_type.path);
+  match instance.expected_specifier.borrow().as_ref() {
+    Some(expected) if expected.get_raw() != actual => {
+      format!("{name} is \"{actual}\" at {property_path} but should be \"{}\"", expected.get_raw())
+    }
+    _ => format!("{name} is \"{actual}\" at {property_path}"),
+  }
+}
+
+/// eg. `Property is not sorted az at .keywords`
+pub fn describe_mismatch(mismatch: &FormatMismatch) -> String {
+  let property_path = ui::util::get_formatted_path(&mismatch.property_path);
+  format!("{} at {property_path}", ui::util::to_sentence(&format!("{:?}", mismatch.variant)))
 }
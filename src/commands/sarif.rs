@@ -0,0 +1,110 @@
+#[cfg(test)]
+#[path = "sarif_test.rs"]
+mod sarif_test;
+
+use {
+  crate::{
+    commands::{reporter, ui},
+    context::Context,
+    instance::{Instance, InstanceState, Severity},
+    source::{FormatMismatch, FormatMismatchVariant},
+    span::Span,
+  },
+  serde_json::{Value, json},
+  std::path::Path,
+};
+
+const SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// A SARIF 2.1.0 log containing a single run of syncpack
+pub fn to_log(rules: Vec<Value>, results: Vec<Value>) -> Value {
+  json!({
+    "$schema": SCHEMA,
+    "version": "2.1.0",
+    "runs": [{
+      "tool": {
+        "driver": {
+          "name": "syncpack",
+          "version": env!("CARGO_PKG_VERSION"),
+          "informationUri": "https://syncpack.dev",
+          "rules": rules,
+        }
+      },
+      "results": results,
+    }]
+  })
+}
+
+/// One rule for every status `lint` can report
+pub fn instance_rules() -> Vec<Value> {
+  InstanceState::issue_names()
+    .iter()
+    .map(|name| to_rule(name, ui::util::get_status_code_url(name)))
+    .collect()
+}
+
+/// One rule for every check `format --check` can report
+pub fn format_rules() -> Vec<Value> {
+  FormatMismatchVariant::ALL
+    .iter()
+    .map(|variant| {
+      let config_key = variant.get_config_key();
+      let help_uri = format!("https://syncpack.dev/config/{}", ui::util::to_kebab_case(config_key));
+      to_rule(&format!("{variant:?}"), help_uri)
+    })
+    .collect()
+}
+
+fn to_rule(name: &str, help_uri: String) -> Value {
+  json!({
+    "id": name,
+    "name": name,
+    "shortDescription": { "text": ui::util::to_sentence(name) },
+    "helpUri": help_uri,
+  })
+}
+
+/// A result for an instance which `lint` found to be invalid
+pub fn instance_result(ctx: &Context, instance: &Instance) -> Value {
+  let severity = instance.severity.borrow().unwrap_or(Severity::None);
+  let status_code = instance.state.borrow().get_name();
+  let text = format!("{} ({status_code})", reporter::describe_instance(instance));
+  let file_path = reporter::get_source_file_path(ctx, &ctx.sources.all[instance.source_idx().0]);
+  to_result(ctx, &status_code, severity, text, file_path.as_deref(), instance.span())
+}
+
+/// A result for a formatting mismatch found by `format --check`
+pub fn mismatch_result(ctx: &Context, filepath: &Path, mismatch: &FormatMismatch) -> Value {
+  let status_code = format!("{:?}", mismatch.variant);
+  let text = reporter::describe_mismatch(mismatch);
+  to_result(ctx, &status_code, Severity::Error, text, Some(filepath), mismatch.span)
+}
+
+fn to_result(ctx: &Context, rule_id: &str, severity: Severity, text: String, file_path: Option<&Path>, span: Option<Span>) -> Value {
+  let mut physical_location = json!({
+    "artifactLocation": {
+      "uri": file_path.map(|file_path| reporter::get_uri(ctx, file_path)),
+    }
+  });
+  if let Some(span) = span {
+    physical_location["region"] = json!({
+      "startLine": span.value.line,
+      "startColumn": span.value.column,
+    });
+  }
+  json!({
+    "ruleId": rule_id,
+    "level": to_level(severity),
+    "message": { "text": text },
+    "locations": [{ "physicalLocation": physical_location }],
+  })
+}
+
+/// `fix` and `error` both fail `lint`, so are reported as errors
+fn to_level(severity: Severity) -> &'static str {
+  match severity {
+    Severity::Fix | Severity::Error => "error",
+    Severity::Warn => "warning",
+    Severity::None => "note",
+  }
+}
@@ -0,0 +1,146 @@
+use {
+  crate::{
+    commands::{
+      format, lint,
+      patch::Patch,
+      reporter::{SarifFormatReporter, SarifLintReporter},
+    },
+    instance::InstanceState,
+    test::{builder::TestBuilder, mock_disk::MockDiskIo},
+  },
+  serde_json::{Value, json},
+};
+
+fn rule_ids(log: &Value) -> Vec<&str> {
+  log["runs"][0]["tool"]["driver"]["rules"]
+    .as_array()
+    .unwrap()
+    .iter()
+    .map(|rule| rule["id"].as_str().unwrap())
+    .collect()
+}
+
+#[tokio::test]
+async fn lint_reports_each_issue_with_its_rule_level_and_location() {
+  let ctx = TestBuilder::new()
+    .with_packages(vec![
+      json!({
+        "name": "package-a",
+        "version": "1.0.0",
+        "dependencies": { "react": "17.0.2" }
+      }),
+      json!({
+        "name": "package-b",
+        "version": "1.0.0",
+        "dependencies": { "react": "18.2.0" }
+      }),
+    ])
+    .run()
+    .await;
+  let reporter = SarifLintReporter::default();
+  let _ = lint::run(ctx, &reporter, &MockDiskIo::new());
+  let log = reporter.to_log();
+  assert_eq!(log["version"], "2.1.0");
+  assert_eq!(log["runs"][0]["tool"]["driver"]["name"], "syncpack");
+  assert_eq!(
+    log["runs"][0]["results"],
+    json!([{
+      "ruleId": "DiffersToHighestOrLowestSemver",
+      "level": "error",
+      "message": { "text": "react is \"17.0.2\" at .dependencies but should be \"18.2.0\" (DiffersToHighestOrLowestSemver)" },
+      "locations": [{
+        "physicalLocation": {
+          "artifactLocation": { "uri": "packages/package-a/package.json" },
+          "region": { "startLine": 5, "startColumn": 14 }
+        }
+      }]
+    }])
+  );
+}
+
+#[tokio::test]
+async fn lint_maps_warn_severity_to_warning_level() {
+  let ctx = TestBuilder::new()
+    .with_packages(vec![
+      json!({
+        "name": "package-a",
+        "version": "1.0.0",
+        "dependencies": { "react": "17.0.2" }
+      }),
+      json!({
+        "name": "package-b",
+        "version": "1.0.0",
+        "dependencies": { "react": "18.2.0" }
+      }),
+    ])
+    .with_version_group(json!({
+      "dependencies": ["react"],
+      "pinVersion": "18.2.0",
+      "severity": { "DiffersToPin": "warn" }
+    }))
+    .run()
+    .await;
+  let reporter = SarifLintReporter::default();
+  assert!(lint::run(ctx, &reporter, &MockDiskIo::new()).is_ok());
+  let log = reporter.to_log();
+  assert_eq!(log["runs"][0]["results"][0]["ruleId"], "DiffersToPin");
+  assert_eq!(log["runs"][0]["results"][0]["level"], "warning");
+}
+
+#[test]
+fn lint_rules_cover_every_issue_status_with_a_help_url() {
+  let log = SarifLintReporter::default().to_log();
+  assert_eq!(rule_ids(&log), InstanceState::issue_names());
+  assert_eq!(
+    log["runs"][0]["tool"]["driver"]["rules"][0],
+    json!({
+      "id": "IsBanned",
+      "name": "IsBanned",
+      "shortDescription": { "text": "Is banned" },
+      "helpUri": "https://syncpack.dev/status/is-banned"
+    })
+  );
+}
+
+#[test]
+fn format_check_reports_each_mismatch() {
+  let ctx = TestBuilder::new()
+    .with_packages(vec![json!({
+      "name": "package-a",
+      "version": "1.0.0",
+      "keywords": ["b", "a"]
+    })])
+    .with_config(json!({ "sortPackages": false }))
+    .build_and_visit_formatting();
+  let reporter = SarifFormatReporter::default();
+  assert!(format::run(ctx, &reporter, &MockDiskIo::new(), &Patch::default()).is_err());
+  let log = reporter.to_log();
+  assert_eq!(
+    rule_ids(&log),
+    vec![
+      "BugsPropertyIsNotFormatted",
+      "RepositoryPropertyIsNotFormatted",
+      "PropertyIsNotSortedAz",
+      "PackagePropertiesAreNotSorted",
+      "ExportsPropertyIsNotSorted",
+    ]
+  );
+  assert_eq!(
+    log["runs"][0]["tool"]["driver"]["rules"][2]["helpUri"],
+    "https://syncpack.dev/config/sort-az"
+  );
+  assert_eq!(
+    log["runs"][0]["results"],
+    json!([{
+      "ruleId": "PropertyIsNotSortedAz",
+      "level": "error",
+      "message": { "text": "Property is not sorted az at .keywords" },
+      "locations": [{
+        "physicalLocation": {
+          "artifactLocation": { "uri": "/packages/package-a/package.json" },
+          "region": { "startLine": 4, "startColumn": 15 }
+        }
+      }]
+    }])
+  );
+}
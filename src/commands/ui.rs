@@ -7,6 +7,7 @@ pub const LINE_ENDING: &str = "\r\n";
 pub const LINE_ENDING: &str = "\n";
 
 pub mod dependency;
+pub mod diff;
 pub mod group;
 pub mod icon;
 pub mod instance;
@@ -0,0 +1,49 @@
+use {colored::*, log::info, similar::TextDiff};
+
+/// Render a unified diff between two versions of a file in the format
+/// accepted by `git apply`. `relative_path` is used for the `a/` and `b/`
+/// headers. Returns an empty string when both versions are identical.
+pub fn unified_diff(relative_path: &str, before: &str, after: &str) -> String {
+  to_unified_diff(&format!("a/{relative_path}"), &format!("b/{relative_path}"), before, after)
+}
+
+/// Like `unified_diff`, but diffed against `/dev/null` when there is no
+/// `before` so that `git apply` creates the file
+pub fn file_diff(relative_path: &str, before: Option<&str>, after: &str) -> String {
+  match before {
+    Some(before) => unified_diff(relative_path, before, after),
+    None => to_unified_diff("/dev/null", &format!("b/{relative_path}"), "", after),
+  }
+}
+
+fn to_unified_diff(old_header: &str, new_header: &str, before: &str, after: &str) -> String {
+  if before == after {
+    return "".to_string();
+  }
+  TextDiff::from_lines(before, after)
+    .unified_diff()
+    .context_radius(3)
+    .header(old_header, new_header)
+    .to_string()
+}
+
+/// Print a unified diff with added lines in green and removed lines in red
+pub fn print_unified_diff(relative_path: &str, before: &str, after: &str) {
+  print_diff(&unified_diff(relative_path, before, after));
+}
+
+pub fn print_diff(diff: &str) {
+  diff.lines().for_each(|line| {
+    if line.starts_with("+++") || line.starts_with("---") {
+      info!("{}", line.bold());
+    } else if line.starts_with("@@") {
+      info!("{}", line.cyan());
+    } else if line.starts_with('+') {
+      info!("{}", line.green());
+    } else if line.starts_with('-') {
+      info!("{}", line.red());
+    } else {
+      info!("{line}");
+    }
+  });
+}
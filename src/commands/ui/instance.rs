@@ -114,7 +114,7 @@ fn get_common_parts(ctx: &Context, instance: &Instance) -> (String, String, Stri
   let actual = get_actual(instance);
   let location = get_location(ctx, instance);
   let state_link = get_state_link_in_parens(ctx, instance);
-  let local_hint = get_local_hint(ctx, instance);
+  let local_hint = ui::util::join_line(vec![&get_local_hint(ctx, instance), &get_resolved_hint(instance)]);
   (actual, location, state_link, local_hint)
 }
 
@@ -135,6 +135,14 @@ fn get_local_hint(ctx: &Context, instance: &Instance) -> String {
   }
 }
 
+/// The version installed according to the lockfile, when it was read
+fn get_resolved_hint(instance: &Instance) -> String {
+  match &instance.resolved_version {
+    Some(version) => format!("(resolved {version})").dimmed().to_string(),
+    None => "".to_string(),
+  }
+}
+
 pub fn get_expected(instance: &Instance) -> String {
   instance.expected_specifier.borrow().as_ref().unwrap().get_raw().to_string()
 }
@@ -148,9 +156,14 @@ pub fn get_location(ctx: &Context, instance: &Instance) -> String {
   let path_to_prop = instance.descriptor.dependency_type.path.replace("/", ".");
   let file_link = match &ctx.sources.all[instance.source_idx().0] {
     crate::source::Source::Package { file_idx, .. } => {
-      ui::package::get_package_json_link(ctx, &ctx.disk.package_json_files[*file_idx].filepath)
+      ui::package::get_file_link(ctx, &ctx.disk.package_json_files[*file_idx].filepath, instance.span())
+    }
+    crate::source::Source::DenoJson { file_idx, .. } => {
+      ui::package::get_file_link(ctx, &ctx.disk.deno_json_files[*file_idx].filepath, instance.span())
+    }
+    source @ (crate::source::Source::PnpmYaml | crate::source::Source::YarnRc) => {
+      format!("{}{}", source.name(), ui::package::get_position_suffix(instance.span()))
     }
-    crate::source::Source::PnpmYaml => "pnpm-workspace.yaml".to_string(),
   };
   ui::util::join_line(vec![&alias_info, &"in".to_string(), &file_link, &"at".to_string(), &path_to_prop])
 }
@@ -1,5 +1,10 @@
 use {
-  crate::{commands::ui, context::Context, source::FormatMismatch},
+  crate::{
+    commands::ui,
+    context::Context,
+    source::FormatMismatch,
+    span::{Position, Span},
+  },
   colored::*,
   log::info,
   std::path::Path,
@@ -12,31 +17,56 @@ pub fn print_invalid_package(ctx: &Context, name: &str, filepath: &Path, mismatc
   info!("{count_column} {name} {location}");
 }
 
-pub fn print_invalid(ctx: &Context, mismatch: &FormatMismatch) {
+pub fn print_invalid(ctx: &Context, filepath: &Path, mismatch: &FormatMismatch) {
   let indent = " ".repeat(ui::DEFAULT_INDENT + 1);
   let icon = ui::icon::err();
   let status_code = format!("{:?}", mismatch.variant);
   let status_code_link = ui::util::get_status_code_link(ctx, &status_code).red();
-  let property_path = ui::util::get_formatted_path(&mismatch.property_path);
-  let location = format!("at {property_path}").dimmed();
+  let location = get_mismatch_location(ctx, filepath, mismatch).dimmed();
   info!("{indent} {icon} {status_code_link} {location}");
 }
 
-pub fn print_fixed(ctx: &Context, mismatch: &FormatMismatch) {
+pub fn print_fixed(ctx: &Context, filepath: &Path, mismatch: &FormatMismatch) {
   let indent = " ".repeat(ui::DEFAULT_INDENT + 1);
   let icon = ui::icon::ok();
   let status_code = format!("{:?}", mismatch.variant);
   let status_code_link = ui::util::get_status_code_link(ctx, &status_code).dimmed();
-  let property_path = ui::util::get_formatted_path(&mismatch.property_path);
-  let location = format!("at {property_path}").dimmed();
+  let location = get_mismatch_location(ctx, filepath, mismatch).dimmed();
   info!("{indent} {icon} {status_code_link} {location}");
 }
 
+/// eg. "at .bugs in packages/foo/package.json:12:3"
+fn get_mismatch_location(ctx: &Context, filepath: &Path, mismatch: &FormatMismatch) -> String {
+  let property_path = ui::util::get_formatted_path(&mismatch.property_path);
+  match mismatch.span {
+    Some(span) => format!("at {property_path} in {}", get_file_link(ctx, filepath, Some(span))),
+    None => format!("at {property_path}"),
+  }
+}
+
 /// Render a clickable link to a package.json file
 pub fn get_package_json_link(ctx: &Context, package_file_path: &Path) -> String {
-  let file_path = package_file_path.to_str().unwrap();
-  let relative_file_path = get_relative_file_path(&ctx.disk.cwd, package_file_path);
-  ui::util::get_link(ctx, format!("file:{file_path}"), relative_file_path)
+  get_file_link(ctx, package_file_path, None)
+}
+
+/// Render a clickable link to a file, followed by the line and column of
+/// `span` when known, such as `packages/foo/package.json:12:5`
+pub fn get_file_link(ctx: &Context, file_path: &Path, span: Option<Span>) -> String {
+  let absolute_file_path = file_path.to_str().unwrap();
+  let relative_file_path = get_relative_file_path(&ctx.disk.cwd, file_path);
+  let text = format!("{relative_file_path}{}", get_position_suffix(span));
+  ui::util::get_link(ctx, format!("file:{absolute_file_path}"), text)
+}
+
+/// eg. ":12:5", or nothing when the position is not known
+pub fn get_position_suffix(span: Option<Span>) -> String {
+  match span {
+    Some(Span {
+      value: Position { line, column },
+      ..
+    }) => format!(":{line}:{column}"),
+    None => "".to_string(),
+  }
 }
 
 fn get_relative_file_path(cwd: &Path, file_path: &Path) -> String {
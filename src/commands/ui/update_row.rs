@@ -241,7 +241,9 @@ pub fn count_diffs(rows: &[UpdateRow]) -> DiffCounts {
   counts
 }
 
-fn diff_kind_of_row(row: &UpdateRow) -> DiffKind {
+/// The kind of update a row would make, `DiffKind::None` when either
+/// specifier is not an exact semver version once its range is removed
+pub fn diff_kind_of_row(row: &UpdateRow) -> DiffKind {
   let (_, current_body) = split_leading_range(&row.current_raw);
   let (_, target_body) = split_leading_range(&row.target_raw);
   match (Version::parse(current_body).ok(), Version::parse(target_body).ok()) {
@@ -29,8 +29,18 @@ pub fn count_column(count: usize) -> String {
 
 /// Render the reason code as a clickable link
 pub fn get_status_code_link(ctx: &Context, pascal_case: &str) -> String {
+  get_link(ctx, get_status_code_url(pascal_case), pascal_case)
+}
+
+/// eg. "https://syncpack.dev/status/differs-to-local"
+pub fn get_status_code_url(pascal_case: &str) -> String {
   let base_url = "https://syncpack.dev/status";
-  let kebab_case = pascal_case
+  format!("{base_url}/{}", to_kebab_case(pascal_case))
+}
+
+/// Convert eg. "DiffersToLocal" to "differs-to-local"
+pub fn to_kebab_case(pascal_case: &str) -> String {
+  pascal_case
     .chars()
     .enumerate()
     .map(|(i, c)| {
@@ -42,8 +52,17 @@ pub fn get_status_code_link(ctx: &Context, pascal_case: &str) -> String {
         c.to_string()
       }
     })
-    .collect::<String>();
-  get_link(ctx, format!("{base_url}/{kebab_case}"), pascal_case)
+    .collect::<String>()
+}
+
+/// Convert eg. "DiffersToLocal" to "Differs to local"
+pub fn to_sentence(pascal_case: &str) -> String {
+  let words = to_kebab_case(pascal_case).replace('-', " ");
+  let mut chars = words.chars();
+  match chars.next() {
+    Some(first) => first.to_uppercase().chain(chars).collect(),
+    None => words,
+  }
 }
 
 /// Render a clickable link
@@ -64,3 +83,10 @@ pub fn print_no_issues_found() {
   let icon = ui::icon::ok();
   info!("{icon} No issues found");
 }
+
+/// Heading above the output of each workspace root in multi-root mode
+pub fn print_workspace_root(root: &str) {
+  let header = format!("# {root} ");
+  let divider = "#".repeat(80_usize.saturating_sub(header.len()));
+  info!("{}", format!("{header}{divider}").bold());
+}
@@ -1,12 +1,14 @@
 use {
   crate::{
-    commands::ui::{self, LINE_ENDING, update_row},
+    commands::{
+      reporter::UpdateReporter,
+      ui::{LINE_ENDING, update_row},
+    },
     context::Context,
     disk::{DiskIo, copy_expected_specifier_json, copy_expected_specifier_yaml, write_json_file, write_yaml_file},
     errors::SyncpackError,
     instance::InstanceIdx,
     registry::updates::RegistryUpdates,
-    source::Source,
     tui::{Tui, TuiReadiness, UpdateRow},
     version_group::{VersionGroup, VersionGroupBehavior},
   },
@@ -140,7 +142,13 @@ pub fn filter_rows_for_display(rows: &[UpdateRow], selection: &[bool]) -> Vec<Up
   filtered
 }
 
-pub fn run<D: DiskIo>(mut ctx: Context, registry_updates: RegistryUpdates, io: &D, tui: &dyn Tui) -> Result<Context, SyncpackError> {
+pub fn run<D: DiskIo>(
+  mut ctx: Context,
+  registry_updates: RegistryUpdates,
+  io: &D,
+  tui: &dyn Tui,
+  reporter: &dyn UpdateReporter,
+) -> Result<Context, SyncpackError> {
   let now = update_row::unix_now();
   let rows = build_update_rows(&ctx, &registry_updates, now);
   let was_outdated = !rows.is_empty();
@@ -184,10 +192,7 @@ pub fn run<D: DiskIo>(mut ctx: Context, registry_updates: RegistryUpdates, io: &
   let filtered_for_display: Option<Vec<UpdateRow>> = selection.as_deref().map(|sel| filter_rows_for_display(&rows, sel));
   let display_rows: &[UpdateRow] = filtered_for_display.as_deref().unwrap_or(&rows);
 
-  update_row::render_rows(display_rows, None);
-
-  let counts = update_row::count_diffs(display_rows);
-  update_row::render_summary(counts);
+  reporter.on_rows(&ctx, display_rows);
 
   // Apply selected rows by copying expected → actual through the
   // existing per-source dispatch.
@@ -198,14 +203,12 @@ pub fn run<D: DiskIo>(mut ctx: Context, registry_updates: RegistryUpdates, io: &
 
   for inst_idx in copy_actions {
     let source_idx = ctx.instances[inst_idx.0].source_idx();
-    let consumer_file_idx: Option<usize> = match &ctx.sources.all[source_idx.0] {
-      Source::Package { file_idx, .. } => Some(*file_idx),
-      Source::PnpmYaml => None,
-    };
+    let source = &ctx.sources.all[source_idx.0];
+    let source_kind = source.kind();
     let instance = &ctx.instances[inst_idx.0];
-    if let Some(fi) = consumer_file_idx {
-      copy_expected_specifier_json(&mut ctx.disk.package_json_files[fi], instance);
-    } else if let Some(yaml) = ctx.disk.pnpm_workspace.as_mut() {
+    if let Some(file) = ctx.disk.json_source_mut(source) {
+      copy_expected_specifier_json(file, instance);
+    } else if let Some(yaml) = ctx.disk.yaml_source_mut(source_kind) {
       copy_expected_specifier_yaml(yaml, instance);
     }
   }
@@ -219,23 +222,34 @@ pub fn run<D: DiskIo>(mut ctx: Context, registry_updates: RegistryUpdates, io: &
       "Syncpack does not yet support custom npm registries{LINE_ENDING}  Subscribe to https://github.com/JamieMason/syncpack/issues/220"
     );
   } else if !was_outdated {
-    ui::util::print_no_issues_found();
+    reporter.on_no_issues();
   }
+  reporter.on_complete(&ctx);
+
+  let registry_result = if registry_updates.failed.is_empty() {
+    Ok(())
+  } else {
+    Err(SyncpackError::RegistryFailed(registry_updates.failed.len()))
+  };
 
   if ctx.config.cli.check {
-    return if was_outdated { Err(SyncpackError::IssuesFound) } else { Ok(ctx) };
+    return if was_outdated {
+      Err(SyncpackError::IssuesFound)
+    } else {
+      registry_result.map(|_| ctx)
+    };
   }
 
   if !ctx.config.cli.dry_run {
     let indent = ctx.config.rcfile.indent.as_deref();
     let fallback = ctx.disk.formatting_fallback();
-    for file in ctx.disk.package_json_files.iter_mut() {
+    for file in ctx.disk.json_files_mut() {
       write_json_file(file, io, indent, &fallback)?;
     }
-    if let Some(yaml) = ctx.disk.pnpm_workspace.as_mut() {
+    for yaml in ctx.disk.yaml_files_mut() {
       write_yaml_file(yaml, io, indent, &fallback)?;
     }
   }
 
-  Ok(ctx)
+  registry_result.map(|_| ctx)
 }
@@ -1,6 +1,9 @@
 use {
   crate::{
-    commands::update::{build_update_rows, filter_rows_for_display},
+    commands::{
+      reporter::JsonUpdateReporter,
+      update::{build_update_rows, filter_rows_for_display},
+    },
     test::{builder::TestBuilder, mock_tui::MockTui},
   },
   serde_json::json,
@@ -166,6 +169,34 @@ async fn registry_times_populate_time_labels() {
   assert_eq!(rows[0].target_time_label.as_deref(), Some("~3d"));
 }
 
+#[tokio::test]
+async fn json_reporter_describes_each_row_with_its_diff_kind() {
+  let (ctx, updates) = TestBuilder::new()
+    .with_packages(vec![json!({
+      "name": "package-a",
+      "version": "1.0.0",
+      "dependencies": {"foo": "^1.0.0"}
+    })])
+    .with_registry_updates(json!({"foo": ["1.0.0", "1.2.0"]}))
+    .with_registry_times("foo", times(&[("1.0.0", "2024-01-01T00:00:00Z")]))
+    .run_with_updates()
+    .await;
+  let rows = build_update_rows(&ctx, &updates.unwrap(), FROZEN_NOW);
+  assert_eq!(
+    JsonUpdateReporter::row_to_json(&ctx, &rows[0]),
+    json!({
+      "dependency": "foo",
+      "versionGroup": "HighestSemver",
+      "versionGroupIndex": 0,
+      "versionGroupLabel": "Default Version Group",
+      "count": 1,
+      "current": { "raw": "^1.0.0", "age": "~14d" },
+      "target": { "raw": "^1.2.0", "age": null },
+      "diff": "minor"
+    })
+  );
+}
+
 #[tokio::test]
 async fn ignored_dep_via_update_group_does_not_appear_in_rows() {
   let (ctx, updates) = TestBuilder::new()
@@ -298,7 +329,13 @@ async fn filter_rows_for_display_drops_unselected_and_recomputes_dep_total() {
 mod apply {
   use {
     super::*,
-    crate::{commands::update, errors::SyncpackError, registry::updates::RegistryUpdates, test::mock_disk::MockDiskIo},
+    crate::{
+      commands::update,
+      errors::SyncpackError,
+      instance::{InstanceState, SuspectInstance},
+      registry::updates::RegistryUpdates,
+      test::mock_disk::MockDiskIo,
+    },
   };
 
   /// Run the full `update::run` flow with a frozen MockTui and return
@@ -318,7 +355,7 @@ mod apply {
       times_by_internal_name: Default::default(),
       failed: vec![],
     });
-    update::run(ctx, updates, &disk, &tui)
+    update::run(ctx, updates, &disk, &tui, &crate::commands::reporter::PrettyUpdateReporter)
   }
 
   async fn run_update(builder: TestBuilder, tui: MockTui) -> crate::context::Context {
@@ -356,6 +393,95 @@ mod apply {
     assert_eq!(dirty.len(), 2);
   }
 
+  #[tokio::test]
+  async fn failed_registry_requests_fail_the_command_once_updates_are_applied() {
+    for dry_run in [true, false] {
+      let (mut ctx, updates) = TestBuilder::new()
+        .with_packages(vec![json!({
+          "name": "package-a",
+          "version": "1.0.0",
+          "dependencies": {"foo": "^1.0.0", "bar": "1.0.0"}
+        })])
+        .with_registry_updates(json!({"foo": ["1.0.0", "1.0.1"]}))
+        .run_with_updates()
+        .await;
+      let mut updates = updates.unwrap();
+      updates.failed = vec!["bar".to_string()];
+      ctx.config.cli.check = false;
+      ctx.config.cli.dry_run = dry_run;
+      let filepath = ctx.disk.package_json_files[0].filepath.clone();
+      let disk = MockDiskIo::new();
+      let result = update::run(
+        ctx,
+        updates,
+        &disk,
+        &MockTui::select_all(),
+        &crate::commands::reporter::PrettyUpdateReporter,
+      );
+      assert!(matches!(result, Err(SyncpackError::RegistryFailed(1))), "dry_run: {dry_run}");
+      assert_eq!(disk.written_text(&filepath).is_some(), !dry_run, "dry_run: {dry_run}");
+    }
+  }
+
+  fn lodash_state(ctx: &crate::context::Context) -> InstanceState {
+    ctx
+      .instances
+      .iter()
+      .find(|instance| instance.id == "lodash in /dependencies of pkg-a")
+      .expect("lodash instance not found")
+      .state
+      .borrow()
+      .clone()
+  }
+
+  #[tokio::test]
+  async fn pnpm_patch_in_package_json_is_orphaned_once_an_update_is_applied() {
+    let root = r#"{
+      "name": "root",
+      "pnpm": { "patchedDependencies": { "lodash@4.17.20": "patches/lodash@4.17.20.patch" } }
+    }"#;
+    let (mut ctx, updates) = TestBuilder::new()
+      .with_pnpm_package_manager()
+      .with_file_at("package.json", root)
+      .with_packages(vec![json!({"name": "pkg-a", "dependencies": {"lodash": "4.17.20"}})])
+      .with_registry_updates(json!({"lodash": ["4.17.20", "4.17.21"]}))
+      .run_with_updates()
+      .await;
+    assert!(
+      lodash_state(&ctx).is_fixable(),
+      "the patch is only orphaned once the update is applied"
+    );
+    ctx.config.cli.check = false;
+    ctx.config.cli.dry_run = false;
+    let filepath = ctx
+      .disk
+      .package_json_files
+      .iter()
+      .find(|file| crate::disk::package_name(file) == "pkg-a")
+      .expect("pkg-a not found")
+      .filepath
+      .clone();
+    let disk = MockDiskIo::new();
+    update::run(
+      ctx,
+      updates.unwrap(),
+      &disk,
+      &MockTui::select_all(),
+      &crate::commands::reporter::PrettyUpdateReporter,
+    )
+    .expect("update::run failed");
+    let updated: serde_json::Value = serde_json::from_str(&disk.written_text(&filepath).expect("pkg-a was not written")).unwrap();
+    assert_eq!(updated.pointer("/dependencies/lodash"), Some(&json!("4.17.21")));
+
+    let ctx = TestBuilder::new()
+      .with_pnpm_package_manager()
+      .with_file_at("package.json", root)
+      .with_packages(vec![updated])
+      .run()
+      .await;
+    assert!(matches!(lodash_state(&ctx), InstanceState::Suspect(SuspectInstance::OrphanedPatch)));
+  }
+
   #[tokio::test]
   async fn cancel_returns_cancelled_error() {
     let builder = TestBuilder::new()
@@ -447,3 +573,45 @@ mod apply {
     assert!(dirty_files(&ctx).is_empty());
   }
 }
+
+#[tokio::test]
+async fn jsr_imports_in_deno_json_keep_their_protocol() {
+  let (ctx, updates) = TestBuilder::new()
+    .with_file_at(
+      "deno.json",
+      r#"{ "imports": { "@std/path": "jsr:@std/path@^1.0.0", "chalk": "npm:chalk@5.0.0" } }"#,
+    )
+    .with_registry_updates(json!({"@std/path": ["1.0.0", "1.1.0"], "chalk": ["5.0.0", "5.3.0"]}))
+    .run_with_updates()
+    .await;
+  let rows = build_update_rows(&ctx, &updates.unwrap(), FROZEN_NOW);
+  let targets: Vec<(&str, &str)> = rows.iter().map(|row| (row.current_raw.as_str(), row.target_raw.as_str())).collect();
+  assert_eq!(
+    targets,
+    vec![
+      ("jsr:@std/path@^1.0.0", "jsr:@std/path@^1.1.0"),
+      ("npm:chalk@5.0.0", "npm:chalk@5.3.0")
+    ]
+  );
+}
+
+#[tokio::test]
+async fn tools_in_engines_and_package_manager_are_updated() {
+  let (ctx, updates) = TestBuilder::new()
+    .with_packages(vec![json!({
+      "name": "package-a",
+      "version": "1.0.0",
+      "engines": {"node": ">=20.0.0"},
+      "packageManager": "pnpm@9.1.0"
+    })])
+    .with_registry_updates(json!({"node": ["20.0.0", "22.1.0"], "pnpm": ["9.1.0", "9.12.0"]}))
+    .run_with_updates()
+    .await;
+  let rows = build_update_rows(&ctx, &updates.unwrap(), FROZEN_NOW);
+  let mut targets: Vec<(&str, &str)> = rows
+    .iter()
+    .map(|row| (row.dependency_name.as_str(), row.target_raw.as_str()))
+    .collect();
+  targets.sort();
+  assert_eq!(targets, vec![("node", ">=22.1.0"), ("pnpm", "9.12.0")]);
+}
@@ -5,7 +5,14 @@ use {
     disk::{Disk, PackageManager},
     errors::{UnsupportedConfigError, UnsupportedConfigErrors},
     instance::{Instance, InstanceDescriptor, InstanceIdx},
-    rcfile::{Rcfile, from_disk::RcfileError, validate_raw_dep_types},
+    rcfile::{
+      Rcfile,
+      from_disk::RcfileError,
+      nested::{NestedOrigins, NestedOverrides},
+      semver_group::SemverGroup,
+      validate_raw_dep_types,
+    },
+    source::Source,
     sources::Sources,
     version_group::{VersionGroup, VersionGroupBehavior},
   },
@@ -33,12 +40,13 @@ pub struct Config {
 #[derive(Debug)]
 pub struct Context {
   pub config: Config,
-  /// Mutation goes through `ctx.disk.package_json_files[idx]` or
-  /// `ctx.disk.pnpm_workspace`.
+  /// Mutation goes through `ctx.disk.package_json_files[idx]`,
+  /// `ctx.disk.pnpm_workspace` or `ctx.disk.yarnrc`.
   pub disk: Disk,
   pub instances: Vec<Instance>,
-  /// Sole owner of every `Source` reference. Pnpm yaml is a unit-variant
-  /// slot; the actual file lives on `disk.pnpm_workspace`.
+  /// Sole owner of every `Source` reference. Pnpm yaml and .yarnrc.yml are
+  /// unit-variant slots; the actual files live on `disk.pnpm_workspace` and
+  /// `disk.yarnrc`.
   pub sources: Sources,
   pub version_groups: Vec<VersionGroup>,
 }
@@ -63,6 +71,8 @@ impl Context {
     let dependency_groups = mem::take(&mut config.rcfile.dependency_groups);
     let semver_groups = mem::take(&mut config.rcfile.semver_groups);
     let update_groups = mem::take(&mut config.rcfile.update_groups);
+    let root_version_group_count = config.rcfile.version_groups.len();
+    let mut nested_overrides = NestedOverrides::default();
     let mut version_groups = config
       .rcfile
       .get_version_groups(&sources) // @TODO: Return every error
@@ -70,6 +80,7 @@ impl Context {
       .map_err(UnsupportedConfigErrors)
       .map_err(RcfileError::UnsupportedConfig)
       .map_err(ContextError::RcfileError)?;
+    let nested_origins = mem::take(&mut config.rcfile.nested_origins);
     let all_dependency_types = config.rcfile.all_dependency_types.clone();
     if let Some(ref filters) = config.cli.filters {
       filters
@@ -111,11 +122,23 @@ impl Context {
         .find(|group| group.selector.can_add(&descriptor, package_name))
         .map(|group| group.policy.clone());
 
+      check_nested_overrides(
+        &mut nested_overrides,
+        &nested_origins,
+        &semver_groups,
+        &version_groups[..version_groups
+          .len()
+          .min(nested_origins.version_groups.len() + root_version_group_count)],
+        &descriptor,
+        package_name,
+      );
+
       let version_group = version_groups
         .iter_mut()
         .find(|group| group.selector().can_add(&descriptor, package_name));
 
-      let instance = Instance::new(descriptor, package_name, preferred_semver_range, preferred_update_policy);
+      let mut instance = Instance::new(descriptor, package_name, preferred_semver_range, preferred_update_policy);
+      instance.resolved_version = resolve_from_lockfile(&disk, &sources, &instance);
       let idx = InstanceIdx(instances.len());
       instances.push(instance);
 
@@ -124,6 +147,8 @@ impl Context {
       }
     }
 
+    nested_overrides.report();
+
     Ok(Self {
       config,
       disk,
@@ -140,7 +165,7 @@ impl Context {
   }
 
   /// All catalog-def instances for this internal dep name (one per catalog the
-  /// dep appears in across pnpm and Bun). Keys by `descriptor.internal_name`
+  /// dep appears in across pnpm, Yarn and Bun). Keys by `descriptor.internal_name`
   /// so callers inside a `DependencyCore` visit can pass `dep.internal_name`
   /// directly — this works under `dependency_groups` aliasing because the
   /// def and its consumers share the same alias label.
@@ -160,10 +185,30 @@ impl Context {
   }
 }
 
+/// The version an instance is installed at according to the lockfile. Only
+/// dependencies declared in package.json files can be looked up.
+fn resolve_from_lockfile(disk: &Disk, sources: &Sources, instance: &Instance) -> Option<String> {
+  let lockfile = disk.lockfile.as_ref()?;
+  if instance.is_local_instance {
+    return None;
+  }
+  let Source::Package { file_idx, .. } = &sources.all[instance.descriptor.source_idx.0] else {
+    return None;
+  };
+  let directory = disk.package_json_files.get(*file_idx)?.filepath.parent()?;
+  let importer = directory.strip_prefix(&disk.cwd).ok()?.to_str()?.replace('\\', "/");
+  lockfile
+    .resolve(&importer, &instance.descriptor.name, instance.descriptor.specifier.get_raw())
+    .map(String::from)
+}
+
 /// Validate every dep-type-filter selector (`dependency_groups`,
 /// `semver_groups`, raw `version_groups`) against the post-discovery list of
 /// dependency types.
 fn validate_post_discovery(rcfile: &Rcfile) -> Result<(), UnsupportedConfigError> {
+  for selector in rcfile.selectors.values() {
+    selector.validate_dependency_types(&rcfile.all_dependency_types)?;
+  }
   for selector in &rcfile.dependency_groups {
     selector.validate_dependency_types(&rcfile.all_dependency_types)?;
   }
@@ -173,8 +218,55 @@ fn validate_post_discovery(rcfile: &Rcfile) -> Result<(), UnsupportedConfigError
   for group in &rcfile.update_groups {
     group.selector.validate_dependency_types(&rcfile.all_dependency_types)?;
   }
-  for group in &rcfile.version_groups {
+  for group in rcfile.nested_version_groups.iter().chain(&rcfile.version_groups) {
     validate_raw_dep_types(&group.dependency_types, &rcfile.all_dependency_types)?;
   }
   Ok(())
 }
+
+/// Record when a group from a workspace package's own config claims an
+/// instance which one of the root groups would otherwise have claimed.
+/// `version_groups` excludes the catch-all groups which syncpack adds.
+fn check_nested_overrides(
+  overrides: &mut NestedOverrides,
+  origins: &NestedOrigins,
+  semver_groups: &[SemverGroup],
+  version_groups: &[VersionGroup],
+  descriptor: &InstanceDescriptor,
+  package_name: &str,
+) {
+  if !origins.semver_groups.is_empty() {
+    // Semver groups are [built-in local group, ...nested, ...root, catch-all]
+    let nested_end = 1 + origins.semver_groups.len();
+    let root_end = semver_groups.len().saturating_sub(1);
+    overrides.check(
+      origins
+        .semver_groups
+        .iter()
+        .map(String::as_str)
+        .zip(semver_groups[1..nested_end].iter().map(|group| &group.selector)),
+      semver_groups[nested_end..root_end]
+        .iter()
+        .enumerate()
+        .map(|(index, group)| (format!("semverGroups[{index}]"), &group.selector)),
+      descriptor,
+      package_name,
+    );
+  }
+  if !origins.version_groups.is_empty() {
+    let nested_end = origins.version_groups.len();
+    overrides.check(
+      origins
+        .version_groups
+        .iter()
+        .map(String::as_str)
+        .zip(version_groups[..nested_end].iter().map(|group| group.selector())),
+      version_groups[nested_end..]
+        .iter()
+        .enumerate()
+        .map(|(index, group)| (format!("versionGroups[{index}]"), group.selector())),
+      descriptor,
+      package_name,
+    );
+  }
+}
@@ -1,6 +1,10 @@
 pub mod dependency_type;
+pub mod override_path;
 
-pub use dependency_type::{DependencyType, Strategy};
+pub use {
+  dependency_type::{DependencyType, Strategy},
+  override_path::OverridePath,
+};
 
 /// Registry URL for fetching package metadata.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
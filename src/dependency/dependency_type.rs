@@ -13,6 +13,15 @@ pub enum Strategy {
   UnnamedVersionString,
   /// "versionsByName"
   VersionsByName,
+  /// "importMap", a Deno `imports` object of "name": "npm:name@version" or
+  /// "name": "jsr:@scope/name@version"
+  ImportMap,
+  /// "overrideTree", npm `overrides` where a value is either a version or
+  /// an object of overrides which apply within that package's dependencies
+  OverrideTree,
+  /// "resolutionPaths", Yarn `resolutions` keyed by paths such as "**/name"
+  /// or "parent/name"
+  ResolutionPaths,
   /// Not recognised
   InvalidConfig,
 }
@@ -24,6 +33,9 @@ impl Strategy {
       "name@version" => Strategy::NamedVersionString,
       "version" => Strategy::UnnamedVersionString,
       "versionsByName" => Strategy::VersionsByName,
+      "importMap" => Strategy::ImportMap,
+      "overrideTree" => Strategy::OverrideTree,
+      "resolutionPaths" => Strategy::ResolutionPaths,
       _ => Strategy::InvalidConfig,
     }
   }
@@ -76,7 +88,11 @@ fn normalize_path(path: String) -> String {
 #[cfg(test)]
 mod dependency_type_test {
   use {
-    crate::{rcfile::compute_all_dependency_types, source::SourceKind},
+    crate::{
+      dependency::Strategy,
+      rcfile::{CustomType, compute_all_dependency_types},
+      source::SourceKind,
+    },
     std::collections::HashMap,
   };
 
@@ -85,10 +101,10 @@ mod dependency_type_test {
     let dep_types = compute_all_dependency_types(&HashMap::new()).expect("default dep types compute");
     assert!(!dep_types.is_empty());
     for dt in &dep_types {
-      let expected_source = if dt.name == "pnpmOverrides" {
-        SourceKind::PnpmWorkspace
-      } else {
-        SourceKind::PackageJson
+      let expected_source = match dt.name.as_str() {
+        "deno" => SourceKind::DenoJson,
+        "pnpmOverrides" => SourceKind::PnpmWorkspace,
+        _ => SourceKind::PackageJson,
       };
       assert_eq!(dt.source, expected_source, "{}: default dep type source mismatch", dt.name);
       assert!(
@@ -98,8 +114,40 @@ mod dependency_type_test {
       );
     }
     let names: Vec<&str> = dep_types.iter().map(|dt| dt.name.as_str()).collect();
-    for expected in ["dev", "prod", "peer", "local", "overrides", "pnpmOverrides", "resolutions"] {
+    for expected in [
+      "deno",
+      "dev",
+      "engines",
+      "local",
+      "overrides",
+      "packageManager",
+      "peer",
+      "pnpmOverrides",
+      "pnpmOverridesLegacy",
+      "prod",
+      "resolutions",
+      "volta",
+    ] {
       assert!(names.contains(&expected), "missing default dep type {expected}");
     }
   }
+
+  #[test]
+  fn custom_type_replaces_default_type_of_the_same_name() {
+    let custom_types = HashMap::from([(
+      String::from("engines"),
+      CustomType {
+        strategy: String::from("version"),
+        name_path: None,
+        path: String::from("engines.node"),
+        source: None,
+        unknown_fields: HashMap::new(),
+      },
+    )]);
+    let dep_types = compute_all_dependency_types(&custom_types).expect("dep types compute");
+    let engines: Vec<_> = dep_types.iter().filter(|dt| dt.name == "engines").collect();
+    assert_eq!(engines.len(), 1);
+    assert_eq!(engines[0].path, "/engines/node");
+    assert!(matches!(engines[0].strategy, Strategy::UnnamedVersionString));
+  }
 }
@@ -0,0 +1,143 @@
+use serde_json::{Map, Value};
+
+#[cfg(test)]
+#[path = "override_path_test.rs"]
+mod override_path_test;
+
+/// Where a dependency is overridden within npm's nested `overrides` tree, or
+/// within a Yarn `resolutions` path such as `**/foo` or `a/b`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OverridePath {
+  /// Selectors of the packages whose dependencies the override applies to,
+  /// outermost first. `["foo@2"]` for `{ "foo@2": { "bar": "1.0.0" } }` and
+  /// `["**"]` for `"**/bar"`.
+  pub parents: Vec<String>,
+  /// The range the dependency must already be within for the override to
+  /// apply, such as `<1.0.2` in `"json5@<1.0.2": "1.0.2"`.
+  pub range: Option<String>,
+  /// Property names from the dependency type's `path` down to the object
+  /// which holds the version.
+  pub parent_keys: Vec<String>,
+  /// Property name of the version within that object, such as `bar`, `.` or
+  /// `**/bar`.
+  pub key: String,
+}
+
+/// A dependency read from `overrides` or `resolutions`
+#[derive(Debug, PartialEq, Eq)]
+pub struct OverrideEntry {
+  /// The name of the overridden dependency, the leaf of the path
+  pub name: String,
+  pub raw_specifier: String,
+  /// `None` for plain `"name": "version"` entries
+  pub override_path: Option<OverridePath>,
+}
+
+impl OverridePath {
+  /// JSON pointer to the object which holds the version
+  pub fn parent_pointer(&self, dependency_type_path: &str) -> String {
+    self.parent_keys.iter().fold(dependency_type_path.to_string(), |pointer, key| {
+      format!("{pointer}/{}", key.replace('~', "~0").replace('/', "~1"))
+    })
+  }
+
+  /// Where the version is, for display, such as `/overrides/foo@2/bar` or
+  /// `/resolutions/**/bar`
+  pub fn location(&self, dependency_type_path: &str) -> String {
+    self
+      .parent_keys
+      .iter()
+      .chain(std::iter::once(&self.key))
+      .fold(dependency_type_path.to_string(), |location, key| format!("{location}/{key}"))
+  }
+}
+
+/// Split a selector such as `foo`, `foo@2` or `@scope/foo@^1` into its name
+/// and optional range. The `@` of a scope is not a separator.
+pub fn split_selector(selector: &str) -> (&str, Option<&str>) {
+  match selector.get(1..).and_then(|rest| rest.find('@')) {
+    Some(at) => (&selector[..at + 1], Some(&selector[at + 2..]).filter(|range| !range.is_empty())),
+    None => (selector, None),
+  }
+}
+
+/// Read npm's `overrides`, where the value of a key is either a version, or an
+/// object of overrides which apply within that package's dependencies. The
+/// `.` key of such an object holds the version of the package itself.
+///
+/// ```json
+/// { "foo": "1.0.0", "bar@2": { ".": "2.1.0", "baz": "3.0.0" } }
+/// ```
+pub fn read_override_tree(overrides: &Map<String, Value>) -> Vec<OverrideEntry> {
+  let mut entries = vec![];
+  collect_override_tree(overrides, &[], &mut entries);
+  entries
+}
+
+fn collect_override_tree(overrides: &Map<String, Value>, parent_keys: &[String], entries: &mut Vec<OverrideEntry>) {
+  for (key, value) in overrides {
+    match value {
+      Value::String(raw_specifier) => {
+        // `.` is the version of the package whose object this is
+        let selector = if key == "." {
+          parent_keys.last().map_or(key.as_str(), String::as_str)
+        } else {
+          key
+        };
+        let parents = if key == "." {
+          &parent_keys[..parent_keys.len().saturating_sub(1)]
+        } else {
+          parent_keys
+        };
+        let (name, range) = split_selector(selector);
+        let is_plain = parent_keys.is_empty() && range.is_none();
+        entries.push(OverrideEntry {
+          name: name.to_string(),
+          raw_specifier: raw_specifier.clone(),
+          override_path: (!is_plain).then(|| OverridePath {
+            parents: parents.to_vec(),
+            range: range.map(String::from),
+            parent_keys: parent_keys.to_vec(),
+            key: key.clone(),
+          }),
+        });
+      }
+      Value::Object(children) => {
+        let mut child_keys = parent_keys.to_vec();
+        child_keys.push(key.clone());
+        collect_override_tree(children, &child_keys, entries);
+      }
+      _ => {}
+    }
+  }
+}
+
+/// Read a Yarn `resolutions` key such as `foo`, `**/foo`, `a/b`,
+/// `@scope/a/**/@scope/b` or `a/foo@^1`. The last package in the path is the
+/// dependency being overridden.
+pub fn read_resolution(key: &str, raw_specifier: &str) -> OverrideEntry {
+  let mut segments: Vec<String> = vec![];
+  let mut parts = key.split('/');
+  while let Some(part) = parts.next() {
+    match parts.clone().next() {
+      Some(name) if part.starts_with('@') && part != "**" => {
+        parts.next();
+        segments.push(format!("{part}/{name}"));
+      }
+      _ => segments.push(part.to_string()),
+    }
+  }
+  let leaf = segments.pop().unwrap_or_default();
+  let (name, range) = split_selector(&leaf);
+  let is_plain = segments.is_empty() && range.is_none();
+  OverrideEntry {
+    name: name.to_string(),
+    raw_specifier: raw_specifier.to_string(),
+    override_path: (!is_plain).then(|| OverridePath {
+      parents: segments,
+      range: range.map(String::from),
+      parent_keys: vec![],
+      key: key.to_string(),
+    }),
+  }
+}
@@ -0,0 +1,94 @@
+use {
+  super::{OverrideEntry, OverridePath, read_override_tree, read_resolution, split_selector},
+  serde_json::json,
+};
+
+fn entry(name: &str, raw_specifier: &str, override_path: Option<OverridePath>) -> OverrideEntry {
+  OverrideEntry {
+    name: name.to_string(),
+    raw_specifier: raw_specifier.to_string(),
+    override_path,
+  }
+}
+
+fn path(parents: &[&str], range: Option<&str>, parent_keys: &[&str], key: &str) -> Option<OverridePath> {
+  Some(OverridePath {
+    parents: parents.iter().map(|s| s.to_string()).collect(),
+    range: range.map(String::from),
+    parent_keys: parent_keys.iter().map(|s| s.to_string()).collect(),
+    key: key.to_string(),
+  })
+}
+
+#[test]
+fn splits_selectors_into_name_and_range() {
+  assert_eq!(split_selector("foo"), ("foo", None));
+  assert_eq!(split_selector("foo@2"), ("foo", Some("2")));
+  assert_eq!(split_selector("json5@>=2.0.0 <2.2.2"), ("json5", Some(">=2.0.0 <2.2.2")));
+  assert_eq!(split_selector("@types/node"), ("@types/node", None));
+  assert_eq!(split_selector("@types/node@<18"), ("@types/node", Some("<18")));
+  assert_eq!(split_selector("foo@"), ("foo", None));
+}
+
+#[test]
+fn reads_npm_override_tree() {
+  let overrides = json!({
+    "foo": "1.0.0",
+    "json5@<1.0.2": "1.0.2",
+    "bar@2": {
+      ".": "2.1.0",
+      "baz": "3.0.0",
+      "@scope/qux": {
+        "quux": "4.0.0"
+      }
+    }
+  });
+  let entries = read_override_tree(overrides.as_object().unwrap());
+  assert_eq!(
+    entries,
+    vec![
+      entry("foo", "1.0.0", None),
+      entry("json5", "1.0.2", path(&[], Some("<1.0.2"), &[], "json5@<1.0.2")),
+      entry("bar", "2.1.0", path(&[], Some("2"), &["bar@2"], ".")),
+      entry("baz", "3.0.0", path(&["bar@2"], None, &["bar@2"], "baz")),
+      entry(
+        "quux",
+        "4.0.0",
+        path(&["bar@2", "@scope/qux"], None, &["bar@2", "@scope/qux"], "quux")
+      ),
+    ]
+  );
+}
+
+#[test]
+fn reads_yarn_resolution_paths() {
+  assert_eq!(read_resolution("foo", "1.0.0"), entry("foo", "1.0.0", None));
+  assert_eq!(read_resolution("@scope/foo", "1.0.0"), entry("@scope/foo", "1.0.0", None));
+  assert_eq!(
+    read_resolution("**/foo", "1.0.0"),
+    entry("foo", "1.0.0", path(&["**"], None, &[], "**/foo"))
+  );
+  assert_eq!(
+    read_resolution("a/b/foo", "1.0.0"),
+    entry("foo", "1.0.0", path(&["a", "b"], None, &[], "a/b/foo"))
+  );
+  assert_eq!(
+    read_resolution("@scope/a/**/@scope/foo", "1.0.0"),
+    entry(
+      "@scope/foo",
+      "1.0.0",
+      path(&["@scope/a", "**"], None, &[], "@scope/a/**/@scope/foo")
+    )
+  );
+  assert_eq!(
+    read_resolution("foo@^1.0.0", "1.2.0"),
+    entry("foo", "1.2.0", path(&[], Some("^1.0.0"), &[], "foo@^1.0.0"))
+  );
+}
+
+#[test]
+fn escapes_keys_in_parent_pointer() {
+  let override_path = path(&["@scope/a"], None, &["@scope/a", "b~c"], "d").unwrap();
+  assert_eq!(override_path.parent_pointer("/overrides"), "/overrides/@scope~1a/b~0c");
+  assert_eq!(override_path.location("/overrides"), "/overrides/@scope/a/b~c/d");
+}
@@ -1,10 +1,20 @@
 use {
-  crate::{dependency::Strategy, instance::Instance, specifier::Specifier},
+  crate::{
+    dependency::{Strategy, override_path::split_selector},
+    instance::Instance,
+    lockfile::Lockfile,
+    source::{Source, SourceKind},
+    span::{self, SpanIndex},
+    specifier::Specifier,
+  },
   detect_indent::detect_indent,
   detect_newline_style::LineEnding,
+  ignore::gitignore::{Gitignore, GitignoreBuilder},
   serde_json::Value as JsonValue,
   std::{
+    collections::{BTreeMap, HashSet},
     fs,
+    ops::Range,
     path::{Path, PathBuf},
     process::Command,
     rc::Rc,
@@ -13,9 +23,31 @@ use {
   yaml_serde::{Mapping, Value as YamlValue},
 };
 
+/// A `"name@version": "patches/name@version.patch"` entry of pnpm's
+/// `patchedDependencies`
+#[derive(Debug, PartialEq, Eq)]
+pub struct PatchedDependency {
+  pub name: String,
+  /// The exact version the patch is applied to
+  pub version: String,
+  /// Where the patch is declared, for display
+  pub declared_in: &'static str,
+}
+
+impl PatchedDependency {
+  fn new(key: &str, declared_in: &'static str) -> Option<Self> {
+    let (name, version) = split_selector(key);
+    Some(PatchedDependency {
+      name: name.to_string(),
+      version: version?.to_string(),
+      declared_in,
+    })
+  }
+}
+
 /// A YAML file held in memory alongside its raw text and a queue of
 /// pending edit operations. The dual model lets reads (`json_view`,
-/// `pnpm_catalog_names`, etc.) hit the parsed `yaml_serde::Value` while
+/// `yaml_catalog_names`, etc.) hit the parsed `yaml_serde::Value` while
 /// writes replay `patches` against the original `raw` text via the
 /// `yamlpatch` crate so comments / blank lines / indent are preserved.
 #[derive(Debug)]
@@ -85,6 +117,10 @@ pub enum DiskIoError {
   JsonParse(#[source] serde_json::Error),
   #[error("Failed to serialise JSON:\n\n{0}")]
   JsonSerialize(#[source] serde_json::Error),
+  #[error("Failed to parse JSON5:\n\n{0}")]
+  Json5Parse(#[source] json5::Error),
+  #[error("Failed to parse TOML:\n\n{0}")]
+  TomlParse(#[source] toml::de::Error),
   #[error("Failed to parse YAML:\n\n{0}")]
   YamlParse(#[source] yaml_serde::Error),
   #[error("Failed to serialise YAML:\n\n{0}")]
@@ -93,6 +129,8 @@ pub enum DiskIoError {
   YamlPatchParse(#[source] yamlpath::QueryError),
   #[error("Failed to apply YAML patch:\n\n{0}")]
   YamlPatchApply(#[source] yamlpatch::Error),
+  #[error("Cannot write {0} without losing its comments, update it by hand")]
+  JsoncRewrite(String),
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -112,6 +150,13 @@ pub struct File<T> {
   pub contents: T,
   /// `true` once `contents` has been mutated and not yet written to disk.
   pub dirty: bool,
+  /// Where each property was written when the file was read. Only indexed
+  /// for package.json and deno.json files.
+  pub spans: SpanIndex,
+  /// The text of a JSONC file with comments or trailing commas, which would
+  /// be lost if it were serialised again, so it is edited in place instead.
+  /// `None` for strict JSON.
+  pub raw: Option<String>,
 }
 
 impl<T> File<T> {
@@ -164,6 +209,22 @@ pub struct Disk {
   pub package_json_root_idx: Option<usize>,
   pub package_manager: Option<PackageManager>,
   pub pnpm_workspace: Option<YamlFile>,
+  /// Yarn Berry's `.yarnrc.yml`, which holds Yarn catalogs
+  pub yarnrc: Option<YamlFile>,
+  /// The root `deno.json` or `deno.jsonc`, followed by that of every member
+  /// of its `workspace`
+  pub deno_json_files: Vec<File<serde_json::Value>>,
+  /// Only read when needed, see `load_lockfile`
+  pub lockfile: Option<Lockfile>,
+  /// Rush's `rush.json`, which lists the folder of every project
+  pub rush_json: Option<File<serde_json::Value>>,
+  /// Nx's `workspace.json`, which lists the root of every project in
+  /// workspaces which predate `project.json`
+  pub nx_workspace_json: Option<File<serde_json::Value>>,
+  /// Whether there is an `nx.json`, when every directory containing a
+  /// `project.json` is the root of an Nx project. They are only searched for
+  /// when packages cannot be found in any other way.
+  pub has_nx_json: bool,
 }
 
 impl Disk {
@@ -172,7 +233,7 @@ impl Disk {
     let package_json_root_file = io.read_json_file(&directory.join("package.json"));
     let package_manager = if io.path_exists(&directory.join("pnpm-lock.yaml")) || io.path_exists(&directory.join("pnpm-workspace.yaml")) {
       Some(PackageManager::Pnpm)
-    } else if io.path_exists(&directory.join("yarn.lock")) {
+    } else if io.path_exists(&directory.join("yarn.lock")) || io.path_exists(&directory.join(".yarnrc.yml")) {
       Some(PackageManager::Yarn)
     } else if io.path_exists(&directory.join("package-lock.json")) {
       Some(PackageManager::Npm)
@@ -186,6 +247,15 @@ impl Disk {
     } else {
       None
     };
+    let yarnrc = if let Some(PackageManager::Yarn) = package_manager {
+      io.read_yaml_file(&directory.join(".yarnrc.yml"))
+    } else {
+      None
+    };
+
+    let deno_json_files = read_deno_json_files(io, directory);
+    let rush_json = read_rush_json(io, directory);
+    let nx_workspace_json = read_nx_workspace_json(io, directory);
 
     let mut package_json_files = Vec::new();
     let mut package_json_root_idx = None;
@@ -201,14 +271,80 @@ impl Disk {
       package_json_root_idx,
       package_manager,
       pnpm_workspace: pnpm_workspace.and_then(Result::ok),
+      yarnrc: yarnrc.and_then(Result::ok),
+      deno_json_files,
+      lockfile: None,
+      rush_json,
+      nx_workspace_json,
+      has_nx_json: io.path_exists(&directory.join("nx.json")),
+    }
+  }
+
+  /// Read the lockfile of the detected package manager, so the version each
+  /// instance resolved to can be looked up.
+  pub fn load_lockfile<T: DiskIo>(&mut self, io: &T) {
+    self.lockfile = Lockfile::from_workspace(io, &self.cwd, self.package_manager);
+  }
+
+  /// The YAML file behind a `PnpmYaml` or `YarnRc` source. `None` for
+  /// package.json sources, or when the file was not present at parse time.
+  pub fn yaml_source_mut(&mut self, kind: SourceKind) -> Option<&mut YamlFile> {
+    match kind {
+      SourceKind::PackageJson | SourceKind::DenoJson => None,
+      SourceKind::PnpmWorkspace => self.pnpm_workspace.as_mut(),
+      SourceKind::YarnRc => self.yarnrc.as_mut(),
     }
   }
 
+  /// The JSON file behind a `Package` or `DenoJson` source. `None` for yaml
+  /// sources.
+  pub fn json_source_mut(&mut self, source: &Source) -> Option<&mut File<serde_json::Value>> {
+    match source {
+      Source::Package { file_idx, .. } => self.package_json_files.get_mut(*file_idx),
+      Source::DenoJson { file_idx, .. } => self.deno_json_files.get_mut(*file_idx),
+      Source::PnpmYaml | Source::YarnRc => None,
+    }
+  }
+
+  /// Every JSON file which syncpack can write dependencies to.
+  pub fn json_files_mut(&mut self) -> impl Iterator<Item = &mut File<serde_json::Value>> {
+    self.package_json_files.iter_mut().chain(self.deno_json_files.iter_mut())
+  }
+
+  /// Every YAML file which syncpack can write to.
+  pub fn yaml_files_mut(&mut self) -> impl Iterator<Item = &mut YamlFile> {
+    self.pnpm_workspace.iter_mut().chain(self.yarnrc.iter_mut())
+  }
+
   /// Borrow the root package.json if one was loaded.
   pub fn package_json_root(&self) -> Option<&File<serde_json::Value>> {
     self.package_json_root_idx.and_then(|i| self.package_json_files.get(i))
   }
 
+  /// Every patch pnpm applies to an exact version of a dependency, read from
+  /// `pnpm.patchedDependencies` in the root package.json and
+  /// `patchedDependencies` in pnpm-workspace.yaml. Patches keyed by name
+  /// alone apply to every version and are skipped.
+  pub fn patched_dependencies(&self) -> Vec<PatchedDependency> {
+    let from_package_json = self
+      .package_json_root()
+      .and_then(|file| file.contents.pointer("/pnpm/patchedDependencies"))
+      .and_then(JsonValue::as_object)
+      .into_iter()
+      .flat_map(|patches| patches.keys())
+      .filter_map(|key| PatchedDependency::new(key, "pnpm.patchedDependencies in package.json"));
+    let from_pnpm_workspace = self
+      .pnpm_workspace
+      .as_ref()
+      .and_then(|yaml| yaml.contents.get("patchedDependencies"))
+      .and_then(YamlValue::as_mapping)
+      .into_iter()
+      .flat_map(|patches| patches.keys())
+      .filter_map(YamlValue::as_str)
+      .filter_map(|key| PatchedDependency::new(key, "patchedDependencies in pnpm-workspace.yaml"));
+    from_package_json.chain(from_pnpm_workspace).collect()
+  }
+
   /// Default formatting fallback used when a file's own formatting is empty.
   /// Prefers the root pkg.json's formatting; falls back to defaults.
   pub fn formatting_fallback(&self) -> DetectedFormatting {
@@ -251,6 +387,121 @@ pub fn parse_json_file(raw: String, filepath: PathBuf) -> Option<File<serde_json
       formatting: detect_formatting(&raw),
       contents,
       dirty: false,
+      spans: SpanIndex::from_json(&raw),
+      raw: None,
+    }),
+    Err(err) => {
+      log::error!("Invalid JSON at {}: {err}", filepath.to_str().unwrap_or("unknown"));
+      None
+    }
+  }
+}
+
+/// Read `rush.json`, which Rush allows to contain comments.
+fn read_rush_json<T: DiskIo>(io: &T, directory: &Path) -> Option<File<serde_json::Value>> {
+  match io.read_textfile(&directory.join("rush.json"))? {
+    Ok(file) => parse_jsonc_file(file.contents, file.filepath),
+    Err(err) => {
+      log::error!("{err}");
+      None
+    }
+  }
+}
+
+fn read_nx_workspace_json<T: DiskIo>(io: &T, directory: &Path) -> Option<File<serde_json::Value>> {
+  match io.read_json_file(&directory.join("workspace.json"))? {
+    Ok(file) => Some(file),
+    Err(err) => {
+      log::error!("{err}");
+      None
+    }
+  }
+}
+
+/// Every directory below `directory` containing a `project.json`, which is
+/// how Nx workspaces with an `nx.json` locate their projects
+pub fn find_nx_project_roots<T: DiskIo>(io: &T, directory: &Path, respect_gitignore: bool) -> Vec<String> {
+  let mut roots: Vec<String> = io
+    .find_package_jsons(directory, &["**/project.json".to_string()], respect_gitignore)
+    .iter()
+    .filter_map(|path| {
+      path
+        .parent()?
+        .strip_prefix(directory)
+        .ok()?
+        .to_str()
+        .map(|dir| dir.replace('\\', "/"))
+    })
+    .filter(|dir| !dir.is_empty())
+    .collect();
+  roots.sort();
+  roots
+}
+
+/// Read the root `deno.json` or `deno.jsonc`, then the config file of every
+/// directory listed in its `workspace`. A member ending in `/*` includes each
+/// directory within it.
+fn read_deno_json_files<T: DiskIo>(io: &T, directory: &Path) -> Vec<File<serde_json::Value>> {
+  let Some(root_file) = read_deno_json_file(io, directory) else {
+    return vec![];
+  };
+  let members: Vec<String> = root_file
+    .contents
+    .pointer("/workspace/members")
+    .or_else(|| root_file.contents.get("workspace"))
+    .and_then(|v| v.as_array())
+    .map(|arr| arr.iter().filter_map(|v| v.as_str().map(String::from)).collect())
+    .unwrap_or_default();
+  let mut member_dirs: Vec<PathBuf> = vec![];
+  for member in &members {
+    let member = member.trim_start_matches("./").trim_end_matches('/');
+    match member.strip_suffix("/*") {
+      Some(parent) => match io.read_dir(&directory.join(parent)) {
+        Ok(mut entries) => {
+          entries.sort_by(|a, b| a.path().cmp(b.path()));
+          member_dirs.extend(
+            entries
+              .into_iter()
+              .filter(|entry| entry.is_dir())
+              .map(|entry| entry.path().to_path_buf()),
+          );
+        }
+        Err(err) => log::debug!("Deno workspace member '{member}' not readable: {err}"),
+      },
+      None => member_dirs.push(directory.join(member)),
+    }
+  }
+  let mut files = vec![root_file];
+  files.extend(member_dirs.iter().filter_map(|dir| read_deno_json_file(io, dir)));
+  files
+}
+
+/// Read `deno.json`, or `deno.jsonc` when there is none, from a directory.
+fn read_deno_json_file<T: DiskIo>(io: &T, directory: &Path) -> Option<File<serde_json::Value>> {
+  ["deno.json", "deno.jsonc"]
+    .iter()
+    .find_map(|file_name| match io.read_textfile(&directory.join(file_name))? {
+      Ok(file) => Some(parse_jsonc_file(file.contents, file.filepath)),
+      Err(err) => {
+        log::error!("{err}");
+        None
+      }
+    })
+    .flatten()
+}
+
+/// Parse a JSON file which may contain comments, such as `deno.jsonc` or
+/// `rush.json`. It is read as JSON5 so comments and trailing commas are
+/// accepted. Returns `None` if the file is invalid; the error is logged.
+pub fn parse_jsonc_file(raw: String, filepath: PathBuf) -> Option<File<serde_json::Value>> {
+  match json5::from_str::<serde_json::Value>(&raw) {
+    Ok(contents) => Some(File {
+      filepath,
+      formatting: detect_formatting(&raw),
+      contents,
+      dirty: false,
+      spans: SpanIndex::from_json(&raw),
+      raw: serde_json::from_str::<serde::de::IgnoredAny>(&raw).is_err().then_some(raw),
     }),
     Err(err) => {
       log::error!("Invalid JSON at {}: {err}", filepath.to_str().unwrap_or("unknown"));
@@ -316,10 +567,11 @@ pub trait DiskIo {
   /// the in-memory `contents` is serialised fresh via `yaml_serde`.
   fn write_yaml_file(&self, file: &YamlFile) -> Result<(), DiskIoError>;
   /// Find every `package.json` under `root` that matches `patterns`,
-  /// honouring `.gitignore` and skipping `node_modules`/`.git`. Patterns
-  /// use Override semantics: bare globs include, `!`-prefixed globs
-  /// exclude. `*` matches a single path segment; `**` spans separators.
-  fn find_package_jsons(&self, root: &Path, patterns: &[String]) -> Vec<PathBuf>;
+  /// honouring `.syncpackignore`, `.gitignore` when `respect_gitignore` is
+  /// set, and skipping `node_modules`/`.git`. Patterns use Override
+  /// semantics: bare globs include, `!`-prefixed globs exclude. `*` matches a
+  /// single path segment; `**` spans separators.
+  fn find_package_jsons(&self, root: &Path, patterns: &[String], respect_gitignore: bool) -> Vec<PathBuf>;
 }
 
 #[derive(Debug)]
@@ -383,6 +635,8 @@ impl DiskIo for LiveDiskIo {
         formatting: detect_formatting(&raw),
         contents: raw,
         dirty: false,
+        spans: SpanIndex::default(),
+        raw: None,
       })
     })
   }
@@ -397,6 +651,8 @@ impl DiskIo for LiveDiskIo {
             formatting: detect_formatting(&file.contents),
             contents: parsed,
             dirty: false,
+            spans: SpanIndex::default(),
+            raw: None,
           })
       })
     })
@@ -418,6 +674,8 @@ impl DiskIo for LiveDiskIo {
             formatting: detect_formatting(&file.contents),
             contents: parsed,
             dirty: false,
+            spans: SpanIndex::default(),
+            raw: None,
           })
       })
     })
@@ -440,36 +698,115 @@ impl DiskIo for LiveDiskIo {
     fs::write(&file.filepath, &bytes).map_err(DiskIoError::FileWrite)
   }
 
-  fn find_package_jsons(&self, root: &Path, patterns: &[String]) -> Vec<PathBuf> {
-    let mut builder = ignore::overrides::OverrideBuilder::new(root);
-    for pattern in patterns {
-      if let Err(err) = builder.add(pattern) {
-        log::debug!("Invalid source pattern '{pattern}': {err}");
-      }
+  fn find_package_jsons(&self, root: &Path, patterns: &[String], respect_gitignore: bool) -> Vec<PathBuf> {
+    let overrides = build_source_overrides(root, patterns);
+    let syncpackignore = read_ignore_file(root, SYNCPACKIGNORE);
+    let found = walk_package_jsons(root, &overrides, respect_gitignore, &syncpackignore);
+    if log::log_enabled!(log::Level::Debug) {
+      let candidates = walk_package_jsons(root, &overrides, false, &Gitignore::empty());
+      let gitignore = if respect_gitignore {
+        read_ignore_file(root, ".gitignore")
+      } else {
+        Gitignore::empty()
+      };
+      log_excluded_counts(
+        &candidates,
+        &found,
+        &[(SYNCPACKIGNORE, &syncpackignore), (".gitignore", &gitignore)],
+      );
     }
-    let overrides = builder.build().unwrap_or_else(|err| {
-      log::debug!("Failed to build source pattern overrides: {err}");
-      ignore::overrides::Override::empty()
-    });
+    found
+  }
+}
 
-    ignore::WalkBuilder::new(root)
-      .require_git(false)
-      .overrides(overrides)
-      .filter_entry(|entry| {
-        let name = entry.file_name();
-        name != "node_modules" && name != ".git"
-      })
-      .build()
-      .filter_map(|result| match result {
-        Ok(entry) => Some(entry),
-        Err(err) => {
-          log::debug!("Walk error: {err}");
-          None
-        }
+/// A file at the root of the workspace which uses gitignore syntax to exclude
+/// files from being discovered by syncpack
+pub const SYNCPACKIGNORE: &str = ".syncpackignore";
+
+/// Compile `--source` style patterns. Bare globs include, `!`-prefixed globs
+/// exclude.
+pub(crate) fn build_source_overrides(root: &Path, patterns: &[String]) -> ignore::overrides::Override {
+  let mut builder = ignore::overrides::OverrideBuilder::new(root);
+  for pattern in patterns {
+    if let Err(err) = builder.add(pattern) {
+      log::debug!("Invalid source pattern '{pattern}': {err}");
+    }
+  }
+  builder.build().unwrap_or_else(|err| {
+    log::debug!("Failed to build source pattern overrides: {err}");
+    ignore::overrides::Override::empty()
+  })
+}
+
+/// Compile an ignore file written in gitignore syntax. Missing or invalid
+/// lines are skipped.
+pub(crate) fn ignore_matcher_from_str(root: &Path, contents: &str) -> Gitignore {
+  let mut builder = GitignoreBuilder::new(root);
+  for line in contents.lines() {
+    if let Err(err) = builder.add_line(None, line) {
+      log::debug!("Invalid ignore rule '{line}': {err}");
+    }
+  }
+  builder.build().unwrap_or_else(|err| {
+    log::debug!("Failed to build ignore rules: {err}");
+    Gitignore::empty()
+  })
+}
+
+fn read_ignore_file(root: &Path, file_name: &str) -> Gitignore {
+  match fs::read_to_string(root.join(file_name)) {
+    Ok(contents) => ignore_matcher_from_str(root, &contents),
+    Err(_) => Gitignore::empty(),
+  }
+}
+
+/// Walk `root` for JSON files matching `overrides`. The `.syncpackignore`
+/// rules are checked against files as well as directories, so they exclude
+/// files which the source patterns match explicitly.
+fn walk_package_jsons(root: &Path, overrides: &ignore::overrides::Override, git_ignore: bool, syncpackignore: &Gitignore) -> Vec<PathBuf> {
+  let syncpackignore = syncpackignore.clone();
+  ignore::WalkBuilder::new(root)
+    .require_git(false)
+    .git_ignore(git_ignore)
+    .git_exclude(git_ignore)
+    .git_global(git_ignore)
+    .overrides(overrides.clone())
+    .filter_entry(move |entry| {
+      let name = entry.file_name();
+      let is_dir = entry.file_type().is_some_and(|t| t.is_dir());
+      name != "node_modules" && name != ".git" && !syncpackignore.matched(entry.path(), is_dir).is_ignore()
+    })
+    .build()
+    .filter_map(|result| match result {
+      Ok(entry) => Some(entry),
+      Err(err) => {
+        log::debug!("Walk error: {err}");
+        None
+      }
+    })
+    .filter(|entry| entry.file_type().is_some_and(|t| t.is_file()) && entry.file_name().to_string_lossy().ends_with(".json"))
+    .map(|entry| entry.into_path())
+    .collect()
+}
+
+/// Log how many files matching the source patterns were excluded by each
+/// ignore rule. `rules` are checked in order; a file excluded by none of them
+/// was excluded by an ignore file in a nested directory.
+pub(crate) fn log_excluded_counts(candidates: &[PathBuf], found: &[PathBuf], rules: &[(&str, &Gitignore)]) {
+  let found: HashSet<&PathBuf> = found.iter().collect();
+  let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+  for path in candidates.iter().filter(|path| !found.contains(path)) {
+    let rule = rules
+      .iter()
+      .find_map(|(file_name, matcher)| match matcher.matched_path_or_any_parents(path, false) {
+        ignore::Match::Ignore(glob) => Some(format!("'{}' in {file_name}", glob.original())),
+        _ => None,
       })
-      .filter(|entry| entry.file_type().is_some_and(|t| t.is_file()) && entry.file_name().to_string_lossy().ends_with(".json"))
-      .map(|entry| entry.into_path())
-      .collect()
+      .unwrap_or_else(|| "an ignore file in a nested directory".to_string());
+    *counts.entry(rule).or_default() += 1;
+  }
+  for (rule, count) in counts {
+    log::debug!("{count} file(s) matching the source patterns were excluded by {rule}");
   }
 }
 
@@ -694,8 +1031,9 @@ pub fn copy_expected_specifier_json(file: &mut File<JsonValue>, instance: &Insta
     Strategy::UnnamedVersionString => {
       set_prop(file, path_to_prop_str, JsonValue::String(raw_specifier));
     }
-    Strategy::VersionsByName => {
-      set_nested_prop(file, path_to_prop_str, &instance.descriptor.name, JsonValue::String(raw_specifier));
+    Strategy::VersionsByName | Strategy::ImportMap | Strategy::OverrideTree | Strategy::ResolutionPaths => {
+      let (parent_pointer, key) = instance.descriptor.get_parent_pointer_and_key();
+      set_nested_prop(file, &parent_pointer, &key, JsonValue::String(raw_specifier));
     }
     Strategy::InvalidConfig => {
       unreachable!("unrecognised strategy");
@@ -896,7 +1234,7 @@ pub fn remove_catalog_definition(file: &mut YamlFile, catalog_name: &str, dep_na
 
 /// Apply a consumer instance's `expected_specifier` to the yaml. Catalog
 /// instances route through the catalog-definition path; non-catalog
-/// `PnpmWorkspace` instances (pnpm `overrides`, which use the
+/// `PnpmWorkspace` / `YarnRc` instances (pnpm `overrides`, which use the
 /// `versionsByName` strategy) write into the top-level block named by the
 /// dep type's `path` (e.g. `overrides`). No-op when the instance has no
 /// expected specifier.
@@ -917,17 +1255,74 @@ pub fn copy_expected_specifier_yaml(file: &mut YamlFile, instance: &Instance) {
   }
 }
 
-/// Persist a JSON file when dirty. Returns `Ok(true)` on actual write,
-/// `Ok(false)` when no-op. Resets `dirty = false` post-write.
-pub fn write_json_file<D: DiskIo>(
-  file: &mut File<JsonValue>,
-  io: &D,
+/// The bytes `write_json_file` would write, used to preview changes during
+/// `--dry-run`. JSONC files are edited in place, see `edit_jsonc`.
+pub fn render_json_bytes(
+  file: &File<JsonValue>,
   indent_override: Option<&str>,
   formatting_fallback: &DetectedFormatting,
-) -> Result<bool, DiskIoError> {
-  if !file.dirty {
-    return Ok(false);
+) -> Result<Vec<u8>, DiskIoError> {
+  match &file.raw {
+    Some(raw) => edit_jsonc(raw, &file.spans, &file.contents)
+      .map(String::into_bytes)
+      .ok_or_else(|| DiskIoError::JsoncRewrite(file.filepath.to_string_lossy().to_string())),
+    None => get_pretty_json_bytes(&get_json_snapshot(file, indent_override, formatting_fallback)),
+  }
+}
+
+/// The text of a JSONC file with every value which has changed replaced
+/// where it was written, keeping its comments, trailing commas and layout.
+/// `None` when properties were added, removed or replaced by an object or
+/// array, which cannot be written without losing them.
+pub fn edit_jsonc(raw: &str, spans: &SpanIndex, contents: &JsonValue) -> Option<String> {
+  let original = json5::from_str::<JsonValue>(raw).ok()?;
+  let mut edits: Vec<(Range<usize>, String)> = vec![];
+  collect_jsonc_edits(raw, spans, "", &original, contents, &mut edits)?;
+  edits.sort_by_key(|(range, _)| std::cmp::Reverse(range.start));
+  let mut text = raw.to_string();
+  edits.into_iter().for_each(|(range, value)| text.replace_range(range, &value));
+  Some(text)
+}
+
+fn collect_jsonc_edits(
+  raw: &str,
+  spans: &SpanIndex,
+  pointer: &str,
+  before: &JsonValue,
+  after: &JsonValue,
+  edits: &mut Vec<(Range<usize>, String)>,
+) -> Option<()> {
+  match (before, after) {
+    _ if before == after => Some(()),
+    (JsonValue::Object(before), JsonValue::Object(after)) => {
+      if before.len() != after.len() || before.keys().any(|key| !after.contains_key(key)) {
+        return None;
+      }
+      before
+        .iter()
+        .try_for_each(|(key, value)| collect_jsonc_edits(raw, spans, &span::child_pointer(pointer, key), value, &after[key], edits))
+    }
+    (JsonValue::Array(before), JsonValue::Array(after)) if before.len() == after.len() => before
+      .iter()
+      .zip(after)
+      .enumerate()
+      .try_for_each(|(index, (before, after))| collect_jsonc_edits(raw, spans, &format!("{pointer}/{index}"), before, after, edits)),
+    (JsonValue::Object(_) | JsonValue::Array(_), _) | (_, JsonValue::Object(_) | JsonValue::Array(_)) => None,
+    _ => {
+      let range = span::scalar_json_range(raw, spans.get(pointer)?.value)?;
+      edits.push((range, after.to_string()));
+      Some(())
+    }
   }
+}
+
+/// Borrow the contents of a JSON file with the formatting it will be
+/// written with
+fn get_json_snapshot<'a>(
+  file: &'a File<JsonValue>,
+  indent_override: Option<&str>,
+  formatting_fallback: &DetectedFormatting,
+) -> File<&'a JsonValue> {
   let effective_formatting = match indent_override {
     Some(indent) => DetectedFormatting {
       indent: indent.to_string(),
@@ -936,13 +1331,33 @@ pub fn write_json_file<D: DiskIo>(
     None if file.formatting.indent.is_empty() => formatting_fallback.clone(),
     None => file.formatting.clone(),
   };
-  let snapshot = File {
+  File {
     filepath: file.filepath.clone(),
     formatting: effective_formatting,
     contents: &file.contents,
     dirty: false,
-  };
-  io.write_json_file(&snapshot)?;
+    spans: SpanIndex::default(),
+    raw: None,
+  }
+}
+
+/// Persist a JSON file when dirty. Returns `Ok(true)` on actual write,
+/// `Ok(false)` when no-op. Resets `dirty = false` post-write. A JSONC file
+/// which cannot be written without losing its comments is an error.
+pub fn write_json_file<D: DiskIo>(
+  file: &mut File<JsonValue>,
+  io: &D,
+  indent_override: Option<&str>,
+  formatting_fallback: &DetectedFormatting,
+) -> Result<bool, DiskIoError> {
+  if !file.dirty {
+    return Ok(false);
+  }
+  if file.raw.is_some() {
+    io.write_bytes(&file.filepath, &render_json_bytes(file, indent_override, formatting_fallback)?)?;
+  } else {
+    io.write_json_file(&get_json_snapshot(file, indent_override, formatting_fallback))?;
+  }
   file.dirty = false;
   Ok(true)
 }
@@ -1,9 +1,11 @@
 use {
   crate::{
     disk::{
-      Disk, File, PackageManager, PendingYamlOp, empty_yaml_file, ensure_object_path, insert_catalog_definition, json_view,
-      parse_yaml_file, remove_catalog_definition, remove_prop, render_yaml_bytes, set_nested_prop, set_prop,
+      Disk, DiskIo, File, PackageManager, PendingYamlOp, empty_yaml_file, ensure_object_path, find_nx_project_roots,
+      ignore_matcher_from_str, insert_catalog_definition, json_view, parse_yaml_file, remove_catalog_definition, remove_prop,
+      render_yaml_bytes, set_nested_prop, set_prop,
     },
+    span::SpanIndex,
     specifier::Specifier,
     test::mock_disk::MockDiskIo,
   },
@@ -17,6 +19,8 @@ fn json_file_from_value(value: serde_json::Value) -> File<serde_json::Value> {
     formatting: Default::default(),
     contents: value,
     dirty: false,
+    spans: SpanIndex::default(),
+    raw: None,
   }
 }
 
@@ -29,6 +33,56 @@ fn detects_pnpm_from_workspace_yaml_without_lockfile() {
   assert!(disk.pnpm_workspace.is_some(), "pnpm-workspace.yaml should be loaded");
 }
 
+#[test]
+fn detects_yarn_berry_from_yarnrc_without_lockfile() {
+  let mut io = MockDiskIo::new();
+  io.add_file(".yarnrc.yml", "catalog:\n  react: ^18.0.0\n".to_string());
+  let disk = Disk::from_workspace(&io, io.root());
+  assert_eq!(disk.package_manager, Some(PackageManager::Yarn));
+  assert!(disk.yarnrc.is_some(), ".yarnrc.yml should be loaded");
+}
+
+#[test]
+fn yarnrc_is_ignored_when_another_package_manager_is_detected() {
+  let mut io = MockDiskIo::new();
+  io.add_file("pnpm-lock.yaml", "lockfileVersion: '6.0'\n".to_string());
+  io.add_file(".yarnrc.yml", "catalog:\n  react: ^18.0.0\n".to_string());
+  let disk = Disk::from_workspace(&io, io.root());
+  assert_eq!(disk.package_manager, Some(PackageManager::Pnpm));
+  assert!(disk.yarnrc.is_none());
+}
+
+#[test]
+fn reads_deno_workspace_members() {
+  let mut io = MockDiskIo::new();
+  io.add_file(
+    "deno.jsonc",
+    "{\n  // Members\n  \"workspace\": [\"./app\", \"./libs/*\"],\n}\n".to_string(),
+  );
+  io.add_file("app/deno.json", "{ \"name\": \"@acme/app\" }".to_string());
+  io.add_file("libs/fmt/deno.jsonc", "{ \"name\": \"@acme/fmt\" }".to_string());
+  io.add_file("libs/path/deno.json", "{ \"name\": \"@acme/path\" }".to_string());
+  io.add_file("other/deno.json", "{ \"name\": \"@acme/other\" }".to_string());
+  let disk = Disk::from_workspace(&io, io.root());
+  let paths: Vec<String> = disk
+    .deno_json_files
+    .iter()
+    .map(|file| file.filepath.strip_prefix(io.root()).unwrap().to_string_lossy().to_string())
+    .collect();
+  assert_eq!(
+    paths,
+    vec!["deno.jsonc", "app/deno.json", "libs/fmt/deno.jsonc", "libs/path/deno.json"]
+  );
+}
+
+#[test]
+fn no_deno_files_without_a_root_deno_json() {
+  let mut io = MockDiskIo::new();
+  io.add_file("app/deno.json", "{ \"name\": \"@acme/app\" }".to_string());
+  let disk = Disk::from_workspace(&io, io.root());
+  assert!(disk.deno_json_files.is_empty());
+}
+
 #[test]
 fn detects_bun_from_legacy_binary_lockfile() {
   let mut io = MockDiskIo::new();
@@ -54,6 +108,8 @@ fn file_dirty_settable() {
     formatting: Default::default(),
     contents: serde_json::Value::Null,
     dirty: false,
+    spans: SpanIndex::default(),
+    raw: None,
   };
   assert!(!file.is_dirty());
   file.mark_dirty();
@@ -82,6 +138,12 @@ fn disk_constructs_without_io_field() {
     package_json_root_idx: None,
     package_manager: None,
     pnpm_workspace: None,
+    yarnrc: None,
+    deno_json_files: Vec::new(),
+    lockfile: None,
+    rush_json: None,
+    nx_workspace_json: None,
+    has_nx_json: false,
   };
   assert_eq!(disk.cwd, std::path::PathBuf::from("/test"));
 }
@@ -661,6 +723,8 @@ mod disk_ops {
       formatting,
       contents: &file.contents,
       dirty: false,
+      spans: SpanIndex::default(),
+      raw: None,
     };
     String::from_utf8(get_pretty_json_bytes(&snapshot).unwrap()).unwrap()
   }
@@ -672,7 +736,9 @@ mod disk_ops {
       internal_name: name.to_string(),
       is_local_dependency: false,
       name: name.to_string(),
+      override_path: None,
       source_idx: SourceIdx(0),
+      span: None,
       specifier: Specifier::new("0.0.0"), // ignored — overridden by mark_fixable below
     };
     let instance = Instance::new(descriptor, "test-pkg", None, None);
@@ -1038,3 +1104,92 @@ mod disk_ops {
     assert!(z_pos < a_pos && a_pos < m_pos, "key order not preserved, got:\n{result}");
   }
 }
+
+#[test]
+fn reads_rush_json_with_comments() {
+  let mut io = MockDiskIo::new();
+  io.add_file(
+    "rush.json",
+    "{\n  // Every project in the repo\n  \"projects\": [{ \"packageName\": \"app\", \"projectFolder\": \"apps/app\" },],\n}\n".to_string(),
+  );
+  let disk = Disk::from_workspace(&io, io.root());
+  let rush_json = disk.rush_json.expect("rush.json should be read");
+  assert_eq!(
+    rush_json.contents.pointer("/projects/0/projectFolder").and_then(|v| v.as_str()),
+    Some("apps/app")
+  );
+}
+
+#[test]
+fn reads_nx_workspace_json() {
+  let mut io = MockDiskIo::new();
+  io.add_file("nx.json", "{}".to_string());
+  io.add_json(
+    "workspace.json",
+    &json!({ "version": 2, "projects": { "web": "apps/web", "ui": { "root": "libs/ui" } } }),
+  );
+  let disk = Disk::from_workspace(&io, io.root());
+  assert!(disk.has_nx_json);
+  let workspace_json = disk.nx_workspace_json.expect("workspace.json should be read");
+  assert_eq!(
+    workspace_json.contents.pointer("/projects/web").and_then(|v| v.as_str()),
+    Some("apps/web")
+  );
+}
+
+#[test]
+fn finds_nx_project_roots_from_project_json_files() {
+  let mut io = MockDiskIo::new();
+  io.add_file("project.json", "{}".to_string());
+  io.add_file("libs/ui/project.json", "{}".to_string());
+  io.add_file("apps/web/project.json", "{}".to_string());
+  assert_eq!(find_nx_project_roots(&io, io.root(), true), vec!["apps/web", "libs/ui"]);
+}
+
+#[test]
+fn no_nx_json() {
+  let mut io = MockDiskIo::new();
+  io.add_file("apps/web/project.json", "{}".to_string());
+  let disk = Disk::from_workspace(&io, io.root());
+  assert!(!disk.has_nx_json);
+}
+
+#[test]
+fn ignore_matcher_supports_gitignore_syntax() {
+  let root = PathBuf::from("/repo");
+  let matcher = ignore_matcher_from_str(&root, "# comment\npackages/*/\n!packages/keep/\n");
+  let is_ignored = |path: &str| matcher.matched_path_or_any_parents(root.join(path), false).is_ignore();
+  assert!(is_ignored("packages/generated/package.json"));
+  assert!(!is_ignored("packages/keep/package.json"));
+  assert!(!is_ignored("package.json"));
+}
+
+#[test]
+fn finds_package_jsons_not_excluded_by_ignore_files() {
+  let mut io = MockDiskIo::new();
+  io.add_file(".gitignore", "dist/\n".to_string());
+  io.add_file(".syncpackignore", "packages/generated/\n".to_string());
+  for path in [
+    "package.json",
+    "packages/a/package.json",
+    "packages/generated/package.json",
+    "dist/package.json",
+  ] {
+    io.add_json(path, &json!({}));
+  }
+  let patterns = ["**/package.json".to_string()];
+  let relative = |respect_gitignore: bool| {
+    let mut paths: Vec<String> = io
+      .find_package_jsons(io.root(), &patterns, respect_gitignore)
+      .iter()
+      .map(|path| path.strip_prefix(io.root()).unwrap().to_string_lossy().replace('\\', "/"))
+      .collect();
+    paths.sort();
+    paths
+  };
+  assert_eq!(relative(true), vec!["package.json", "packages/a/package.json"]);
+  assert_eq!(
+    relative(false),
+    vec!["dist/package.json", "package.json", "packages/a/package.json"]
+  );
+}
@@ -21,8 +21,10 @@ impl fmt::Display for UnsupportedConfigErrors {
 
 #[derive(Debug, Error)]
 pub enum UnsupportedConfigError {
-  #[error("Config property '{property}' is deprecated\n{hint}")]
+  #[error("Config property '{property}' is deprecated\n{hint}\nRun 'syncpack migrate-config' to rewrite it automatically")]
   DeprecatedProperty { property: String, hint: String },
+  #[error("selectors reference each other in a loop: {chain}")]
+  CircularSelector { chain: String },
   #[error("dependencyType '{name}' does not match any built-in or custom types")]
   InvalidDependencyType { name: String },
   #[error("Invalid semver group: must have isDisabled, isIgnored, or range")]
@@ -38,12 +40,47 @@ pub enum UnsupportedConfigError {
     key: String,
     permitted: Vec<&'static str>,
   },
-  #[error("customTypes.<name>.source: '{value}' is not a recognised source.\nUse 'PackageJson' or 'PnpmWorkspace'.")]
+  #[error("customTypes.<name>.source: '{value}' is not a recognised source.\nUse 'PackageJson', 'PnpmWorkspace', 'YarnRc' or 'DenoJson'.")]
   InvalidSource { value: String },
+  #[error("Invalid value '{value}' for '{key}' from {origin}, expected {expected}")]
+  InvalidOverride {
+    key: String,
+    value: String,
+    origin: String,
+    expected: String,
+  },
+  #[error("{path}.packages cannot be set in a nested config, its groups always apply to the package which contains it")]
+  NestedGroupPackages { path: String },
+  #[error("Config property '{property}' is not supported in {origin}, nested configs can only contain versionGroups and semverGroups")]
+  UnsupportedNestedProperty { origin: String, property: String },
+  #[error("Selector '{name}' used at {path} is not defined in selectors")]
+  UnknownSelector { name: String, path: String },
+  #[error("Config property '{key}' from {origin} cannot be overridden, expected one of: {supported}")]
+  UnknownOverride { key: String, origin: String, supported: String },
   #[error("Config property '{path}' is not recognised")]
   UnrecognisedProperty { path: String },
 }
 
+/// The status syncpack exits with, documented at
+/// https://syncpack.dev/guide/exit-codes/
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(i32)]
+pub enum ExitCode {
+  Success = 0,
+  /// An instance or file has an issue with a severity of `error`
+  IssuesFound = 1,
+  /// There are no errors, but more warnings than `--max-warnings` allows
+  TooManyWarnings = 2,
+  /// The command line arguments or config file are not valid
+  InvalidConfig = 3,
+  /// A file could not be read or written
+  Io = 4,
+  /// Versions of a dependency could not be fetched from the npm registry
+  Registry = 5,
+  /// The user cancelled an interactive prompt
+  Cancelled = 6,
+}
+
 #[derive(Debug, Error)]
 pub enum SyncpackError {
   #[error(transparent)]
@@ -54,16 +91,63 @@ pub enum SyncpackError {
   DeprecatedCommand,
   #[error("Issues found")]
   IssuesFound,
+  #[error("{count} warnings found, which is more than --max-warnings {max_warnings}")]
+  TooManyWarnings { count: usize, max_warnings: usize },
+  #[error("Failed to fetch {0} dependencies from the npm registry")]
+  RegistryFailed(usize),
+  /// An error which has already been logged, such as one from a single
+  /// workspace root, which only sets the exit code
+  #[error("")]
+  AlreadyReported(ExitCode),
+  #[error("Baseline file {0} does not exist, create it with syncpack lint --update-baseline")]
+  BaselineNotFound(String),
+  #[error("Baseline file {path} is not valid: {source}")]
+  InvalidBaseline { path: String, source: serde_json::Error },
   #[error("Cancelled, no changes written")]
   Cancelled,
   #[error("{0}")]
   CliError(clap::Error),
   #[error("No subcommand specified")]
   NoSubcommand,
+  #[error("No config file found to migrate")]
+  NoConfigFile,
+  #[error(
+    "{0} is evaluated by Node.js and cannot be rewritten automatically.\nSee https://syncpack.dev/guide/migrate-v14 to migrate it by hand."
+  )]
+  CannotMigrateJavaScriptConfig(String),
+  #[error("{0} cannot be rewritten automatically without losing its comments, apply the changes above by hand")]
+  CannotRewriteConfig(String),
   #[error(transparent)]
   RcfileError(RcfileError),
   #[error(
     "Bun catalog blocks were found at both top-level (/catalog or /catalogs/*) and nested under /workspaces (/workspaces/catalog or /workspaces/catalogs/*) in the root package.json. Only one location can be used."
   )]
   BunDualCatalogPath,
+  #[error("{root}: {source}")]
+  WorkspaceRoot { root: String, source: Box<SyncpackError> },
+}
+
+impl SyncpackError {
+  pub fn exit_code(&self) -> ExitCode {
+    match self {
+      SyncpackError::IssuesFound => ExitCode::IssuesFound,
+      SyncpackError::TooManyWarnings { .. } => ExitCode::TooManyWarnings,
+      SyncpackError::DiskIoError(_) => ExitCode::Io,
+      SyncpackError::RegistryFailed(_) => ExitCode::Registry,
+      SyncpackError::Cancelled => ExitCode::Cancelled,
+      SyncpackError::AlreadyReported(exit_code) => *exit_code,
+      SyncpackError::WorkspaceRoot { source, .. } => source.exit_code(),
+      SyncpackError::ContextError(_)
+      | SyncpackError::DeprecatedCommand
+      | SyncpackError::CliError(_)
+      | SyncpackError::NoSubcommand
+      | SyncpackError::NoConfigFile
+      | SyncpackError::CannotMigrateJavaScriptConfig(_)
+      | SyncpackError::CannotRewriteConfig(_)
+      | SyncpackError::RcfileError(_)
+      | SyncpackError::BaselineNotFound(_)
+      | SyncpackError::InvalidBaseline { .. }
+      | SyncpackError::BunDualCatalogPath => ExitCode::InvalidConfig,
+    }
+  }
 }
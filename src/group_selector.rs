@@ -1,3 +1,4 @@
+pub mod named;
 pub mod pattern_matcher;
 
 #[cfg(test)]
@@ -5,7 +6,9 @@ mod group_selector_test;
 
 use {
   crate::{dependency::DependencyType, errors::UnsupportedConfigError, instance::InstanceDescriptor},
+  named::SelectorExpr,
   pattern_matcher::PatternMatcher,
+  std::sync::Arc,
 };
 
 #[derive(Clone, Debug)]
@@ -50,6 +53,9 @@ pub struct GroupSelector {
   /// - "workspace-protocol" or -!workspace-protocol"
   pub include_specifier_types: Vec<String>,
   pub exclude_specifier_types: Vec<String>,
+  /// A named selector from the top-level `selectors` property, referenced
+  /// with `selector: "<name>"`. Must match in addition to the other filters.
+  pub named: Option<Arc<SelectorExpr>>,
   has_dependency_type_filters: bool,
   has_specifier_type_filters: bool,
   has_dependency_filters: bool,
@@ -102,9 +108,16 @@ impl GroupSelector {
       exclude_packages,
       include_specifier_types,
       exclude_specifier_types,
+      named: None,
     }
   }
 
+  /// Require a named selector to also match
+  pub fn with_named(mut self, named: Option<Arc<SelectorExpr>>) -> GroupSelector {
+    self.named = named;
+    self
+  }
+
   /// Validate that all dependency type filters reference known dependency types.
   pub fn validate_dependency_types(&self, all_dependency_types: &[DependencyType]) -> Result<(), UnsupportedConfigError> {
     for expected in self.include_dependency_types.iter().chain(self.exclude_dependency_types.iter()) {
@@ -112,6 +125,9 @@ impl GroupSelector {
         return Err(UnsupportedConfigError::InvalidDependencyType { name: expected.clone() });
       }
     }
+    if let Some(named) = &self.named {
+      named.validate_dependency_types(all_dependency_types)?;
+    }
     Ok(())
   }
 
@@ -130,6 +146,9 @@ impl GroupSelector {
     if self.has_package_filters && !self.matches_packages(package_name) {
       return false;
     }
+    if let Some(named) = &self.named {
+      return named.can_add(descriptor, package_name);
+    }
     true
   }
 
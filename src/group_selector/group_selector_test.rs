@@ -27,7 +27,9 @@ fn descriptor(name: &str, is_local_dependency: bool) -> InstanceDescriptor {
     internal_name: name.to_string(),
     is_local_dependency,
     name: name.to_string(),
+    override_path: None,
     source_idx: SourceIdx(0),
+    span: None,
     specifier: Specifier::new("1.0.0"),
   }
 }
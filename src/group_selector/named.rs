@@ -0,0 +1,221 @@
+use {
+  crate::{dependency::DependencyType, errors::UnsupportedConfigError, group_selector::GroupSelector, instance::InstanceDescriptor},
+  serde::Deserialize,
+  serde_json::Value,
+  std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+  },
+};
+
+#[cfg(test)]
+#[path = "named_test.rs"]
+mod named_test;
+
+/// A selector defined under the top-level `selectors` property of the rcfile,
+/// or written inline within `allOf`, `anyOf`, or `not`.
+///
+/// Every property which is set must match. An empty selector matches every
+/// instance.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RawSelector {
+  #[serde(default)]
+  pub dependencies: Vec<String>,
+  #[serde(default)]
+  pub dependency_types: Vec<String>,
+  #[serde(default)]
+  pub packages: Vec<String>,
+  #[serde(default)]
+  pub specifier_types: Vec<String>,
+  /// Every one of these selectors must match
+  #[serde(default)]
+  pub all_of: Vec<SelectorRef>,
+  /// At least one of these selectors must match
+  #[serde(default)]
+  pub any_of: Vec<SelectorRef>,
+  /// This selector must not match
+  pub not: Option<Box<SelectorRef>>,
+  #[serde(flatten)]
+  pub unknown_fields: HashMap<String, Value>,
+}
+
+/// Either the name of a selector defined in `selectors`, or a selector
+/// written inline.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum SelectorRef {
+  Name(String),
+  Inline(RawSelector),
+}
+
+impl RawSelector {
+  fn has_criteria(&self) -> bool {
+    !self.dependencies.is_empty() || !self.dependency_types.is_empty() || !self.packages.is_empty() || !self.specifier_types.is_empty()
+  }
+
+  /// Paths of every unrecognised property, including those of inline
+  /// selectors nested within this one.
+  pub fn unknown_paths(&self, path: &str) -> Vec<String> {
+    let own = self
+      .unknown_fields
+      .keys()
+      .filter(|key| !key.starts_with("//"))
+      .map(|key| format!("{path}.{key}"));
+    let all_of = inline_unknown_paths(&self.all_of, &format!("{path}.allOf"));
+    let any_of = inline_unknown_paths(&self.any_of, &format!("{path}.anyOf"));
+    let not = match self.not.as_deref() {
+      Some(SelectorRef::Inline(raw)) => raw.unknown_paths(&format!("{path}.not")),
+      _ => vec![],
+    };
+    own.chain(all_of).chain(any_of).chain(not).collect()
+  }
+}
+
+fn inline_unknown_paths(refs: &[SelectorRef], path: &str) -> Vec<String> {
+  refs
+    .iter()
+    .enumerate()
+    .flat_map(|(index, selector_ref)| match selector_ref {
+      SelectorRef::Inline(raw) => raw.unknown_paths(&format!("{path}[{index}]")),
+      SelectorRef::Name(_) => vec![],
+    })
+    .collect()
+}
+
+/// A compiled selector. Named selectors are compiled once and shared by
+/// every group which references them.
+#[derive(Debug)]
+pub enum SelectorExpr {
+  /// Matches the same way as the selector properties of a group
+  Match(Box<GroupSelector>),
+  AllOf(Vec<Arc<SelectorExpr>>),
+  AnyOf(Vec<Arc<SelectorExpr>>),
+  Not(Arc<SelectorExpr>),
+}
+
+impl SelectorExpr {
+  pub fn can_add(&self, descriptor: &InstanceDescriptor, package_name: &str) -> bool {
+    match self {
+      SelectorExpr::Match(selector) => selector.can_add(descriptor, package_name),
+      SelectorExpr::AllOf(exprs) => exprs.iter().all(|expr| expr.can_add(descriptor, package_name)),
+      SelectorExpr::AnyOf(exprs) => exprs.iter().any(|expr| expr.can_add(descriptor, package_name)),
+      SelectorExpr::Not(expr) => !expr.can_add(descriptor, package_name),
+    }
+  }
+
+  /// Validate that all dependency type filters reference known dependency types.
+  pub fn validate_dependency_types(&self, all_dependency_types: &[DependencyType]) -> Result<(), UnsupportedConfigError> {
+    match self {
+      SelectorExpr::Match(selector) => selector.validate_dependency_types(all_dependency_types),
+      SelectorExpr::AllOf(exprs) | SelectorExpr::AnyOf(exprs) => exprs
+        .iter()
+        .try_for_each(|expr| expr.validate_dependency_types(all_dependency_types)),
+      SelectorExpr::Not(expr) => expr.validate_dependency_types(all_dependency_types),
+    }
+  }
+}
+
+/// Compile every selector under the top-level `selectors` property, following
+/// references between them. Undefined names and circular references are
+/// config errors.
+pub fn compile_selectors(raw: &BTreeMap<String, RawSelector>) -> Result<BTreeMap<String, Arc<SelectorExpr>>, UnsupportedConfigError> {
+  let mut compiler = Compiler {
+    raw,
+    compiled: BTreeMap::new(),
+    stack: vec![],
+  };
+  for name in raw.keys() {
+    compiler.compile_named(name, &format!("selectors.{name}"))?;
+  }
+  Ok(compiler.compiled)
+}
+
+/// Look up the selector a group refers to with `selector: "<name>"`
+pub fn resolve_selector(
+  selectors: &BTreeMap<String, Arc<SelectorExpr>>,
+  name: &Option<String>,
+  path: &str,
+) -> Result<Option<Arc<SelectorExpr>>, UnsupportedConfigError> {
+  name
+    .as_ref()
+    .map(|name| {
+      selectors.get(name).cloned().ok_or_else(|| UnsupportedConfigError::UnknownSelector {
+        name: name.clone(),
+        path: format!("{path}.selector"),
+      })
+    })
+    .transpose()
+}
+
+struct Compiler<'a> {
+  raw: &'a BTreeMap<String, RawSelector>,
+  compiled: BTreeMap<String, Arc<SelectorExpr>>,
+  /// Names currently being compiled, used to detect circular references
+  stack: Vec<String>,
+}
+
+impl Compiler<'_> {
+  fn compile_named(&mut self, name: &str, path: &str) -> Result<Arc<SelectorExpr>, UnsupportedConfigError> {
+    if let Some(expr) = self.compiled.get(name) {
+      return Ok(Arc::clone(expr));
+    }
+    if let Some(start) = self.stack.iter().position(|visiting| visiting == name) {
+      let mut chain = self.stack[start..].to_vec();
+      chain.push(name.to_string());
+      return Err(UnsupportedConfigError::CircularSelector {
+        chain: chain.join(" → ")
+      });
+    }
+    let raw = self.raw.get(name).ok_or_else(|| UnsupportedConfigError::UnknownSelector {
+      name: name.to_string(),
+      path: path.to_string(),
+    })?;
+    self.stack.push(name.to_string());
+    let expr = self.compile(raw, name, &format!("selectors.{name}"))?;
+    self.stack.pop();
+    self.compiled.insert(name.to_string(), Arc::clone(&expr));
+    Ok(expr)
+  }
+
+  fn compile_ref(&mut self, selector_ref: &SelectorRef, label: &str, path: &str) -> Result<Arc<SelectorExpr>, UnsupportedConfigError> {
+    match selector_ref {
+      SelectorRef::Name(name) => self.compile_named(name, path),
+      SelectorRef::Inline(raw) => self.compile(raw, label, path),
+    }
+  }
+
+  fn compile(&mut self, raw: &RawSelector, label: &str, path: &str) -> Result<Arc<SelectorExpr>, UnsupportedConfigError> {
+    let mut parts: Vec<Arc<SelectorExpr>> = vec![];
+    if raw.has_criteria() {
+      parts.push(Arc::new(SelectorExpr::Match(Box::new(GroupSelector::new(
+        raw.dependencies.clone(),
+        raw.dependency_types.clone(),
+        label.to_string(),
+        raw.packages.clone(),
+        raw.specifier_types.clone(),
+      )))));
+    }
+    for (index, selector_ref) in raw.all_of.iter().enumerate() {
+      parts.push(self.compile_ref(selector_ref, label, &format!("{path}.allOf[{index}]"))?);
+    }
+    if !raw.any_of.is_empty() {
+      let any_of = raw
+        .any_of
+        .iter()
+        .enumerate()
+        .map(|(index, selector_ref)| self.compile_ref(selector_ref, label, &format!("{path}.anyOf[{index}]")))
+        .collect::<Result<Vec<_>, _>>()?;
+      parts.push(Arc::new(SelectorExpr::AnyOf(any_of)));
+    }
+    if let Some(not) = &raw.not {
+      let expr = self.compile_ref(not, label, &format!("{path}.not"))?;
+      parts.push(Arc::new(SelectorExpr::Not(expr)));
+    }
+    Ok(if parts.len() == 1 {
+      parts.remove(0)
+    } else {
+      Arc::new(SelectorExpr::AllOf(parts))
+    })
+  }
+}
@@ -0,0 +1,199 @@
+use {
+  crate::{
+    dependency::{DependencyType, Strategy},
+    errors::UnsupportedConfigError,
+    group_selector::named::{RawSelector, SelectorExpr, compile_selectors, resolve_selector},
+    instance::InstanceDescriptor,
+    rcfile::{RawRcfile, Rcfile},
+    source::SourceKind,
+    sources::SourceIdx,
+    specifier::Specifier,
+    test::builder::TestBuilder,
+  },
+  serde_json::{Value, json},
+  std::{collections::BTreeMap, rc::Rc, sync::Arc},
+};
+
+fn descriptor(name: &str) -> InstanceDescriptor {
+  InstanceDescriptor {
+    dependency_type: Rc::new(DependencyType {
+      name: "prod".to_string(),
+      name_path: None,
+      path: "/dependencies".to_string(),
+      strategy: Strategy::VersionsByName,
+      source: SourceKind::PackageJson,
+      is_catalog_definition: false,
+    }),
+    internal_name: name.to_string(),
+    is_local_dependency: false,
+    name: name.to_string(),
+    override_path: None,
+    source_idx: SourceIdx(0),
+    span: None,
+    specifier: Specifier::new("1.0.0"),
+  }
+}
+
+fn compile(selectors: Value) -> Result<BTreeMap<String, Arc<SelectorExpr>>, UnsupportedConfigError> {
+  let raw: BTreeMap<String, RawSelector> = serde_json::from_value(selectors).unwrap();
+  compile_selectors(&raw)
+}
+
+fn expected_version(ctx: &crate::context::Context, id: &str) -> Option<String> {
+  ctx
+    .instances
+    .iter()
+    .find(|instance| instance.id == id)
+    .unwrap_or_else(|| panic!("instance not found: {id}"))
+    .expected_specifier
+    .borrow()
+    .as_ref()
+    .map(|specifier| specifier.get_raw().to_string())
+}
+
+#[test]
+fn properties_of_a_selector_must_all_match() {
+  let selectors = compile(json!({
+    "react-in-apps": { "dependencies": ["react"], "packages": ["app-*"] }
+  }))
+  .unwrap();
+  let selector = &selectors["react-in-apps"];
+  assert!(selector.can_add(&descriptor("react"), "app-web"));
+  assert!(!selector.can_add(&descriptor("react"), "lib-ui"));
+  assert!(!selector.can_add(&descriptor("vue"), "app-web"));
+}
+
+#[test]
+fn all_of_any_of_and_not_combine_named_and_inline_selectors() {
+  let selectors = compile(json!({
+    "apps": { "packages": ["app-*"] },
+    "frameworks": { "anyOf": [{ "dependencies": ["react"] }, { "dependencies": ["vue"] }] },
+    "frameworks-outside-apps": { "allOf": ["frameworks", { "not": "apps" }] }
+  }))
+  .unwrap();
+  let selector = &selectors["frameworks-outside-apps"];
+  assert!(selector.can_add(&descriptor("react"), "lib-ui"));
+  assert!(selector.can_add(&descriptor("vue"), "lib-ui"));
+  assert!(!selector.can_add(&descriptor("react"), "app-web"));
+  assert!(!selector.can_add(&descriptor("lodash"), "lib-ui"));
+}
+
+#[test]
+fn named_selectors_are_compiled_once_and_shared() {
+  let selectors = compile(json!({
+    "apps": { "packages": ["app-*"] },
+    "not-apps": { "not": "apps" }
+  }))
+  .unwrap();
+  let SelectorExpr::Not(inner) = selectors["not-apps"].as_ref() else {
+    panic!("expected a Not selector, got {:?}", selectors["not-apps"]);
+  };
+  assert!(Arc::ptr_eq(inner, &selectors["apps"]));
+}
+
+#[test]
+fn reference_to_undefined_selector_is_an_error() {
+  let err = compile(json!({ "apps": { "allOf": ["missing"] } })).unwrap_err();
+  assert!(matches!(
+    err,
+    UnsupportedConfigError::UnknownSelector { name, path } if name == "missing" && path == "selectors.apps.allOf[0]"
+  ));
+}
+
+#[test]
+fn circular_references_are_an_error() {
+  let err = compile(json!({
+    "a": { "anyOf": ["b"] },
+    "b": { "not": "a" }
+  }))
+  .unwrap_err();
+  assert!(matches!(err, UnsupportedConfigError::CircularSelector { chain } if chain == "a → b → a"));
+}
+
+#[test]
+fn group_referencing_undefined_selector_is_an_error() {
+  let raw: RawRcfile = serde_json::from_value(json!({
+    "selectors": { "apps": { "packages": ["app-*"] } },
+    "versionGroups": [{ "selector": "ap", "pinVersion": "1.0.0" }]
+  }))
+  .unwrap();
+  let err = Rcfile::try_from(raw).unwrap_err();
+  assert!(matches!(
+    err,
+    UnsupportedConfigError::UnknownSelector { name, path } if name == "ap" && path == "versionGroups[0].selector"
+  ));
+}
+
+#[test]
+fn groups_without_a_selector_resolve_to_none() {
+  let selectors = compile(json!({})).unwrap();
+  assert!(resolve_selector(&selectors, &None, "semverGroups[0]").unwrap().is_none());
+}
+
+#[test]
+fn unknown_properties_of_selectors_are_reported() {
+  let raw: RawRcfile = serde_json::from_value(json!({
+    "selectors": { "apps": { "package": ["app-*"], "anyOf": [{ "dependency": ["react"] }] } }
+  }))
+  .unwrap();
+  let paths: Vec<String> = raw
+    .validate_unknown_fields()
+    .unwrap_err()
+    .into_iter()
+    .map(|err| match err {
+      UnsupportedConfigError::UnrecognisedProperty { path } => path,
+      other => panic!("expected an unrecognised property, got {other:?}"),
+    })
+    .collect();
+  assert_eq!(paths, vec!["selectors.apps.package", "selectors.apps.anyOf[0].dependency"]);
+}
+
+#[tokio::test]
+async fn groups_reference_the_same_selector() {
+  let ctx = TestBuilder::new()
+    .with_packages(vec![
+      json!({ "name": "app-web", "dependencies": { "react": "^16.0.0" } }),
+      json!({ "name": "lib-ui", "dependencies": { "react": "^16.0.0" } }),
+    ])
+    .with_config(json!({
+      "selectors": {
+        "react-in-apps": { "allOf": [{ "dependencies": ["react"] }, { "packages": ["app-*"] }] }
+      }
+    }))
+    .with_version_group(json!({ "selector": "react-in-apps", "pinVersion": "18.0.0" }))
+    .with_semver_group(json!({ "selector": "react-in-apps", "range": "~" }))
+    .run()
+    .await;
+
+  assert_eq!(
+    expected_version(&ctx, "react in /dependencies of app-web").as_deref(),
+    Some("18.0.0")
+  );
+  assert_eq!(
+    expected_version(&ctx, "react in /dependencies of lib-ui").as_deref(),
+    Some("^16.0.0")
+  );
+}
+
+#[tokio::test]
+async fn selector_is_combined_with_the_group_own_properties() {
+  let ctx = TestBuilder::new()
+    .with_packages(vec![
+      json!({ "name": "app-web", "dependencies": { "react": "16.0.0", "vue": "2.0.0" } }),
+      json!({ "name": "lib-ui", "dependencies": { "react": "16.0.0" } }),
+    ])
+    .with_config(json!({ "selectors": { "apps": { "packages": ["app-*"] } } }))
+    .with_version_group(json!({ "selector": "apps", "dependencies": ["react"], "pinVersion": "18.0.0" }))
+    .run()
+    .await;
+
+  assert_eq!(
+    expected_version(&ctx, "react in /dependencies of app-web").as_deref(),
+    Some("18.0.0")
+  );
+  assert_eq!(expected_version(&ctx, "vue in /dependencies of app-web").as_deref(), Some("2.0.0"));
+  assert_eq!(
+    expected_version(&ctx, "react in /dependencies of lib-ui").as_deref(),
+    Some("16.0.0")
+  );
+}
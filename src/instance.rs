@@ -17,12 +17,14 @@ mod source_idx_test;
 
 use {
   crate::{
-    dependency::{DependencyType, Strategy, UpdateUrl},
+    dependency::{DependencyType, OverridePath, Strategy, UpdateUrl},
     disk::Disk,
     rcfile::update_group::UpdatePolicy,
+    registry::client::NODE_RELEASE_INDEX_URL,
     semver_range::SemverRange,
     source::Source,
     sources::SourceIdx,
+    span::{Span, child_pointer},
     specifier::Specifier,
   },
   log::debug,
@@ -33,6 +35,10 @@ use {
   },
 };
 
+/// Locations of the built-in `engines`, `packageManager` and `volta`
+/// dependency types, which hold the versions of tools rather than packages.
+const TOOLCHAIN_PATHS: [&str; 3] = ["/engines", "/packageManager", "/volta"];
+
 /// Index into the Context.instances arena.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct InstanceIdx(pub usize);
@@ -59,15 +65,46 @@ pub struct InstanceDescriptor {
   /// IS a local package's own version declaration).
   pub is_local_dependency: bool,
   pub name: String,
+  /// Where this dependency is overridden within nested npm `overrides` or a
+  /// Yarn `resolutions` path. `None` for every other instance, including
+  /// plain `"name": "version"` overrides.
+  pub override_path: Option<OverridePath>,
   /// Index into `Sources::all` for the file this instance was read from. For
-  /// catalog defs this points at the holding file (pnpm yaml or the Bun root
-  /// pkg.json); for regular declarations it points at the consuming
+  /// catalog defs this points at the holding file (pnpm yaml, .yarnrc.yml or
+  /// the Bun root pkg.json); for regular declarations it points at the consuming
   /// package.json.
   pub source_idx: SourceIdx,
+  /// Where the specifier is written in its source file, when known.
+  pub span: Option<Span>,
   /// The original specifier, never mutated.
   pub specifier: Rc<Specifier>,
 }
 
+impl InstanceDescriptor {
+  /// JSON pointer to the object which holds this dependency, and the
+  /// property name of its version within that object, for dependency types
+  /// which are objects keyed by name.
+  pub fn get_parent_pointer_and_key(&self) -> (String, String) {
+    let path = &self.dependency_type.path;
+    match &self.override_path {
+      Some(override_path) => (override_path.parent_pointer(path), override_path.key.clone()),
+      None => (path.clone(), self.name.clone()),
+    }
+  }
+
+  /// JSON pointer to the property holding this instance's specifier, such
+  /// as `/dependencies/react` or `/packageManager`.
+  pub fn get_pointer(&self) -> String {
+    match (&self.override_path, &self.dependency_type.strategy) {
+      (Some(_), _) | (None, Strategy::VersionsByName | Strategy::ImportMap) => {
+        let (parent_pointer, key) = self.get_parent_pointer_and_key();
+        child_pointer(&parent_pointer, &key)
+      }
+      _ => self.dependency_type.path.clone(),
+    }
+  }
+}
+
 /// A single occurrence of a dependency in the project.
 #[derive(Debug)]
 pub struct Instance {
@@ -85,6 +122,10 @@ pub struct Instance {
   /// when picking eligible registry updates (skip entirely or clamp the
   /// effective `UpdateTarget`). `None` when no group matched.
   pub preferred_update_policy: Option<UpdatePolicy>,
+  /// The version installed according to the lockfile. Only looked up for
+  /// package.json dependencies when `checkLockfile` is enabled, or by the
+  /// `list` and `json` commands.
+  pub resolved_version: Option<String>,
   /// Resolved by `VersionGroup::resolve_action` from the instance's `state`,
   /// the matching group's `severity` map and the rcfile's `strict` flag. Set
   /// as a side effect of `resolve_action`; remains `None` until the resolver
@@ -103,7 +144,11 @@ impl Instance {
     preferred_update_policy: Option<UpdatePolicy>,
   ) -> Instance {
     let dependency_type_name = &descriptor.dependency_type.path;
-    let id = format!("{} in {} of {}", &descriptor.name, dependency_type_name, package_name);
+    let location = match &descriptor.override_path {
+      Some(override_path) => override_path.location(dependency_type_name),
+      None => dependency_type_name.clone(),
+    };
+    let id = format!("{} in {} of {}", &descriptor.name, location, package_name);
     let is_local_instance = dependency_type_name == "/version";
     Instance {
       descriptor,
@@ -112,56 +157,66 @@ impl Instance {
       is_local_instance,
       preferred_semver_range,
       preferred_update_policy,
+      resolved_version: None,
       severity: RefCell::new(None),
       state: RefCell::new(InstanceState::Unknown),
     }
   }
 
-  /// Check if a link: specifier resolves to the given local package's directory.
+  /// Check if a file: or link: specifier resolves to the given local
+  /// package's directory, relative to the package.json which declares it.
   ///
   /// Examples:
   /// - "link:../package-a" from /packages/package-b/package.json -> /packages/package-a
-  /// - "link:../../elsewhere/package-a" from /packages/package-b/package.json -> /elsewhere/package-a
+  /// - "file:../../elsewhere/package-a" from /packages/package-b/package.json -> /elsewhere/package-a
   ///
-  /// pnpm catalog instances do not live in a package.json, so a `Link`
+  /// pnpm and Yarn catalog instances do not live in a package.json, so a path
   /// specifier sourced from one cannot resolve to a local package — this
-  /// returns `false` in that case.
-  pub fn link_resolves_to_local_package(&self, local_instance: &Instance, sources: &[Source], disk: &Disk) -> bool {
-    if let Specifier::Link(link) = &*self.descriptor.specifier {
-      let self_idx = self.source_idx();
-      let local_idx = local_instance.source_idx();
-      let Source::Package {
-        file_idx: consuming_idx, ..
-      } = &sources[self_idx.0]
-      else {
-        return false;
-      };
-      let Source::Package { file_idx: local_idx, .. } = &sources[local_idx.0] else {
-        return false;
-      };
-      let consuming_package_path = &disk.package_json_files[*consuming_idx].filepath;
-      let consuming_package_dir = consuming_package_path.parent().unwrap_or_else(|| Path::new(""));
-      let link_path = link.raw.strip_prefix("link:").unwrap_or(&link.raw);
-      let resolved_link_path = consuming_package_dir.join(link_path);
-      let local_package_path = &disk.package_json_files[*local_idx].filepath;
-      let local_package_dir = local_package_path.parent().unwrap_or_else(|| Path::new(""));
-
-      if let (Ok(resolved_canonical), Ok(local_canonical)) = (resolved_link_path.canonicalize(), local_package_dir.canonicalize()) {
-        resolved_canonical == local_canonical
-      } else {
-        let normalized_resolved = Self::normalize_path(&resolved_link_path);
-        let normalized_local = Self::normalize_path(local_package_dir);
-        normalized_resolved == normalized_local
-      }
+  /// returns `false` in that case, as it does for a `file:` tarball.
+  pub fn path_resolves_to_local_package(&self, local_instance: &Instance, sources: &[Source], disk: &Disk) -> bool {
+    let path = match &*self.descriptor.specifier {
+      Specifier::File(file) => file.raw.strip_prefix("file:").unwrap_or(&file.raw),
+      Specifier::Link(link) => link.raw.strip_prefix("link:").unwrap_or(&link.raw),
+      _ => return false,
+    };
+    let Source::Package {
+      file_idx: consuming_idx, ..
+    } = &sources[self.source_idx().0]
+    else {
+      return false;
+    };
+    let Source::Package { file_idx: local_idx, .. } = &sources[local_instance.source_idx().0] else {
+      return false;
+    };
+    let consuming_package_path = &disk.package_json_files[*consuming_idx].filepath;
+    let consuming_package_dir = consuming_package_path.parent().unwrap_or_else(|| Path::new(""));
+    let resolved_path = consuming_package_dir.join(path);
+    let local_package_path = &disk.package_json_files[*local_idx].filepath;
+    let local_package_dir = local_package_path.parent().unwrap_or_else(|| Path::new(""));
+
+    if let (Ok(resolved_canonical), Ok(local_canonical)) = (resolved_path.canonicalize(), local_package_dir.canonicalize()) {
+      resolved_canonical == local_canonical
     } else {
-      false
+      let normalized_resolved = Self::normalize_path(&resolved_path);
+      let normalized_local = Self::normalize_path(local_package_dir);
+      normalized_resolved == normalized_local
+    }
+  }
+
+  /// The `workspace:` specifier a `file:` or `link:` path to a local package
+  /// is replaced with. Uses the shorthand of the instance's semver group
+  /// when it prefers `^` or `~`, otherwise `workspace:*`.
+  pub fn get_workspace_protocol_specifier(&self) -> Rc<Specifier> {
+    match &self.preferred_semver_range {
+      Some(range @ (SemverRange::Minor | SemverRange::Patch)) => Specifier::new(&format!("workspace:{}", range.unwrap())),
+      _ => Specifier::new("workspace:*"),
     }
   }
 
-  /// Whether this instance was sourced from a catalog (pnpm or Bun) rather
-  /// than a regular package.json dependency. Reads the dep type's
+  /// Whether this instance was sourced from a catalog (pnpm, Yarn or Bun)
+  /// rather than a regular package.json dependency. Reads the dep type's
   /// `is_catalog_definition` flag — set by `make_catalog_dep_types` for
-  /// auto-generated `pnpmCatalog*` / `bunCatalog*` dep types.
+  /// auto-generated `pnpmCatalog*` / `yarnCatalog*` / `bunCatalog*` dep types.
   pub fn is_catalog_instance(&self) -> bool {
     self.descriptor.dependency_type.is_catalog_definition
   }
@@ -171,6 +226,12 @@ impl Instance {
     self.descriptor.source_idx
   }
 
+  /// Where this instance's specifier is written in its source file, as it
+  /// was when the file was read.
+  pub fn span(&self) -> Option<Span> {
+    self.descriptor.span
+  }
+
   /// Catalog name (`"default"` or named) for catalog instances; `None` for
   /// regular non-catalog instances. Derives the name cheaply from the dep
   /// type's `name` (no allocation).
@@ -344,6 +405,9 @@ impl Instance {
   }
 
   pub fn get_update_url(&self) -> Option<UpdateUrl> {
+    if self.is_toolchain_instance() {
+      return self.get_toolchain_update_url();
+    }
     if !self.is_local_instance {
       let internal_name = &self.descriptor.internal_name;
       let actual_name = &self.descriptor.name;
@@ -352,7 +416,19 @@ impl Instance {
         Specifier::Alias(alias) => {
           let aliased_name = &alias.name;
           if !aliased_name.is_empty() {
-            if aliased_name.starts_with("@jsr/") {
+            if alias.is_jsr() {
+              // JSR serves every package to npm clients as `@jsr/{scope}__{name}`
+              match aliased_name.strip_prefix('@').and_then(|name| name.split_once('/')) {
+                Some((scope, name)) => Some(UpdateUrl {
+                  internal_name: internal_name.clone(),
+                  url: format!("https://npm.jsr.io/@jsr/{scope}__{name}"),
+                }),
+                None => {
+                  debug!("'{aliased_name}' in '{raw}' is not a scoped JSR package, skipping update");
+                  None
+                }
+              }
+            } else if aliased_name.starts_with("@jsr/") {
               Some(UpdateUrl {
                 internal_name: internal_name.clone(),
                 url: format!("https://npm.jsr.io/{aliased_name}"),
@@ -392,6 +468,50 @@ impl Instance {
     }
   }
 
+  /// Is this the version of a tool such as Node.js or pnpm, defined in
+  /// `engines`, `packageManager` or `volta`, rather than of a dependency?
+  pub fn is_toolchain_instance(&self) -> bool {
+    TOOLCHAIN_PATHS.contains(&self.descriptor.dependency_type.path.as_str())
+  }
+
+  /// Node.js versions are read from the Node.js release index, and Yarn 2+
+  /// is published to npm as `@yarnpkg/cli-dist`. Other tools in `engines`,
+  /// such as `vscode`, are not packages on npm and are never updated.
+  fn get_toolchain_update_url(&self) -> Option<UpdateUrl> {
+    let internal_name = &self.descriptor.internal_name;
+    let name = self.descriptor.name.as_str();
+    if !matches!(
+      &*self.descriptor.specifier,
+      Specifier::Exact(_) | Specifier::Range(_) | Specifier::Major(_) | Specifier::Minor(_) | Specifier::Latest(_)
+    ) {
+      return None;
+    }
+    let url = match name {
+      "node" => NODE_RELEASE_INDEX_URL.to_string(),
+      "yarn"
+        if self
+          .descriptor
+          .specifier
+          .get_node_version()
+          .is_some_and(|version| version.major >= 2) =>
+      {
+        "https://registry.npmjs.org/@yarnpkg/cli-dist".to_string()
+      }
+      "bun" | "npm" | "pnpm" | "yarn" => format!("https://registry.npmjs.org/{name}"),
+      _ => {
+        debug!(
+          "'{name}' in '{}' is not a known tool, skipping update",
+          self.descriptor.dependency_type.name
+        );
+        return None;
+      }
+    };
+    Some(UpdateUrl {
+      internal_name: internal_name.clone(),
+      url,
+    })
+  }
+
   /// Does this instance's specifier match the specifier of every one of the
   /// given instances?
   pub fn already_satisfies_all(&self, indices: &[InstanceIdx], arena: &[Instance]) -> bool {
@@ -462,14 +582,13 @@ impl Instance {
   }
 
   /// Delete this instance from its underlying `Source`. No-op for
-  /// `PnpmYaml`-sourced instances (pnpm catalog removal lands with the
-  /// Banned-catalog work).
+  /// `PnpmYaml` and `YarnRc`-sourced instances (catalog removal lands with
+  /// the Banned-catalog work).
   pub fn remove(&self, disk: &mut Disk, source: &Source) {
-    let Source::Package { file_idx, .. } = source else {
-      debug!("Cannot remove pnpm catalog instance from a package.json");
+    let Some(file) = disk.json_source_mut(source) else {
+      debug!("Cannot remove catalog instance from a package.json");
       return;
     };
-    let file = &mut disk.package_json_files[*file_idx];
     match self.descriptor.dependency_type.strategy {
       Strategy::NameAndVersionProps | Strategy::NamedVersionString | Strategy::UnnamedVersionString => {
         let path_to_prop = &self.descriptor.dependency_type.path;
@@ -481,9 +600,9 @@ impl Instance {
           debug!("Cannot remove root property");
         }
       }
-      Strategy::VersionsByName => {
-        let path_to_obj = &self.descriptor.dependency_type.path;
-        crate::disk::remove_prop(file, path_to_obj, &self.descriptor.name);
+      Strategy::VersionsByName | Strategy::ImportMap | Strategy::OverrideTree | Strategy::ResolutionPaths => {
+        let (parent_pointer, key) = self.descriptor.get_parent_pointer_and_key();
+        crate::disk::remove_prop(file, &parent_pointer, &key);
       }
       Strategy::InvalidConfig => {
         unreachable!("unrecognised strategy");
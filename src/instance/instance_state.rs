@@ -1,5 +1,5 @@
 use {
-  crate::specifier::Specifier,
+  crate::{specifier::Specifier, variant_names::variant_names},
   std::{cmp::Ordering, rc::Rc},
 };
 
@@ -26,6 +26,18 @@ pub enum InstanceState {
 }
 
 impl InstanceState {
+  /// The name of every status which is reported as an issue, in the order
+  /// they are declared
+  pub fn issue_names() -> Vec<&'static str> {
+    [
+      FixableInstance::NAMES,
+      UnfixableInstance::NAMES,
+      SemverGroupAndVersionConflict::NAMES,
+      SuspectInstance::NAMES,
+    ]
+    .concat()
+  }
+
   #[cfg(test)]
   pub fn valid(state: ValidInstance) -> Self {
     InstanceState::Valid(state)
@@ -51,6 +63,16 @@ impl InstanceState {
     InstanceState::Invalid(InvalidInstance::Unfixable(state))
   }
 
+  /// Every value of `get_status_type` after an instance has been visited,
+  /// from best to worst
+  pub const STATUS_TYPES: &'static [&'static str] = &["Valid", "Suspect", "Fixable", "Unfixable", "Conflict"];
+
+  /// Position of a status type in `STATUS_TYPES`, so the worst of several
+  /// can be found
+  pub fn get_status_type_rank(status_type: &str) -> usize {
+    Self::STATUS_TYPES.iter().position(|other| *other == status_type).unwrap_or(0)
+  }
+
   pub fn get_status_type(&self) -> &'static str {
     match self {
       InstanceState::Unknown => "Unknown",
@@ -203,180 +225,207 @@ pub enum InvalidInstance {
   Conflict(SemverGroupAndVersionConflict),
 }
 
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub enum FixableInstance {
-  /// - ✘ Instance is in a banned version group
-  IsBanned,
-  /// - ✓ Instance is in a highest/lowest semver group
-  /// - ✓ One or more other instances use the catalog: protocol
-  /// - ✘ Instance does not use the catalog: protocol
-  /// - ! catalog: protocol wins
-  DiffersToCatalog,
-  /// - ✓ Instance is in a catalog version group
-  /// - ✓ Instance's dependency is defined in exactly one catalog
-  /// - ✘ Instance does not use the catalog: protocol
-  /// - ! Fix: replace specifier with `catalog:` or `catalog:{name}`
-  /// - String carries the target catalog name (`"default"` for the unnamed catalog).
-  NotUsingCatalog(String),
-  /// - ✓ Instance is in a catalog version group
-  /// - ✓ One catalog (or zero — implicit "default") exists in the project
-  /// - ✘ Instance's dependency is not defined in any catalog
-  /// - ✘ Instance does not use the catalog: protocol
-  /// - ! Fix: add dependency to the catalog and replace specifier with catalog:
-  /// - `catalog_name` carries the target catalog (`"default"` for the unnamed catalog)
-  /// - `winning_specifier` carries the value to enshrine in the catalog (resolved at visit time: unique value when all-identical, highest
-  ///   semver otherwise)
-  MissingFromCatalog {
-    catalog_name: String,
-    winning_specifier: Rc<Specifier>,
-  },
-  /// - ✘ Instance mismatches the version of its locally-developed package
-  DiffersToLocal,
-  /// - ✘ Instance mismatches highest/lowest semver in its group
-  DiffersToHighestOrLowestSemver,
-  /// - ✘ Instance is older than highest semver published to the registry
-  DiffersToNpmRegistry,
-  /// - ✘ Instance mismatches the matching snapTo instance
-  DiffersToSnapTarget,
-  /// - ✘ Instance mismatches its pinned version group
-  DiffersToPin,
-  /// - ✓ Instance has same semver number as highest/lowest semver in its group
-  /// - ✘ Instance mismatches its semver group
-  /// - ✓ Range preferred by semver group satisfies the highest/lowest semver
-  /// - ✓ Fixing the semver range satisfy both groups
-  SemverRangeMismatch,
-  /// - ✓ Instance has same semver number as its pinned version group
-  /// - ✓ Instance matches its semver group
-  /// - ! The semver group requires a range which is different to the pinned version
-  /// - ! Pinned version wins
-  PinOverridesSemverRange,
-  /// - ✓ Instance has same semver number as its pinned version group
-  /// - ✘ Instance mismatches its semver group
-  /// - ! The semver group requires a range which is different to the pinned version
-  /// - ! Pinned version wins
-  PinOverridesSemverRangeMismatch,
-  /// - ✓ Instance has same major.minor as all other instances in its group
-  /// - ✓ Instance matches its semver group
-  /// - ! The semver group requires a range which would break same minor policy
-  /// - ! Same minor policy wins
-  SameMinorOverridesSemverRange,
-  /// - ✓ Instance has same major.minor as all other instances in its group
-  /// - ✘ Instance mismatches its semver group
-  /// - ! The semver group requires a range which would break same minor policy
-  /// - ! Same minor policy wins
-  SameMinorOverridesSemverRangeMismatch,
-  /// - ✓ Instance is in a sameMinor version group with preferVersion set
-  /// - ✓ All instances share the same MAJOR version
-  /// - ✘ Instance's MAJOR.MINOR is not the highest (or lowest) MAJOR.MINOR in the group
-  /// - ! Fix: update to the preferred MAJOR.MINOR target
-  /// - ! Range selection (in priority order):
-  ///     1. If instance has a semver group with a safe preferred range → use preferred range
-  ///     2. If instance has no semver group and on-disk range is safe → preserve on-disk range
-  ///     3. Otherwise → force ~ (sameMinor policy wins over unsafe ranges)
-  DiffersToHighestOrLowestSemverMinor,
+variant_names! {
+  #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+  pub enum FixableInstance {
+    /// - ✘ Instance is in a banned version group
+    IsBanned,
+    /// - ✓ Instance is in a highest/lowest semver group
+    /// - ✓ One or more other instances use the catalog: protocol
+    /// - ✘ Instance does not use the catalog: protocol
+    /// - ! catalog: protocol wins
+    DiffersToCatalog,
+    /// - ✓ Instance is in a catalog version group
+    /// - ✓ Instance's dependency is defined in exactly one catalog
+    /// - ✘ Instance does not use the catalog: protocol
+    /// - ! Fix: replace specifier with `catalog:` or `catalog:{name}`
+    /// - String carries the target catalog name (`"default"` for the unnamed catalog).
+    NotUsingCatalog(String),
+    /// - ✓ Instance is in a catalog version group
+    /// - ✓ One catalog (or zero — implicit "default") exists in the project
+    /// - ✘ Instance's dependency is not defined in any catalog
+    /// - ✘ Instance does not use the catalog: protocol
+    /// - ! Fix: add dependency to the catalog and replace specifier with catalog:
+    /// - `catalog_name` carries the target catalog (`"default"` for the unnamed catalog)
+    /// - `winning_specifier` carries the value to enshrine in the catalog (resolved at visit time: unique value when all-identical, highest
+    ///   semver otherwise)
+    MissingFromCatalog {
+      catalog_name: String,
+      winning_specifier: Rc<Specifier>,
+    },
+    /// - ✘ Instance mismatches the version of its locally-developed package
+    DiffersToLocal,
+    /// - ✓ Instance uses a `file:` or `link:` path to its locally-developed package
+    /// - ✘ `localPathPolicy` is `"workspace"` or `"version"`
+    /// - ! Fix: replace the path with the workspace protocol or the local version
+    UsesLocalPath,
+    /// - ✘ Instance mismatches highest/lowest semver in its group
+    DiffersToHighestOrLowestSemver,
+    /// - ✘ Instance is older than highest semver published to the registry
+    DiffersToNpmRegistry,
+    /// - ✘ Instance mismatches the matching snapTo instance
+    DiffersToSnapTarget,
+    /// - ✘ Instance mismatches its pinned version group
+    DiffersToPin,
+    /// - ✓ Instance has same semver number as highest/lowest semver in its group
+    /// - ✘ Instance mismatches its semver group
+    /// - ✓ Range preferred by semver group satisfies the highest/lowest semver
+    /// - ✓ Fixing the semver range satisfy both groups
+    SemverRangeMismatch,
+    /// - ✓ Instance has same semver number as its pinned version group
+    /// - ✓ Instance matches its semver group
+    /// - ! The semver group requires a range which is different to the pinned version
+    /// - ! Pinned version wins
+    PinOverridesSemverRange,
+    /// - ✓ Instance has same semver number as its pinned version group
+    /// - ✘ Instance mismatches its semver group
+    /// - ! The semver group requires a range which is different to the pinned version
+    /// - ! Pinned version wins
+    PinOverridesSemverRangeMismatch,
+    /// - ✓ Instance has same major.minor as all other instances in its group
+    /// - ✓ Instance matches its semver group
+    /// - ! The semver group requires a range which would break same minor policy
+    /// - ! Same minor policy wins
+    SameMinorOverridesSemverRange,
+    /// - ✓ Instance has same major.minor as all other instances in its group
+    /// - ✘ Instance mismatches its semver group
+    /// - ! The semver group requires a range which would break same minor policy
+    /// - ! Same minor policy wins
+    SameMinorOverridesSemverRangeMismatch,
+    /// - ✓ Instance is in a sameMinor version group with preferVersion set
+    /// - ✓ All instances share the same MAJOR version
+    /// - ✘ Instance's MAJOR.MINOR is not the highest (or lowest) MAJOR.MINOR in the group
+    /// - ! Fix: update to the preferred MAJOR.MINOR target
+    /// - ! Range selection (in priority order):
+    ///     1. If instance has a semver group with a safe preferred range → use preferred range
+    ///     2. If instance has no semver group and on-disk range is safe → preserve on-disk range
+    ///     3. Otherwise → force ~ (sameMinor policy wins over unsafe ranges)
+    DiffersToHighestOrLowestSemverMinor,
+  }
 }
 
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub enum UnfixableInstance {
-  /// - ✘ Instance depends on a local package whose package.json version is not exact semver
-  /// - ? We can't know what the version should be
-  DependsOnInvalidLocalPackage,
-  /// - ✘ Instance mismatches others in its group
-  /// - ✘ One or more Instances are not simple semver
-  /// - ? We can't know what's right or what isn't
-  NonSemverMismatch,
-  /// - ✘ Instance mismatches its same range group
-  /// - ✘ Instance's range doesn't satisfy all other ranges in its same range group
-  /// - ? Instance has no semver group
-  /// - ? We can't know what range the user wants and have to ask them
-  SameRangeMismatch,
-  /// - ✘ Instance mismatches its same minor group
-  /// - ? Instance has no semver group
-  /// - ? We can't know what range the user wants and have to ask them
-  SameMinorMismatch,
-  /// - ✘ Instance is in a sameMinor version group
-  /// - ✘ One or more other instances have a different MAJOR version
-  /// - ? Crossing a major version boundary is unsafe
-  /// - ? We cannot know which MAJOR the user wants and have to ask them
-  SameMinorHasMajorMismatch,
-  /// - ✓ Instance is in a catalog version group
-  /// - ✓ MissingFromCatalog applies to multiple instances of the same dep
-  /// - ✘ Their specifiers differ AND at least one is non-semver
-  /// - ? Syncpack cannot pick which specifier to enshrine in the catalog
-  /// - String carries the target catalog name (`"default"` for the unnamed catalog).
-  MissingFromCatalogAndNonSemverMismatch(String),
-  /// - ✓ Instance is in a catalog version group
-  /// - ✓ Two or more catalogs exist in the project
-  /// - ✓ Instance's dependency is defined in zero OR two-or-more of those catalogs
-  /// - ✘ Instance does not use the catalog: protocol
-  /// - ? Syncpack cannot determine which catalog the dependency belongs to
-  NotUsingCatalogAndCatalogUnknown,
-  /// - ✓ Instance is in a catalog version group
-  /// - ✓ Project has 0 catalogs
-  /// - ✘ `Disk.package_manager` is npm/yarn/Unknown — no recognized lock file
-  /// - ? Cannot infer whether to create pnpm-workspace.yaml or root package.json /catalog
-  CannotInferCatalogFile,
+variant_names! {
+  #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+  pub enum UnfixableInstance {
+    /// - ✘ Instance depends on a local package whose package.json version is not exact semver
+    /// - ? We can't know what the version should be
+    DependsOnInvalidLocalPackage,
+    /// - ✘ Instance mismatches others in its group
+    /// - ✘ One or more Instances are not simple semver
+    /// - ? We can't know what's right or what isn't
+    NonSemverMismatch,
+    /// - ✘ Instance mismatches its same range group
+    /// - ✘ Instance's range doesn't satisfy all other ranges in its same range group
+    /// - ? Instance has no semver group
+    /// - ? We can't know what range the user wants and have to ask them
+    SameRangeMismatch,
+    /// - ✘ Instance mismatches its same minor group
+    /// - ? Instance has no semver group
+    /// - ? We can't know what range the user wants and have to ask them
+    SameMinorMismatch,
+    /// - ✘ Instance is in a sameMinor version group
+    /// - ✘ One or more other instances have a different MAJOR version
+    /// - ? Crossing a major version boundary is unsafe
+    /// - ? We cannot know which MAJOR the user wants and have to ask them
+    SameMinorHasMajorMismatch,
+    /// - ✓ Instance is in a catalog version group
+    /// - ✓ MissingFromCatalog applies to multiple instances of the same dep
+    /// - ✘ Their specifiers differ AND at least one is non-semver
+    /// - ? Syncpack cannot pick which specifier to enshrine in the catalog
+    /// - String carries the target catalog name (`"default"` for the unnamed catalog).
+    MissingFromCatalogAndNonSemverMismatch(String),
+    /// - ✓ Instance is in a catalog version group
+    /// - ✓ Two or more catalogs exist in the project
+    /// - ✓ Instance's dependency is defined in zero OR two-or-more of those catalogs
+    /// - ✘ Instance does not use the catalog: protocol
+    /// - ? Syncpack cannot determine which catalog the dependency belongs to
+    NotUsingCatalogAndCatalogUnknown,
+    /// - ✓ Instance is in a catalog version group
+    /// - ✓ Project has 0 catalogs
+    /// - ✘ `Disk.package_manager` is npm/yarn/Unknown — no recognized lock file
+    /// - ? Cannot infer whether to create pnpm-workspace.yaml or root package.json /catalog
+    CannotInferCatalogFile,
+  }
 }
 
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub enum SemverGroupAndVersionConflict {
-  /// - ✓ Instance has same semver number as highest/lowest semver in its group
-  /// - ✓ Instance matches its semver group
-  /// - ✘ Range preferred by semver group will not satisfy the highest/lowest semver
-  /// - ? We can't know whether the incompatible range matters or not and have to ask
-  MatchConflictsWithHighestOrLowestSemver,
-  /// - ✓ Instance has same semver number as highest/lowest semver in its group
-  /// - ✘ Instance mismatches its semver group
-  /// - ✘ Range preferred by semver group will not satisfy the highest/lowest semver
-  /// - ? We can't know whether the incompatible range matters or not and have to ask
-  MismatchConflictsWithHighestOrLowestSemver,
-  /// - ✓ Instance has same semver number as the matching snapTo instance
-  /// - ✓ Instance matches its semver group
-  /// - ✘ Range preferred by semver group will not satisfy the matching snapTo instance
-  /// - ? We can't know whether the incompatible range matters or not and have to ask
-  MatchConflictsWithSnapTarget,
-  /// - ✓ Instance has same semver number as the matching snapTo instance
-  /// - ✘ Instance mismatches its semver group
-  /// - ✘ Range preferred by semver group will not satisfy the matching snapTo instance
-  /// - ? We can't know whether the incompatible range matters or not and have to ask
-  MismatchConflictsWithSnapTarget,
-  /// - ✓ Instance has same semver number as local instance in its group
-  /// - ✓ Instance matches its semver group
-  /// - ✘ Range preferred by semver group will not satisfy the local instance
-  /// - ? We can't know whether the incompatible range matters or not and have to ask
-  MatchConflictsWithLocal,
-  /// - ✓ Instance has same semver number as local instance
-  /// - ✘ Instance mismatches its semver group
-  /// - ✘ Range preferred by semver group will not satisfy the local instance
-  /// - ? We can't know whether the incompatible range matters or not and have to ask
-  MismatchConflictsWithLocal,
+variant_names! {
+  #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+  pub enum SemverGroupAndVersionConflict {
+    /// - ✓ Instance has same semver number as highest/lowest semver in its group
+    /// - ✓ Instance matches its semver group
+    /// - ✘ Range preferred by semver group will not satisfy the highest/lowest semver
+    /// - ? We can't know whether the incompatible range matters or not and have to ask
+    MatchConflictsWithHighestOrLowestSemver,
+    /// - ✓ Instance has same semver number as highest/lowest semver in its group
+    /// - ✘ Instance mismatches its semver group
+    /// - ✘ Range preferred by semver group will not satisfy the highest/lowest semver
+    /// - ? We can't know whether the incompatible range matters or not and have to ask
+    MismatchConflictsWithHighestOrLowestSemver,
+    /// - ✓ Instance has same semver number as the matching snapTo instance
+    /// - ✓ Instance matches its semver group
+    /// - ✘ Range preferred by semver group will not satisfy the matching snapTo instance
+    /// - ? We can't know whether the incompatible range matters or not and have to ask
+    MatchConflictsWithSnapTarget,
+    /// - ✓ Instance has same semver number as the matching snapTo instance
+    /// - ✘ Instance mismatches its semver group
+    /// - ✘ Range preferred by semver group will not satisfy the matching snapTo instance
+    /// - ? We can't know whether the incompatible range matters or not and have to ask
+    MismatchConflictsWithSnapTarget,
+    /// - ✓ Instance has same semver number as local instance in its group
+    /// - ✓ Instance matches its semver group
+    /// - ✘ Range preferred by semver group will not satisfy the local instance
+    /// - ? We can't know whether the incompatible range matters or not and have to ask
+    MatchConflictsWithLocal,
+    /// - ✓ Instance has same semver number as local instance
+    /// - ✘ Instance mismatches its semver group
+    /// - ✘ Range preferred by semver group will not satisfy the local instance
+    /// - ? We can't know whether the incompatible range matters or not and have to ask
+    MismatchConflictsWithLocal,
+  }
 }
 
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub enum SuspectInstance {
-  /// - ✘ Local Instance is in a banned version group
-  /// - ✘ Misconfiguration: Syncpack refuses to change local dependency specifiers
-  RefuseToBanLocal,
-  /// - ✘ Local Instance mismatches its pinned version group
-  /// - ✘ Misconfiguration: Syncpack refuses to change local dependency specifiers
-  RefuseToPinLocal,
-  /// - ✘ Local Instance is in a snapped to version group
-  /// - ✘ An Instance of this dependency was found in the snapped to package
-  /// - ✘ Misconfiguration: Syncpack refuses to change local dependency specifiers
-  RefuseToSnapLocal,
-  /// - ! Local Instance has no version property
-  /// - ! Not an error on its own unless an instance of it mismatches
-  InvalidLocalVersion,
-  /// - ✓ Instance is in a snapped to version group
-  /// - ✘ An instance of the same dependency was not found in any of the snapped to packages
-  /// - ! This is a misconfiguration resulting in this instance being orphaned
-  DependsOnMissingSnapTarget,
-  /// - ✓ Instance uses the catalog: protocol (bare `catalog:` or `catalog:name`)
-  /// - ✘ The referenced catalog does not exist OR the dep name is not defined in it
-  DependsOnMissingCatalogDefinition,
-  /// - ✓ Instance is in a catalog version group
-  /// - ✓ Instance is a local instance (its own package.json `/version` property)
-  /// - ✘ Local instances cannot use the catalog: protocol
-  /// - ! Reconfigure version groups to exclude local instances from the catalog group
-  RefuseToCatalogLocal,
+variant_names! {
+  #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+  pub enum SuspectInstance {
+    /// - ✘ Local Instance is in a banned version group
+    /// - ✘ Misconfiguration: Syncpack refuses to change local dependency specifiers
+    RefuseToBanLocal,
+    /// - ✘ Local Instance mismatches its pinned version group
+    /// - ✘ Misconfiguration: Syncpack refuses to change local dependency specifiers
+    RefuseToPinLocal,
+    /// - ✘ Local Instance is in a snapped to version group
+    /// - ✘ An Instance of this dependency was found in the snapped to package
+    /// - ✘ Misconfiguration: Syncpack refuses to change local dependency specifiers
+    RefuseToSnapLocal,
+    /// - ! Local Instance has no version property
+    /// - ! Not an error on its own unless an instance of it mismatches
+    InvalidLocalVersion,
+    /// - ✓ Instance is in a snapped to version group
+    /// - ✘ An instance of the same dependency was not found in any of the snapped to packages
+    /// - ! This is a misconfiguration resulting in this instance being orphaned
+    DependsOnMissingSnapTarget,
+    /// - ✓ Instance uses the catalog: protocol (bare `catalog:` or `catalog:name`)
+    /// - ✘ The referenced catalog does not exist OR the dep name is not defined in it
+    DependsOnMissingCatalogDefinition,
+    /// - ✓ Instance is in a catalog version group
+    /// - ✓ Instance is a local instance (its own package.json `/version` property)
+    /// - ✘ Local instances cannot use the catalog: protocol
+    /// - ! Reconfigure version groups to exclude local instances from the catalog group
+    RefuseToCatalogLocal,
+    /// - ✓ `checkLockfile` is enabled
+    /// - ✓ Instance matches the rules of its version group and semver group
+    /// - ✘ The version installed according to the lockfile does not satisfy its specifier
+    /// - ! The lockfile is out of date, run an install to update it
+    ResolvedVersionOutOfRange,
+    /// - ✓ `checkLockfile` is enabled
+    /// - ✓ Instance matches the rules of its version group and semver group
+    /// - ✘ Another instance of this dependency is installed at a higher version
+    /// - ! Deduplicate the lockfile so every instance resolves to the same version
+    ResolvedVersionMismatch,
+    /// - ✓ pnpm `patchedDependencies` patches an exact version of this dependency
+    /// - ✓ Instance matches the rules of its version group and semver group
+    /// - ✘ No instance of this dependency can be installed at the patched version
+    /// - ! The patch is orphaned and will no longer be applied, recreate it with `pnpm patch`
+    OrphanedPatch,
+  }
 }
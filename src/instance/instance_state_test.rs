@@ -70,3 +70,14 @@ fn state_get_name_strips_payload_for_pre_existing_tuple_variants() {
 fn state_get_name_unknown_unchanged() {
   assert_eq!(name_of(InstanceState::Unknown), "Unknown");
 }
+
+#[test]
+fn every_issue_name_is_unique_and_documented() {
+  let docs = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("site/src/content/docs/status");
+  let names = InstanceState::issue_names();
+  for (i, name) in names.iter().enumerate() {
+    assert!(!names[..i].contains(name), "{name} is listed twice");
+    let page = docs.join(format!("{}.mdx", crate::commands::ui::util::to_kebab_case(name)));
+    assert!(page.exists(), "{name} has no status page at {page:?}");
+  }
+}
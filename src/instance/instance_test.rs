@@ -18,6 +18,7 @@ fn returns_correct_registry_update_url() {
       "@lit-labs/ssr": "npm:@lit-labs/ssr@3.3.0",
       "@luca/cases": "npm:@jsr/luca__cases@1",
       "@std/fmt": "npm:@jsr/std__fmt@^1.0.3",
+      "@std/path": "jsr:@std/path@^1.0.0",
       "@std/yaml": "npm:@jsr/std__yaml",
       "lit": "npm:lit@3.2.1",
     }
@@ -63,6 +64,13 @@ fn returns_correct_registry_update_url() {
       url: "https://npm.jsr.io/@jsr/std__fmt".to_string()
     })
   );
+  assert_eq!(
+    get_update_url_by_name("@std/path"),
+    Some(UpdateUrl {
+      internal_name: "@std/path".to_string(),
+      url: "https://npm.jsr.io/@jsr/std__path".to_string()
+    })
+  );
   assert_eq!(
     get_update_url_by_name("@std/yaml"),
     Some(UpdateUrl {
@@ -78,3 +86,52 @@ fn returns_correct_registry_update_url() {
     })
   );
 }
+
+#[test]
+fn returns_registry_update_url_of_tools() {
+  let config = test::mock::config_from_mock(json!({}));
+  let (disk, sources) = test::mock::disk_and_sources_from_mocks(vec![
+    json!({
+      "name": "package-a",
+      "engines": {"node": ">=20.0.0", "vscode": "^1.90.0"},
+      "packageManager": "pnpm@9.1.0+sha512.abc123",
+      "volta": {"extends": "../../package.json", "npm": "10.8.0", "yarn": "1.22.22"}
+    }),
+    json!({
+      "name": "package-b",
+      "packageManager": "yarn@4.1.0"
+    }),
+  ]);
+
+  let ctx = Context::create(config, disk, sources, vec![]).unwrap();
+
+  let get_update_url = |dependency_type: &str, name: &str| {
+    ctx
+      .instances
+      .iter()
+      .find(|instance| instance.descriptor.dependency_type.name == dependency_type && instance.descriptor.name == name)
+      .unwrap_or_else(|| panic!("no {name} instance in {dependency_type}"))
+      .get_update_url()
+      .map(|update_url| update_url.url)
+  };
+
+  assert_eq!(
+    get_update_url("engines", "node").as_deref(),
+    Some("https://nodejs.org/dist/index.json")
+  );
+  assert_eq!(get_update_url("engines", "vscode"), None);
+  assert_eq!(
+    get_update_url("packageManager", "pnpm").as_deref(),
+    Some("https://registry.npmjs.org/pnpm")
+  );
+  assert_eq!(
+    get_update_url("packageManager", "yarn").as_deref(),
+    Some("https://registry.npmjs.org/@yarnpkg/cli-dist")
+  );
+  assert_eq!(get_update_url("volta", "npm").as_deref(), Some("https://registry.npmjs.org/npm"));
+  assert_eq!(get_update_url("volta", "yarn").as_deref(), Some("https://registry.npmjs.org/yarn"));
+  assert!(
+    !ctx.instances.iter().any(|instance| instance.descriptor.name == "extends"),
+    "volta.extends is a path rather than a tool version"
+  );
+}
@@ -157,6 +157,8 @@ fn context_sources_arena_owns_pkg_json_and_pnpm_yaml() {
     .map(|s| match s {
       Source::Package { .. } => "Package",
       Source::PnpmYaml => "PnpmYaml",
+      Source::YarnRc => "YarnRc",
+      Source::DenoJson { .. } => "DenoJson",
     })
     .collect();
   assert_eq!(kinds, vec!["Package", "PnpmYaml"]);
@@ -179,7 +181,7 @@ fn fix_zero_catalogs_pnpm_creates_default_on_disk() {
   // No yaml on disk pre-fix, PM=Pnpm, consumer has a real specifier. After
   // fix the auto-created yaml lands on `ctx.disk.pnpm_workspace` — NOT in
   // the sources arena.
-  use crate::commands::{fix, reporter::FixReporter};
+  use crate::commands::{fix, patch::Patch, reporter::FixReporter};
   struct Silent;
   impl FixReporter for Silent {
     fn on_group_header(&self, _: &Context, _: &crate::version_group::VersionGroup) {}
@@ -191,6 +193,8 @@ fn fix_zero_catalogs_pnpm_creates_default_on_disk() {
     fn on_no_issues(&self) {}
 
     fn on_unfixable_warning(&self) {}
+
+    fn on_complete(&self, _: &Context) {}
   }
   let ctx = TestBuilder::new()
     .with_pnpm_package_manager()
@@ -209,7 +213,7 @@ fn fix_zero_catalogs_pnpm_creates_default_on_disk() {
   assert!(ctx.disk.pnpm_workspace.is_none());
 
   let disk = crate::test::mock_disk::MockDiskIo::new();
-  let ctx = fix::run(ctx, &Silent, &disk).expect("fix succeeds");
+  let ctx = fix::run(ctx, &Silent, &disk, &Patch::default()).expect("fix succeeds");
 
   // Auto-created yaml lives on disk.pnpm_workspace.
   let yaml = ctx
@@ -252,7 +256,7 @@ async fn update_pnpm_catalog_def_writes_yaml() {
     times_by_internal_name: std::collections::HashMap::new(),
     failed: vec![],
   };
-  let _ = update::run(ctx, registry, &disk, &tui);
+  let _ = update::run(ctx, registry, &disk, &tui, &crate::commands::reporter::PrettyUpdateReporter);
 }
 
 #[test]
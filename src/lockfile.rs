@@ -0,0 +1,245 @@
+use {
+  crate::disk::{DiskIo, PackageManager},
+  serde_json::Value as JsonValue,
+  std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+  },
+  yaml_serde::Value as YamlValue,
+};
+
+#[cfg(test)]
+#[path = "lockfile_test.rs"]
+mod lockfile_test;
+
+/// The versions which are actually installed, read from the lockfile of the
+/// detected package manager and normalised so every format is queried the
+/// same way.
+#[derive(Debug, Default)]
+pub struct Lockfile {
+  pub filepath: PathBuf,
+  /// Directory of a workspace package relative to the root (`""` for the
+  /// root itself) → dependency name → installed version. Read from pnpm
+  /// `importers`, npm `<dir>/node_modules/<name>` entries and Bun
+  /// `<workspace>/<name>` entries.
+  by_importer: HashMap<String, HashMap<String, String>>,
+  /// `(name, specifier)` → installed version, read from the entry headers of
+  /// `yarn.lock`.
+  by_specifier: HashMap<(String, String), String>,
+  /// Dependency name → version installed at the root `node_modules`, used
+  /// by npm and Bun when a workspace package has no copy of its own.
+  hoisted: HashMap<String, String>,
+}
+
+impl Lockfile {
+  /// Read the lockfile of the given package manager from `directory`.
+  /// Returns `None` when there is no lockfile or it cannot be read. Bun's
+  /// binary `bun.lockb` is not supported.
+  pub fn from_workspace<T: DiskIo>(io: &T, directory: &Path, package_manager: Option<PackageManager>) -> Option<Lockfile> {
+    let (file_name, parse): (&str, fn(&str) -> Option<Lockfile>) = match package_manager? {
+      PackageManager::Bun => ("bun.lock", parse_bun_lock),
+      PackageManager::Npm => ("package-lock.json", parse_package_lock),
+      PackageManager::Pnpm => ("pnpm-lock.yaml", parse_pnpm_lock),
+      PackageManager::Yarn => ("yarn.lock", parse_yarn_lock),
+      PackageManager::Unknown => return None,
+    };
+    let filepath = directory.join(file_name);
+    match io.read_textfile(&filepath)? {
+      Ok(file) => match parse(&file.contents) {
+        Some(lockfile) => Some(Lockfile { filepath, ..lockfile }),
+        None => {
+          log::error!("Invalid lockfile at {}", filepath.to_str().unwrap_or("unknown"));
+          None
+        }
+      },
+      Err(err) => {
+        log::error!("{err}");
+        None
+      }
+    }
+  }
+
+  /// The version of `name` installed for the workspace package in `importer`
+  /// (a directory relative to the root, `""` for the root), which was
+  /// declared as `specifier`.
+  pub fn resolve(&self, importer: &str, name: &str, specifier: &str) -> Option<&str> {
+    self
+      .by_importer
+      .get(importer)
+      .and_then(|deps| deps.get(name))
+      .or_else(|| self.by_specifier.get(&(name.to_string(), specifier.to_string())))
+      .or_else(|| self.by_specifier.get(&(name.to_string(), format!("npm:{specifier}"))))
+      .or_else(|| self.hoisted.get(name))
+      .map(String::as_str)
+  }
+}
+
+/// Dependency maps of a workspace package which are installed
+const INSTALLED_PROPS: [&str; 3] = ["dependencies", "devDependencies", "optionalDependencies"];
+
+/// pnpm-lock.yaml: `importers.<dir>.<dependencies|devDependencies|...>.<name>`
+/// is `{ specifier, version }` from lockfile v6, or the version itself in v5.
+/// Lockfiles of single-package projects have no `importers` and list the
+/// root's dependencies at the top level.
+fn parse_pnpm_lock(raw: &str) -> Option<Lockfile> {
+  let contents: YamlValue = yaml_serde::from_str(raw).ok()?;
+  let mut lockfile = Lockfile::default();
+  let mut read_importer = |dir: &str, importer: &YamlValue| {
+    let deps = lockfile.by_importer.entry(normalise_importer(dir)).or_default();
+    for prop in INSTALLED_PROPS {
+      let Some(map) = importer.get(prop).and_then(YamlValue::as_mapping) else {
+        continue;
+      };
+      for (name, value) in map {
+        let version = value.get("version").unwrap_or(value).as_str();
+        if let (Some(name), Some(version)) = (name.as_str(), version.and_then(pnpm_version)) {
+          deps.insert(name.to_string(), version);
+        }
+      }
+    }
+  };
+  match contents.get("importers").and_then(YamlValue::as_mapping) {
+    Some(importers) => {
+      for (dir, importer) in importers {
+        if let Some(dir) = dir.as_str() {
+          read_importer(dir, importer);
+        }
+      }
+    }
+    None => read_importer(".", &contents),
+  }
+  Some(lockfile)
+}
+
+/// Reduce a pnpm version reference to a version number.
+///
+/// - "4.17.21" → "4.17.21"
+/// - "18.2.0(react@18.2.0)" → "18.2.0"
+/// - "18.2.0_react@18.2.0" → "18.2.0"
+/// - "string-width@4.2.3" → "4.2.3"
+/// - "/string-width/4.2.3" → "4.2.3"
+/// - "link:../a" → None
+fn pnpm_version(value: &str) -> Option<String> {
+  if value.starts_with("link:") || value.starts_with("file:") {
+    return None;
+  }
+  let value = value.split(['(', '_']).next().unwrap_or(value);
+  let value = if value.starts_with('/') {
+    value.rsplit('/').next().unwrap_or(value)
+  } else {
+    value.rsplit_once('@').map_or(value, |(_, version)| version)
+  };
+  Some(value).filter(|v| !v.is_empty()).map(String::from)
+}
+
+/// package-lock.json (lockfile v2 and v3): `packages` is keyed by install
+/// location, such as `node_modules/lodash` for the root or
+/// `packages/a/node_modules/lodash` for a copy nested in a workspace package.
+/// Transitive dependencies nested within other dependencies are ignored.
+fn parse_package_lock(raw: &str) -> Option<Lockfile> {
+  let contents: JsonValue = serde_json::from_str(raw).ok()?;
+  let mut lockfile = Lockfile::default();
+  let Some(packages) = contents.get("packages").and_then(JsonValue::as_object) else {
+    return Some(lockfile);
+  };
+  for (location, entry) in packages {
+    let Some(version) = entry.get("version").and_then(JsonValue::as_str) else {
+      continue;
+    };
+    if let Some(name) = location.strip_prefix("node_modules/") {
+      if !name.contains("/node_modules/") {
+        lockfile.hoisted.insert(name.to_string(), version.to_string());
+      }
+    } else if let Some((dir, name)) = location.split_once("/node_modules/")
+      && !dir.contains("node_modules")
+      && !name.contains("/node_modules/")
+    {
+      lockfile
+        .by_importer
+        .entry(dir.to_string())
+        .or_default()
+        .insert(name.to_string(), version.to_string());
+    }
+  }
+  Some(lockfile)
+}
+
+/// yarn.lock for both Yarn Classic and Yarn Berry. Each entry is headed by
+/// every `name@specifier` which resolved to it, followed by an indented
+/// `version "1.2.3"` (Classic) or `version: 1.2.3` (Berry).
+fn parse_yarn_lock(raw: &str) -> Option<Lockfile> {
+  let mut lockfile = Lockfile::default();
+  let mut descriptors: Vec<(String, String)> = vec![];
+  for line in raw.lines() {
+    if line.is_empty() || line.starts_with('#') {
+      continue;
+    }
+    if !line.starts_with(' ') {
+      descriptors = line
+        .trim_end_matches(':')
+        .split(", ")
+        .filter_map(|descriptor| {
+          let descriptor = descriptor.trim_matches('"');
+          // Skip the first character so the `@` of a scope is not the split point
+          let at = descriptor.get(1..)?.find('@')? + 1;
+          Some((descriptor[..at].to_string(), descriptor[at + 1..].to_string()))
+        })
+        .collect();
+      continue;
+    }
+    let line = line.trim();
+    if let Some(version) = line.strip_prefix("version").filter(|rest| rest.starts_with([' ', ':'])) {
+      let version = version.trim_start_matches(':').trim().trim_matches('"');
+      for descriptor in descriptors.drain(..) {
+        lockfile.by_specifier.insert(descriptor, version.to_string());
+      }
+    }
+  }
+  Some(lockfile)
+}
+
+/// bun.lock (the text lockfile from Bun 1.2): `workspaces` is keyed by the
+/// directory of each workspace package, and `packages` maps a dependency name
+/// to an array whose first item is `name@version`. A copy nested in a
+/// workspace package is keyed `<workspace name>/<dependency name>`.
+fn parse_bun_lock(raw: &str) -> Option<Lockfile> {
+  let contents: JsonValue = json5::from_str(raw).ok()?;
+  let mut lockfile = Lockfile::default();
+  let Some(packages) = contents.get("packages").and_then(JsonValue::as_object) else {
+    return Some(lockfile);
+  };
+  let version_of = |key: &str| -> Option<String> {
+    let (_, version) = packages.get(key)?.get(0)?.as_str()?.rsplit_once('@')?;
+    Some(version).filter(|v| !v.contains(':')).map(String::from)
+  };
+  for key in packages.keys() {
+    if let Some(version) = version_of(key) {
+      lockfile.hoisted.insert(key.clone(), version);
+    }
+  }
+  let workspaces = contents.get("workspaces").and_then(JsonValue::as_object);
+  for (dir, workspace) in workspaces.into_iter().flatten() {
+    let Some(workspace_name) = workspace.get("name").and_then(JsonValue::as_str) else {
+      continue;
+    };
+    let deps = lockfile.by_importer.entry(dir.clone()).or_default();
+    for prop in INSTALLED_PROPS {
+      for name in workspace
+        .get(prop)
+        .and_then(JsonValue::as_object)
+        .into_iter()
+        .flat_map(|map| map.keys())
+      {
+        if let Some(version) = version_of(&format!("{workspace_name}/{name}")) {
+          deps.insert(name.clone(), version);
+        }
+      }
+    }
+  }
+  Some(lockfile)
+}
+
+/// pnpm refers to the root as `"."`, syncpack uses `""` like npm and Bun
+fn normalise_importer(dir: &str) -> String {
+  if dir == "." { String::new() } else { dir.to_string() }
+}
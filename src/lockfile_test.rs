@@ -0,0 +1,268 @@
+use {
+  super::*,
+  crate::{
+    instance::{InstanceState, SuspectInstance, ValidInstance},
+    test::{builder::TestBuilder, mock_disk::MockDiskIo},
+  },
+  serde_json::json,
+};
+
+fn resolved_version(ctx: &crate::context::Context, id: &str) -> Option<String> {
+  ctx
+    .instances
+    .iter()
+    .find(|instance| instance.id == id)
+    .unwrap_or_else(|| panic!("instance not found: {id}"))
+    .resolved_version
+    .clone()
+}
+
+fn state_of(ctx: &crate::context::Context, id: &str) -> InstanceState {
+  ctx
+    .instances
+    .iter()
+    .find(|instance| instance.id == id)
+    .unwrap_or_else(|| panic!("instance not found: {id}"))
+    .state
+    .borrow()
+    .clone()
+}
+
+#[test]
+fn reads_pnpm_importers() {
+  let lockfile = parse_pnpm_lock(
+    r#"
+lockfileVersion: '9.0'
+importers:
+  .:
+    devDependencies:
+      typescript:
+        specifier: ^5.4.0
+        version: 5.4.5
+  packages/a:
+    dependencies:
+      react-dom:
+        specifier: ^18.2.0
+        version: 18.2.0(react@18.2.0)
+      string-width:
+        specifier: npm:string-width@^4.2.0
+        version: string-width@4.2.3
+      b:
+        specifier: workspace:*
+        version: link:../b
+"#,
+  )
+  .unwrap();
+  assert_eq!(lockfile.resolve("", "typescript", "^5.4.0"), Some("5.4.5"));
+  assert_eq!(lockfile.resolve("packages/a", "react-dom", "^18.2.0"), Some("18.2.0"));
+  assert_eq!(
+    lockfile.resolve("packages/a", "string-width", "npm:string-width@^4.2.0"),
+    Some("4.2.3")
+  );
+  assert_eq!(lockfile.resolve("packages/a", "b", "workspace:*"), None);
+  assert_eq!(lockfile.resolve("packages/b", "react-dom", "^18.2.0"), None);
+}
+
+#[test]
+fn reads_pnpm_v5_lockfile_of_a_single_package() {
+  let lockfile = parse_pnpm_lock(
+    r#"
+lockfileVersion: 5.4
+specifiers:
+  react-dom: ^18.2.0
+dependencies:
+  react-dom: 18.2.0_react@18.2.0
+"#,
+  )
+  .unwrap();
+  assert_eq!(lockfile.resolve("", "react-dom", "^18.2.0"), Some("18.2.0"));
+}
+
+#[test]
+fn reads_package_lock_with_nested_and_hoisted_copies() {
+  let lockfile = parse_package_lock(
+    &json!({
+      "lockfileVersion": 3,
+      "packages": {
+        "": { "workspaces": ["packages/*"] },
+        "node_modules/a": { "resolved": "packages/a", "link": true },
+        "node_modules/lodash": { "version": "4.17.21" },
+        "node_modules/lodash/node_modules/other": { "version": "1.0.0" },
+        "packages/a/node_modules/lodash": { "version": "4.17.20" }
+      }
+    })
+    .to_string(),
+  )
+  .unwrap();
+  assert_eq!(lockfile.resolve("packages/a", "lodash", "^4.17.0"), Some("4.17.20"));
+  assert_eq!(lockfile.resolve("packages/b", "lodash", "^4.17.0"), Some("4.17.21"));
+  assert_eq!(lockfile.resolve("", "a", "*"), None);
+  assert_eq!(lockfile.resolve("", "other", "1.0.0"), None);
+}
+
+#[test]
+fn reads_yarn_classic_lockfile() {
+  let lockfile = parse_yarn_lock(
+    r#"# THIS IS AN AUTOGENERATED FILE. DO NOT EDIT THIS FILE DIRECTLY.
+# yarn lockfile v1
+
+
+"@babel/core@^7.0.0", "@babel/core@^7.1.0":
+  version "7.24.0"
+  resolved "https://registry.yarnpkg.com/@babel/core/-/core-7.24.0.tgz"
+
+lodash@^4.17.0:
+  version "4.17.21"
+"#,
+  )
+  .unwrap();
+  assert_eq!(lockfile.resolve("packages/a", "@babel/core", "^7.1.0"), Some("7.24.0"));
+  assert_eq!(lockfile.resolve("", "lodash", "^4.17.0"), Some("4.17.21"));
+  assert_eq!(lockfile.resolve("", "lodash", "^4.16.0"), None);
+}
+
+#[test]
+fn reads_yarn_berry_lockfile() {
+  let lockfile = parse_yarn_lock(
+    r#"__metadata:
+  version: 8
+  cacheKey: 10
+
+"lodash@npm:^4.17.0, lodash@npm:^4.17.20":
+  version: 4.17.21
+  resolution: "lodash@npm:4.17.21"
+  languageName: node
+  linkType: hard
+
+"strings@npm:string-width@^4.2.0":
+  version: 4.2.3
+  resolution: "string-width@npm:4.2.3"
+"#,
+  )
+  .unwrap();
+  assert_eq!(lockfile.resolve("", "lodash", "^4.17.20"), Some("4.17.21"));
+  assert_eq!(lockfile.resolve("", "strings", "npm:string-width@^4.2.0"), Some("4.2.3"));
+}
+
+#[test]
+fn reads_bun_lock_with_nested_and_hoisted_copies() {
+  let lockfile = parse_bun_lock(
+    r#"{
+  "lockfileVersion": 1,
+  "workspaces": {
+    "": { "name": "root" },
+    "packages/a": { "name": "a", "dependencies": { "lodash": "~4.17.20" } },
+  },
+  "packages": {
+    "a": ["a@workspace:packages/a"],
+    "lodash": ["lodash@4.17.21", "", {}, "sha512-"],
+    "a/lodash": ["lodash@4.17.20", "", {}, "sha512-"],
+  },
+}"#,
+  )
+  .unwrap();
+  assert_eq!(lockfile.resolve("packages/a", "lodash", "~4.17.20"), Some("4.17.20"));
+  assert_eq!(lockfile.resolve("", "lodash", "^4.17.0"), Some("4.17.21"));
+  assert_eq!(lockfile.resolve("", "a", "workspace:*"), None);
+}
+
+#[test]
+fn reads_the_lockfile_of_the_detected_package_manager() {
+  let mut io = MockDiskIo::new();
+  io.add_file("yarn.lock", "lodash@^4.17.0:\n  version \"4.17.21\"\n".to_string());
+  io.add_file("package-lock.json", "not json".to_string());
+  let lockfile = Lockfile::from_workspace(&io, io.root(), Some(PackageManager::Yarn)).unwrap();
+  assert_eq!(lockfile.filepath, io.root().join("yarn.lock"));
+  assert_eq!(lockfile.resolve("", "lodash", "^4.17.0"), Some("4.17.21"));
+  assert!(Lockfile::from_workspace(&io, io.root(), Some(PackageManager::Npm)).is_none());
+  assert!(Lockfile::from_workspace(&io, io.root(), Some(PackageManager::Unknown)).is_none());
+}
+
+const PNPM_LOCK: &str = r#"
+lockfileVersion: '9.0'
+importers:
+  packages/a:
+    dependencies:
+      lodash:
+        specifier: ^4.17.0
+        version: 4.17.21
+  packages/b:
+    dependencies:
+      lodash:
+        specifier: ^4.17.0
+        version: 4.17.20
+  packages/c:
+    dependencies:
+      react:
+        specifier: ^18.3.0
+        version: 18.2.0
+"#;
+
+fn packages() -> Vec<serde_json::Value> {
+  vec![
+    json!({ "name": "a", "dependencies": { "lodash": "^4.17.0" } }),
+    json!({ "name": "b", "dependencies": { "lodash": "^4.17.0" } }),
+    json!({ "name": "c", "dependencies": { "react": "^18.3.0" } }),
+  ]
+}
+
+#[tokio::test]
+async fn lockfile_is_not_checked_by_default() {
+  let ctx = TestBuilder::new()
+    .with_packages(packages())
+    .with_file_at("pnpm-lock.yaml", PNPM_LOCK)
+    .run()
+    .await;
+  assert_eq!(resolved_version(&ctx, "lodash in /dependencies of b"), None);
+  assert!(matches!(
+    state_of(&ctx, "lodash in /dependencies of b"),
+    InstanceState::Valid(ValidInstance::IsHighestOrLowestSemver)
+  ));
+}
+
+#[tokio::test]
+async fn resolved_versions_are_read_for_list_and_json() {
+  let ctx = TestBuilder::new()
+    .with_packages(packages())
+    .with_file_at("pnpm-lock.yaml", PNPM_LOCK)
+    .with_subcommand("json")
+    .run()
+    .await;
+  assert_eq!(resolved_version(&ctx, "lodash in /dependencies of a").as_deref(), Some("4.17.21"));
+  assert_eq!(resolved_version(&ctx, "lodash in /dependencies of b").as_deref(), Some("4.17.20"));
+  assert!(state_of(&ctx, "lodash in /dependencies of b").is_valid());
+}
+
+#[tokio::test]
+async fn check_lockfile_flags_versions_which_differ_or_no_longer_satisfy() {
+  let ctx = TestBuilder::new()
+    .with_packages(packages())
+    .with_file_at("pnpm-lock.yaml", PNPM_LOCK)
+    .with_config(json!({ "checkLockfile": true }))
+    .run()
+    .await;
+  assert!(state_of(&ctx, "lodash in /dependencies of a").is_valid());
+  assert!(matches!(
+    state_of(&ctx, "lodash in /dependencies of b"),
+    InstanceState::Suspect(SuspectInstance::ResolvedVersionMismatch)
+  ));
+  assert!(matches!(
+    state_of(&ctx, "react in /dependencies of c"),
+    InstanceState::Suspect(SuspectInstance::ResolvedVersionOutOfRange)
+  ));
+}
+
+#[tokio::test]
+async fn check_lockfile_leaves_mismatched_specifiers_alone() {
+  let ctx = TestBuilder::new()
+    .with_packages(vec![
+      json!({ "name": "a", "dependencies": { "lodash": "^4.17.0" } }),
+      json!({ "name": "b", "dependencies": { "lodash": "^4.16.0" } }),
+    ])
+    .with_file_at("pnpm-lock.yaml", PNPM_LOCK)
+    .with_config(json!({ "checkLockfile": true }))
+    .run()
+    .await;
+  assert!(state_of(&ctx, "lodash in /dependencies of a").is_valid());
+  assert!(state_of(&ctx, "lodash in /dependencies of b").is_fixable());
+}
@@ -65,6 +65,14 @@ pub fn init() {
     .init();
 }
 
+/// Run `f` without logging anything, for work which is repeated later
+pub fn silently<T>(f: impl FnOnce() -> T) -> T {
+  let levels = LOG_LEVELS.swap(0, Ordering::Relaxed);
+  let result = f();
+  LOG_LEVELS.store(levels, Ordering::Relaxed);
+  result
+}
+
 /// Update log levels and ANSI settings after CLI parse succeeds.
 pub fn configure(cli: &Cli) {
   if cli.log_levels.contains(&LevelFilter::Off) {
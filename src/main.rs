@@ -2,7 +2,7 @@
 
 use {
   crate::{
-    cli::Cli,
+    cli::Invocation,
     disk::LiveDiskIo,
     errors::SyncpackError,
     registry::{
@@ -14,7 +14,7 @@ use {
   },
   clap::error::ErrorKind,
   log::{debug, error},
-  std::{process::exit, sync::Arc},
+  std::{path::PathBuf, process::exit, sync::Arc},
 };
 
 mod catalogs;
@@ -29,42 +29,53 @@ mod disk;
 mod errors;
 mod group_selector;
 mod instance;
+mod lockfile;
 mod logger;
 mod rcfile;
 mod registry;
 mod source;
 mod source_patterns;
 mod sources;
+mod span;
 #[cfg(test)]
 #[path = "test/test.rs"]
 mod test;
 pub use syncpack_specifier::{self as specifier, semver_range};
 mod syncpack;
 mod tui;
+mod variant_names;
 mod version_group;
 mod visit_formatting;
 mod visit_packages;
+mod workspace_roots;
 
 #[tokio::main]
 async fn main() {
   let result = async {
     logger::init();
     let args: Vec<String> = std::env::args().collect();
-    let cli = Cli::parse(&args)?;
     let io = Arc::new(LiveDiskIo::new());
+    let cli = match Invocation::parse(&args)? {
+      Invocation::MigrateConfig(cli) => return syncpack::migrate_config(&cli, &*io),
+      Invocation::Run(cli) => cli.with_env_overrides(std::env::vars()),
+    };
+    let live_registry_client =
+      || LiveRegistryClient::new().with_node_release_index(std::env::var_os("SYNCPACK_NODE_RELEASE_INDEX").map(PathBuf::from));
     let registry_client: Arc<dyn RegistryClient> = if cli.no_cache {
-      Arc::new(LiveRegistryClient::new())
+      Arc::new(live_registry_client())
     } else {
       Arc::new(CachedRegistryClient::new(
-        LiveRegistryClient::new(),
+        live_registry_client(),
         Arc::clone(&io),
         default_cache_filepath(),
       ))
     };
     let tui = LiveTui::new();
-    let (ctx, registry_updates) = syncpack::syncpack(cli, &*io, &registry_client).await?;
-    debug!("config: {:#?}", ctx.config);
-    syncpack::run(ctx, registry_updates, &*io, &tui)
+    let runs = syncpack::syncpack_workspace_roots(cli, &*io, &registry_client).await?;
+    for run in &runs {
+      debug!("config: {:#?}", run.ctx.config);
+    }
+    syncpack::run_workspace_roots(runs, &*io, &tui)
   }
   .await;
 
@@ -79,6 +90,6 @@ async fn main() {
     if !msg.is_empty() {
       error!("{e}");
     }
-    exit(1);
+    exit(e.exit_code() as i32);
   }
 }
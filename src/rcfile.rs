@@ -2,30 +2,51 @@ use {
   crate::{
     dependency::DependencyType,
     errors::UnsupportedConfigError,
-    group_selector::GroupSelector,
+    group_selector::{
+      GroupSelector,
+      named::{RawSelector, SelectorExpr, compile_selectors, resolve_selector},
+    },
     instance::severity::SeverityMap,
     sources::Sources,
     version_group::{AnyVersionGroup, CatalogDefsGroup, VersionGroup},
   },
+  nested::NestedOrigins,
   semver_group::{AnySemverGroup, SemverGroup},
   serde::Deserialize,
   serde_json::Value,
   std::{
     collections::{BTreeMap, HashMap},
     mem,
+    sync::Arc,
   },
   update_group::{AnyUpdateGroup, UpdateGroup},
 };
 
 pub mod from_disk;
+pub mod migrate;
+pub mod nested;
+pub mod overrides;
 #[cfg(test)]
 #[path = "rcfile_test.rs"]
 mod rcfile_test;
 pub mod semver_group;
 pub mod update_group;
 
+/// Every built-in dependency type followed by the user's `customTypes`. A
+/// custom type with the same name as a built-in one replaces it, so configs
+/// written before a type became built-in keep working.
 pub fn compute_all_dependency_types(custom_types: &HashMap<String, CustomType>) -> Result<Vec<DependencyType>, UnsupportedConfigError> {
   let default_types = HashMap::from([
+    (
+      String::from("deno"),
+      CustomType {
+        strategy: String::from("importMap"),
+        name_path: None,
+        path: String::from("imports"),
+        source: Some(String::from("DenoJson")),
+        unknown_fields: HashMap::new(),
+      },
+    ),
     (
       String::from("dev"),
       CustomType {
@@ -36,6 +57,16 @@ pub fn compute_all_dependency_types(custom_types: &HashMap<String, CustomType>)
         unknown_fields: HashMap::new(),
       },
     ),
+    (
+      String::from("engines"),
+      CustomType {
+        strategy: String::from("versionsByName"),
+        name_path: None,
+        path: String::from("engines"),
+        source: None,
+        unknown_fields: HashMap::new(),
+      },
+    ),
     (
       String::from("local"),
       CustomType {
@@ -49,13 +80,23 @@ pub fn compute_all_dependency_types(custom_types: &HashMap<String, CustomType>)
     (
       String::from("overrides"),
       CustomType {
-        strategy: String::from("versionsByName"),
+        strategy: String::from("overrideTree"),
         name_path: None,
         path: String::from("overrides"),
         source: None,
         unknown_fields: HashMap::new(),
       },
     ),
+    (
+      String::from("packageManager"),
+      CustomType {
+        strategy: String::from("name@version"),
+        name_path: None,
+        path: String::from("packageManager"),
+        source: None,
+        unknown_fields: HashMap::new(),
+      },
+    ),
     (
       String::from("peer"),
       CustomType {
@@ -76,6 +117,16 @@ pub fn compute_all_dependency_types(custom_types: &HashMap<String, CustomType>)
         unknown_fields: HashMap::new(),
       },
     ),
+    (
+      String::from("pnpmOverridesLegacy"),
+      CustomType {
+        strategy: String::from("versionsByName"),
+        name_path: None,
+        path: String::from("pnpm.overrides"),
+        source: None,
+        unknown_fields: HashMap::new(),
+      },
+    ),
     (
       String::from("prod"),
       CustomType {
@@ -89,16 +140,27 @@ pub fn compute_all_dependency_types(custom_types: &HashMap<String, CustomType>)
     (
       String::from("resolutions"),
       CustomType {
-        strategy: String::from("versionsByName"),
+        strategy: String::from("resolutionPaths"),
         name_path: None,
         path: String::from("resolutions"),
         source: None,
         unknown_fields: HashMap::new(),
       },
     ),
+    (
+      String::from("volta"),
+      CustomType {
+        strategy: String::from("versionsByName"),
+        name_path: None,
+        path: String::from("volta"),
+        source: None,
+        unknown_fields: HashMap::new(),
+      },
+    ),
   ]);
   default_types
     .iter()
+    .filter(|(name, _)| !custom_types.contains_key(*name))
     .chain(custom_types.iter())
     .map(|(name, custom_type)| DependencyType::new(name, custom_type))
     .collect()
@@ -197,6 +259,36 @@ impl std::str::FromStr for SourceMode {
   }
 }
 
+/// How a `file:` or `link:` specifier which points at the directory of a
+/// package developed in the monorepo is treated.
+///
+/// - `Allow` (default): the path satisfies the local package.
+/// - `Workspace`: rewrite it to the `workspace:` protocol.
+/// - `Version`: rewrite it to the version of the local package.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum LocalPathPolicy {
+  #[default]
+  Allow,
+  Workspace,
+  Version,
+}
+
+impl std::str::FromStr for LocalPathPolicy {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "allow" => Ok(LocalPathPolicy::Allow),
+      "workspace" => Ok(LocalPathPolicy::Workspace),
+      "version" => Ok(LocalPathPolicy::Version),
+      other => Err(format!(
+        "invalid value '{other}' for localPathPolicy: expected 'allow', 'workspace' or 'version'"
+      )),
+    }
+  }
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CustomType {
@@ -205,7 +297,7 @@ pub struct CustomType {
   pub path: String,
   /// Which source file kind this dep type reads from. Defaults to
   /// `"PackageJson"` when omitted. Recognised values: `"PackageJson"`,
-  /// `"PnpmWorkspace"`. Parsed via `SourceKind::parse` in
+  /// `"PnpmWorkspace"`, `"YarnRc"`, `"DenoJson"`. Parsed via `SourceKind::parse` in
   /// `DependencyType::new`.
   pub source: Option<String>,
   #[serde(flatten)]
@@ -228,12 +320,37 @@ pub struct DependencyGroup {
   pub unknown_fields: HashMap<String, Value>,
 }
 
+/// A version group whose dependencies must use the same version in every
+/// workspace root, see `workspace_roots::pin_cross_root_versions`
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CrossRootVersionGroup {
+  #[serde(default)]
+  pub dependencies: Vec<String>,
+  #[serde(default)]
+  pub dependency_types: Vec<String>,
+  #[serde(default)]
+  pub label: String,
+  #[serde(default)]
+  pub packages: Vec<String>,
+  /// `"highestSemver"` (the default) or `"lowestSemver"`
+  pub prefer_version: Option<String>,
+  #[serde(default)]
+  pub specifier_types: Vec<String>,
+  #[serde(flatten)]
+  pub unknown_fields: HashMap<String, Value>,
+}
+
 /// Raw deserialized config file. Converted to `Rcfile` via `From<RawRcfile>`.
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct RawRcfile {
   #[serde(rename = "$schema", skip_serializing)]
   _schema: Option<serde::de::IgnoredAny>,
+  #[serde(default = "default_false")]
+  pub check_lockfile: bool,
+  #[serde(default)]
+  pub cross_root_version_groups: Vec<CrossRootVersionGroup>,
   #[serde(default = "empty_custom_types")]
   pub custom_types: HashMap<String, CustomType>,
   #[serde(default)]
@@ -244,6 +361,8 @@ pub(crate) struct RawRcfile {
   pub format_repository: bool,
   #[serde(default = "default_indent")]
   pub indent: Option<String>,
+  #[serde(default)]
+  pub local_path_policy: LocalPathPolicy,
   #[serde(default = "default_max_concurrent_requests")]
   pub max_concurrent_requests: usize,
   /// User-supplied value from the rcfile. `None` means "fall back to
@@ -251,6 +370,10 @@ pub(crate) struct RawRcfile {
   /// `from_disk::resolve_minimum_release_age`.
   #[serde(default)]
   pub minimum_release_age: Option<u64>,
+  #[serde(default = "default_true")]
+  pub respect_gitignore: bool,
+  #[serde(default)]
+  pub selectors: BTreeMap<String, RawSelector>,
   #[serde(default)]
   pub semver_groups: Vec<AnySemverGroup>,
   #[serde(default)]
@@ -271,6 +394,8 @@ pub(crate) struct RawRcfile {
   pub strict: bool,
   #[serde(default)]
   pub version_groups: Vec<AnyVersionGroup>,
+  #[serde(default)]
+  pub workspace_roots: Vec<String>,
   #[serde(flatten)]
   pub unknown_fields: HashMap<String, Value>,
 }
@@ -325,6 +450,15 @@ impl RawRcfile {
         }
       });
     });
+    self.cross_root_version_groups.iter().enumerate().for_each(|(index, value)| {
+      value.unknown_fields.iter().for_each(|(key, _)| {
+        if !key.starts_with("//") {
+          errors.push(UnsupportedConfigError::UnrecognisedProperty {
+            path: format!("crossRootVersionGroups[{index}].{key}"),
+          });
+        }
+      });
+    });
     self.dependency_groups.iter().enumerate().for_each(|(index, value)| {
       value.unknown_fields.iter().for_each(|(key, _)| {
         if !key.starts_with("//") {
@@ -334,6 +468,11 @@ impl RawRcfile {
         }
       });
     });
+    self.selectors.iter().for_each(|(name, value)| {
+      value.unknown_paths(&format!("selectors.{name}")).into_iter().for_each(|path| {
+        errors.push(UnsupportedConfigError::UnrecognisedProperty { path });
+      });
+    });
     self.semver_groups.iter().enumerate().for_each(|(index, value)| {
       value.unknown_fields.iter().for_each(|(key, _)| {
         if !key.starts_with("//") {
@@ -392,28 +531,41 @@ impl TryFrom<RawRcfile> for Rcfile {
       let selector = GroupSelector::new(dg.dependencies, dg.dependency_types, dg.alias_name, dg.packages, dg.specifier_types);
       dependency_groups.push(selector);
     }
+    let selectors = compile_selectors(&raw.selectors)?;
     let mut semver_groups = vec![SemverGroup::get_exact_local_specifiers()];
-    for group_config in raw.semver_groups {
+    for (index, mut group_config) in raw.semver_groups.into_iter().enumerate() {
+      group_config.named_selector = resolve_selector(&selectors, &group_config.selector, &format!("semverGroups[{index}]"))?;
       let semver_group = SemverGroup::from_config(group_config)?;
       semver_groups.push(semver_group);
     }
     semver_groups.push(SemverGroup::get_catch_all());
 
     let mut update_groups = vec![];
-    for group_config in raw.update_groups {
+    for (index, mut group_config) in raw.update_groups.into_iter().enumerate() {
+      group_config.named_selector = resolve_selector(&selectors, &group_config.selector, &format!("updateGroups[{index}]"))?;
       update_groups.push(UpdateGroup::from_config(group_config)?);
     }
 
+    let mut version_groups = raw.version_groups;
+    for (index, group_config) in version_groups.iter_mut().enumerate() {
+      group_config.named_selector = resolve_selector(&selectors, &group_config.selector, &format!("versionGroups[{index}]"))?;
+    }
+
     Ok(Rcfile {
+      check_lockfile: raw.check_lockfile,
+      cross_root_version_groups: raw.cross_root_version_groups,
       dependency_groups,
       format_bugs: raw.format_bugs,
       format_repository: raw.format_repository,
       indent: raw.indent,
+      local_path_policy: raw.local_path_policy,
       max_concurrent_requests: raw.max_concurrent_requests,
       // `from_disk` re-resolves this against pnpm-workspace.yaml. The
       // `try_from`-only paths (tests, `Rcfile::default()`) get the
       // default here so consumers always see a `u64`.
       minimum_release_age: raw.minimum_release_age.unwrap_or(DEFAULT_MINIMUM_RELEASE_AGE),
+      respect_gitignore: raw.respect_gitignore,
+      selectors,
       semver_groups,
       sort_az: raw.sort_az,
       sort_exports: raw.sort_exports,
@@ -423,7 +575,10 @@ impl TryFrom<RawRcfile> for Rcfile {
       source_mode: raw.source_mode,
       strict: raw.strict,
       update_groups,
-      version_groups: raw.version_groups,
+      version_groups,
+      workspace_roots: raw.workspace_roots,
+      nested_version_groups: vec![],
+      nested_origins: NestedOrigins::default(),
       all_dependency_types,
     })
   }
@@ -431,15 +586,30 @@ impl TryFrom<RawRcfile> for Rcfile {
 
 #[derive(Debug)]
 pub struct Rcfile {
+  /// Compare the version each instance resolved to in the lockfile against
+  /// its specifier and against the other instances of its dependency
+  pub check_lockfile: bool,
+  /// Groups of dependencies which must match across every workspace root.
+  /// Only read from the config file of the directory syncpack is run in.
+  pub cross_root_version_groups: Vec<CrossRootVersionGroup>,
   pub dependency_groups: Vec<GroupSelector>,
   pub format_bugs: bool,
   pub format_repository: bool,
   pub indent: Option<String>,
+  /// What to do with `file:` and `link:` specifiers which point at a local
+  /// package
+  pub local_path_policy: LocalPathPolicy,
   pub max_concurrent_requests: usize,
   /// Skip dependency updates published less than this many minutes ago.
   /// `0` disables age filtering. Resolved with precedence:
   /// rcfile → `pnpm-workspace.yaml` → `DEFAULT_MINIMUM_RELEASE_AGE`.
   pub minimum_release_age: u64,
+  /// Skip files excluded by `.gitignore` when searching for packages.
+  /// `.syncpackignore` is always honoured.
+  pub respect_gitignore: bool,
+  /// Every selector defined under the top-level `selectors` property,
+  /// compiled once and shared by the groups which reference them
+  pub selectors: BTreeMap<String, Arc<SelectorExpr>>,
   pub semver_groups: Vec<SemverGroup>,
   pub sort_az: Vec<String>,
   pub sort_exports: Vec<String>,
@@ -450,6 +620,15 @@ pub struct Rcfile {
   pub strict: bool,
   pub update_groups: Vec<UpdateGroup>,
   pub version_groups: Vec<AnyVersionGroup>,
+  /// Directories of independent workspaces to run against one after the
+  /// other. Only read from the config file of the directory syncpack is run
+  /// in, and replaced by `--workspace-roots`.
+  pub workspace_roots: Vec<String>,
+  /// Version groups from the config of individual workspace packages, already
+  /// scoped to their package. Populated by `apply_nested_configs`.
+  pub nested_version_groups: Vec<AnyVersionGroup>,
+  /// Where each nested version group and semver group was defined
+  pub nested_origins: NestedOrigins,
   /// All dependency types (built-in + custom). Computed after deserialization.
   pub all_dependency_types: Vec<DependencyType>,
 }
@@ -486,14 +665,31 @@ impl Rcfile {
 
   /// Create every version group defined in the rcfile.
   ///
+  /// Groups from nested package configs come first so they take precedence
+  /// over the root groups for the package which defines them. Errors name
+  /// the file and index each group came from.
+  ///
   /// Auto-injects a `CatalogDefs` catch-all immediately before the default
   /// `PreferredSemver` catch-all, but only when at least one catalog dep
   /// type exists. Non-catalog projects see no injection.
   pub fn get_version_groups(&mut self, sources: &Sources) -> Result<Vec<VersionGroup>, UnsupportedConfigError> {
-    let mut all_groups: Vec<VersionGroup> = mem::take(&mut self.version_groups)
+    let nested_paths = &self.nested_origins.version_groups;
+    let mut all_groups: Vec<VersionGroup> = mem::take(&mut self.nested_version_groups)
       .into_iter()
       .enumerate()
-      .map(|(index, group_config)| VersionGroup::from_config(group_config, index, sources))
+      .map(|(index, group_config)| {
+        let path = nested_paths
+          .get(index)
+          .cloned()
+          .unwrap_or_else(|| format!("versionGroups[{index}]"));
+        VersionGroup::from_config(group_config, &path, sources)
+      })
+      .chain(
+        mem::take(&mut self.version_groups)
+          .into_iter()
+          .enumerate()
+          .map(|(index, group_config)| VersionGroup::from_config(group_config, &format!("versionGroups[{index}]"), sources)),
+      )
       .collect::<Result<Vec<_>, _>>()?;
     let catalog_dep_type_names = self.catalog_dep_type_names();
     if !catalog_dep_type_names.is_empty() {
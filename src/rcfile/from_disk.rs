@@ -7,8 +7,10 @@ use {
       DEFAULT_MINIMUM_RELEASE_AGE, RawRcfile, Rcfile,
       from_disk::javascript::{JsResult, get_javascript_contents},
     },
+    span::SpanIndex,
   },
   log::debug,
+  serde::de::DeserializeOwned,
   std::{path::Path, time::Instant},
   thiserror::Error,
 };
@@ -20,6 +22,85 @@ mod javascript;
 #[path = "from_disk_test.rs"]
 mod from_disk_test;
 
+/// Config files looked for in the project root, in the order they are tried.
+/// The first one found wins, before falling back to the `syncpack` or
+/// `config.syncpack` property of the root package.json.
+pub(crate) const RCFILE_NAMES: &[&str] = &[
+  ".syncpackrc",
+  ".syncpackrc.json",
+  ".syncpackrc.jsonc",
+  ".syncpackrc.json5",
+  ".syncpackrc.yaml",
+  ".syncpackrc.yml",
+  "syncpack.toml",
+  ".syncpackrc.js",
+  ".syncpackrc.ts",
+  ".syncpackrc.mjs",
+  ".syncpackrc.cjs",
+  "syncpack.config.js",
+  "syncpack.config.ts",
+  "syncpack.config.mjs",
+  "syncpack.config.cjs",
+];
+
+/// How a config file is parsed, decided by its file extension
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum RcfileFormat {
+  /// `.syncpackrc`, `.json`, and any unrecognised extension
+  Json,
+  /// `.jsonc` and `.json5`, which allow comments and trailing commas
+  Json5,
+  /// `.toml`
+  Toml,
+  /// `.yaml` and `.yml`
+  Yaml,
+  /// Evaluated by Node.js: `.js`, `.cjs`, `.mjs`, `.ts`, `.cts`, `.mts`
+  JavaScript,
+}
+
+impl RcfileFormat {
+  pub fn from_path(filepath: &Path) -> Self {
+    match filepath.extension().and_then(|ext| ext.to_str()) {
+      Some("jsonc" | "json5") => RcfileFormat::Json5,
+      Some("toml") => RcfileFormat::Toml,
+      Some("yaml" | "yml") => RcfileFormat::Yaml,
+      Some("js" | "cjs" | "mjs" | "ts" | "cts" | "mts") => RcfileFormat::JavaScript,
+      _ => RcfileFormat::Json,
+    }
+  }
+
+  /// Read a config file which syncpack can parse without Node.js. Returns
+  /// `None` when the file does not exist, or for JavaScript configs.
+  pub fn read<V: DeserializeOwned, T: DiskIo>(self, io: &T, filepath: &Path) -> Option<Result<File<V>, DiskIoError>> {
+    match self {
+      RcfileFormat::Json => io.read_json_file::<V>(filepath),
+      RcfileFormat::Json5 => read_textfile_as(io, filepath, |raw| json5::from_str::<V>(raw).map_err(DiskIoError::Json5Parse)),
+      RcfileFormat::Toml => read_textfile_as(io, filepath, |raw| toml::from_str::<V>(raw).map_err(DiskIoError::TomlParse)),
+      RcfileFormat::Yaml => io.read_yaml_typed::<V>(filepath),
+      RcfileFormat::JavaScript => None,
+    }
+  }
+}
+
+fn read_textfile_as<V, T: DiskIo>(
+  io: &T,
+  filepath: &Path,
+  parse: impl FnOnce(&str) -> Result<V, DiskIoError>,
+) -> Option<Result<File<V>, DiskIoError>> {
+  io.read_textfile(filepath).map(|res| {
+    res.and_then(|file| {
+      parse(&file.contents).map(|contents| File {
+        filepath: file.filepath,
+        formatting: file.formatting,
+        contents,
+        dirty: false,
+        spans: SpanIndex::default(),
+        raw: None,
+      })
+    })
+  })
+}
+
 #[derive(Debug, Error)]
 pub enum JsRcfileError {
   #[error(transparent)]
@@ -46,16 +127,6 @@ impl Rcfile {
   pub fn from_disk<T: DiskIo>(disk: &Disk, io: &T, cli: &Cli) -> Result<File<Rcfile>, RcfileError> {
     let start = Instant::now();
 
-    let from_json_path = |filepath: &Path| -> Option<Result<File<RawRcfile>, RcfileError>> {
-      io.read_json_file::<RawRcfile>(filepath)
-        .map(|res| res.map_err(RcfileError::DiskIoError))
-    };
-
-    let from_yaml_path = |filepath: &Path| -> Option<Result<File<RawRcfile>, RcfileError>> {
-      io.read_yaml_typed::<RawRcfile>(filepath)
-        .map(|res| res.map_err(RcfileError::DiskIoError))
-    };
-
     let from_javascript_path = |filepath: &Path| -> Option<Result<File<RawRcfile>, RcfileError>> {
       Some(filepath).filter(|filepath| filepath.exists()).map(|filepath| {
         let nodejs_script = get_javascript_contents(filepath);
@@ -88,6 +159,8 @@ impl Rcfile {
                 formatting: detect_formatting(&value),
                 contents,
                 dirty: false,
+                spans: SpanIndex::default(),
+                raw: None,
               }),
             JsResult::Error {
               import_error,
@@ -102,12 +175,12 @@ impl Rcfile {
     };
 
     let from_any_path = |filepath: &Path| -> Option<Result<File<RawRcfile>, RcfileError>> {
-      filepath.extension().and_then(|ext| ext.to_str()).and_then(|ext| match ext {
-        "syncpackrc" | "json" => from_json_path(filepath),
-        "yaml" | "yml" => from_yaml_path(filepath),
-        "js" | "cjs" | "mjs" | "ts" | "cts" | "mts" => from_javascript_path(filepath),
-        _ => from_json_path(filepath),
-      })
+      match RcfileFormat::from_path(filepath) {
+        RcfileFormat::JavaScript => from_javascript_path(filepath),
+        format => format
+          .read::<RawRcfile, T>(io, filepath)
+          .map(|res| res.map_err(RcfileError::DiskIoError)),
+      }
     };
 
     let from_cli_option = || -> Option<Result<File<RawRcfile>, RcfileError>> {
@@ -139,24 +212,15 @@ impl Rcfile {
                 formatting: file.formatting.clone(),
                 contents,
                 dirty: false,
+                spans: SpanIndex::default(),
+                raw: None,
               })
           })
       })
     };
 
     let raw_rcfile = from_cli_option()
-      .or_else(|| from_json_path(&disk.cwd.join(".syncpackrc")))
-      .or_else(|| from_json_path(&disk.cwd.join(".syncpackrc.json")))
-      .or_else(|| from_yaml_path(&disk.cwd.join(".syncpackrc.yaml")))
-      .or_else(|| from_yaml_path(&disk.cwd.join(".syncpackrc.yml")))
-      .or_else(|| from_javascript_path(&disk.cwd.join(".syncpackrc.js")))
-      .or_else(|| from_javascript_path(&disk.cwd.join(".syncpackrc.ts")))
-      .or_else(|| from_javascript_path(&disk.cwd.join(".syncpackrc.mjs")))
-      .or_else(|| from_javascript_path(&disk.cwd.join(".syncpackrc.cjs")))
-      .or_else(|| from_javascript_path(&disk.cwd.join("syncpack.config.js")))
-      .or_else(|| from_javascript_path(&disk.cwd.join("syncpack.config.ts")))
-      .or_else(|| from_javascript_path(&disk.cwd.join("syncpack.config.mjs")))
-      .or_else(|| from_javascript_path(&disk.cwd.join("syncpack.config.cjs")))
+      .or_else(|| RCFILE_NAMES.iter().find_map(|name| from_any_path(&disk.cwd.join(name))))
       .or_else(from_package_json_config_property);
 
     if let Some(Ok(file)) = raw_rcfile {
@@ -179,6 +243,8 @@ impl Rcfile {
             formatting: file.formatting.clone(),
             contents: rcfile,
             dirty: false,
+            spans: SpanIndex::default(),
+            raw: None,
           });
         }
         Err(config_error) => {
@@ -199,6 +265,8 @@ impl Rcfile {
       formatting: DetectedFormatting::default(),
       contents: rcfile,
       dirty: false,
+      spans: SpanIndex::default(),
+      raw: None,
     })
   }
 }
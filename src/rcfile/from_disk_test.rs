@@ -1,8 +1,15 @@
 use {
   crate::{
     disk::Disk,
-    rcfile::{DEFAULT_MINIMUM_RELEASE_AGE, from_disk::resolve_minimum_release_age},
-    test::mock::pnpm_yaml_file_from_str,
+    errors::UnsupportedConfigError,
+    rcfile::{
+      DEFAULT_MINIMUM_RELEASE_AGE, Rcfile,
+      from_disk::{RcfileError, resolve_minimum_release_age},
+    },
+    test::{
+      mock::{cli, pnpm_yaml_file_from_str},
+      mock_disk::MockDiskIo,
+    },
   },
   std::path::PathBuf,
 };
@@ -15,6 +22,12 @@ fn empty_disk() -> Disk {
     package_json_root_idx: None,
     package_manager: None,
     pnpm_workspace: None,
+    yarnrc: None,
+    deno_json_files: Vec::new(),
+    lockfile: None,
+    rush_json: None,
+    nx_workspace_json: None,
+    has_nx_json: false,
   }
 }
 
@@ -58,3 +71,65 @@ fn falls_back_to_default_when_no_pnpm_yaml() {
   let disk = empty_disk();
   assert_eq!(resolve_minimum_release_age(None, &disk), DEFAULT_MINIMUM_RELEASE_AGE);
 }
+
+/// Discover and parse the rcfile from a MockDisk containing the given files
+fn rcfile_from_files(files: Vec<(&str, &str)>) -> Result<Rcfile, RcfileError> {
+  let mut io = MockDiskIo::new();
+  for (path, contents) in files {
+    io.add_file(path, contents.to_string());
+  }
+  let disk = Disk::from_workspace(&io, io.root());
+  Rcfile::from_disk(&disk, &io, &cli()).map(|file| file.contents)
+}
+
+#[test]
+fn reads_jsonc_with_comments_and_trailing_commas() {
+  let rcfile = rcfile_from_files(vec![(
+    ".syncpackrc.jsonc",
+    "{\n  // Only the root package.json\n  \"source\": [\"package.json\",],\n  \"strict\": true,\n}\n",
+  )])
+  .unwrap();
+  assert_eq!(rcfile.source, vec!["package.json"]);
+  assert!(rcfile.strict);
+}
+
+#[test]
+fn reads_json5() {
+  let rcfile = rcfile_from_files(vec![(".syncpackrc.json5", "{ indent: '\\t', sortPackages: false }")]).unwrap();
+  assert_eq!(rcfile.indent.as_deref(), Some("\t"));
+  assert!(!rcfile.sort_packages);
+}
+
+#[test]
+fn reads_toml() {
+  let rcfile = rcfile_from_files(vec![(
+    "syncpack.toml",
+    "# Pin react everywhere\nsortFirst = [\"name\"]\n\n[[versionGroups]]\ndependencies = [\"react\"]\npinVersion = \"18.3.1\"\n",
+  )])
+  .unwrap();
+  assert_eq!(rcfile.sort_first, vec!["name"]);
+  assert_eq!(rcfile.version_groups[0].pin_version.as_deref(), Some("18.3.1"));
+}
+
+#[test]
+fn json_is_preferred_over_jsonc_and_toml() {
+  let rcfile = rcfile_from_files(vec![
+    ("syncpack.toml", "strict = false\n"),
+    (".syncpackrc.jsonc", "{ \"strict\": false }"),
+    (".syncpackrc.json", "{ \"strict\": true }"),
+  ])
+  .unwrap();
+  assert!(rcfile.strict);
+}
+
+#[test]
+fn native_formats_reject_unknown_properties() {
+  let result = rcfile_from_files(vec![("syncpack.toml", "lintFormatting = false\n")]);
+  let Err(RcfileError::UnsupportedConfig(errors)) = result else {
+    panic!("expected an unsupported config error, got {result:?}");
+  };
+  assert!(matches!(
+    &errors.0[..],
+    [UnsupportedConfigError::DeprecatedProperty { property, .. }] if property == "lintFormatting"
+  ));
+}
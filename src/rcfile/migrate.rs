@@ -0,0 +1,182 @@
+use {
+  serde_json::{Map, Value, json},
+  std::fmt,
+};
+
+#[cfg(test)]
+#[path = "migrate_test.rs"]
+mod migrate_test;
+
+/// Top-level properties from v13 and earlier which v14 always enables, so
+/// they can be deleted without changing behaviour.
+const REDUNDANT_PROPERTIES: &[(&str, &str)] = &[
+  ("lintFormatting", "Use 'syncpack format --check' to validate formatting"),
+  ("lintSemverRanges", "Semver range checking is always enabled in 'syncpack lint'"),
+  ("lintVersions", "Version checking is always enabled in 'syncpack lint'"),
+];
+
+/// Dependency type names which were renamed, as `(old, new)`.
+const RENAMED_DEPENDENCY_TYPES: &[(&str, &str)] = &[("workspace", "local")];
+
+/// Specifier type names which were renamed, as `(old, new)`.
+const RENAMED_SPECIFIER_TYPES: &[(&str, &str)] = &[("hosted-git", "git")];
+
+/// Group arrays whose selectors may contain renamed type names.
+const GROUP_PROPERTIES: &[&str] = &["dependencyGroups", "semverGroups", "updateGroups", "versionGroups"];
+
+/// One change made (or which needs to be made by hand) while migrating a
+/// config file to the current format.
+#[derive(Debug, PartialEq)]
+pub enum Migration {
+  /// A property was deleted because its behaviour is now always on
+  Removed { property: String, hint: String },
+  /// A top-level filter was rewritten as an ignored version group
+  MovedToVersionGroup { property: String },
+  /// A dependency or specifier type name in a group selector was renamed
+  RenamedType { path: String, from: String, to: String },
+  /// A property cannot be rewritten automatically and was left untouched
+  Manual { property: String, hint: String },
+}
+
+impl Migration {
+  /// Whether the user still has to edit their config after this migration
+  pub fn is_manual(&self) -> bool {
+    matches!(self, Migration::Manual { .. })
+  }
+}
+
+impl fmt::Display for Migration {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Migration::Removed { property, hint } => write!(f, "Removed '{property}': {hint}"),
+      Migration::MovedToVersionGroup { property } => {
+        write!(
+          f,
+          "Replaced '{property}' with an ignored version group at the start of versionGroups"
+        )
+      }
+      Migration::RenamedType { path, from, to } => write!(f, "Renamed '{from}' to '{to}' in {path}"),
+      Migration::Manual { property, hint } => write!(f, "Cannot migrate '{property}' automatically: {hint}"),
+    }
+  }
+}
+
+/// Rewrite deprecated properties of a raw config object in place into their
+/// current equivalents. Key order of untouched properties is preserved.
+///
+/// Returns every change made, in the order it was applied. An empty `Vec`
+/// means the config was already up to date.
+pub fn migrate(config: &mut Map<String, Value>) -> Vec<Migration> {
+  let mut migrations = vec![];
+  let mut new_version_groups = vec![];
+
+  for (property, hint) in REDUNDANT_PROPERTIES {
+    if config.remove(*property).is_some() {
+      migrations.push(Migration::Removed {
+        property: property.to_string(),
+        hint: hint.to_string(),
+      });
+    }
+  }
+
+  for (property, noun) in [("dependencyTypes", "dependency type"), ("specifierTypes", "specifier type")] {
+    let Some(value) = config.remove(property) else {
+      continue;
+    };
+    match inverted_filter(&value) {
+      Some(excluded) => {
+        new_version_groups.push(json!({
+          "label": format!("Migrated from {property}: ignore every other {noun}"),
+          property: excluded,
+          "isIgnored": true,
+        }));
+        migrations.push(Migration::MovedToVersionGroup {
+          property: property.to_string(),
+        });
+      }
+      None => migrations.push(Migration::Removed {
+        property: property.to_string(),
+        hint: format!("It did not exclude any {noun}s"),
+      }),
+    }
+  }
+
+  if config.contains_key("filter") {
+    migrations.push(Migration::Manual {
+      property: "filter".to_string(),
+      hint: "Regular expressions are not supported, add a version group with glob patterns in 'dependencies' and 'isIgnored: true'"
+        .to_string(),
+    });
+  }
+
+  // Groups are inserted before renaming so that the new groups are renamed
+  // too, and so the reported paths match the indexes in the written file
+  if !new_version_groups.is_empty() {
+    match config.get_mut("versionGroups") {
+      Some(Value::Array(existing)) => {
+        new_version_groups.append(existing);
+        *existing = new_version_groups;
+      }
+      _ => {
+        config.insert("versionGroups".to_string(), Value::Array(new_version_groups));
+      }
+    }
+  }
+
+  for property in GROUP_PROPERTIES {
+    let Some(Value::Array(groups)) = config.get_mut(*property) else {
+      continue;
+    };
+    for (index, group) in groups.iter_mut().enumerate() {
+      let path = format!("{property}[{index}]");
+      rename_types(group, &path, "dependencyTypes", RENAMED_DEPENDENCY_TYPES, &mut migrations);
+      rename_types(group, &path, "specifierTypes", RENAMED_SPECIFIER_TYPES, &mut migrations);
+    }
+  }
+
+  migrations
+}
+
+/// Invert a v13 top-level allow-list such as `["prod", "dev"]` into the
+/// selector for an ignored group: `["!prod", "!dev"]`. Returns `None` when the
+/// allow-list already matches everything, so no group is needed.
+fn inverted_filter(value: &Value) -> Option<Value> {
+  let names: Vec<&str> = value.as_array()?.iter().filter_map(|name| name.as_str()).collect();
+  if names.is_empty() || names.contains(&"**") {
+    return None;
+  }
+  Some(Value::Array(
+    names
+      .into_iter()
+      .map(|name| match name.strip_prefix('!') {
+        Some(negated) => Value::String(negated.to_string()),
+        None => Value::String(format!("!{name}")),
+      })
+      .collect(),
+  ))
+}
+
+/// Rename outdated names inside one selector array of a group, keeping any
+/// `!` negation prefix intact.
+fn rename_types(group: &mut Value, group_path: &str, property: &str, renames: &[(&str, &str)], migrations: &mut Vec<Migration>) {
+  let Some(Value::Array(names)) = group.get_mut(property) else {
+    return;
+  };
+  for name in names.iter_mut() {
+    let Some(raw) = name.as_str() else {
+      continue;
+    };
+    let (prefix, bare) = match raw.strip_prefix('!') {
+      Some(bare) => ("!", bare),
+      None => ("", raw),
+    };
+    if let Some((from, to)) = renames.iter().find(|(from, _)| *from == bare) {
+      migrations.push(Migration::RenamedType {
+        path: format!("{group_path}.{property}"),
+        from: from.to_string(),
+        to: to.to_string(),
+      });
+      *name = Value::String(format!("{prefix}{to}"));
+    }
+  }
+}
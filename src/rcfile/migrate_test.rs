@@ -0,0 +1,176 @@
+use {
+  super::*,
+  serde_json::{Map, Value, json},
+};
+
+fn migrate_value(value: Value) -> (Value, Vec<Migration>) {
+  let mut config: Map<String, Value> = serde_json::from_value(value).unwrap();
+  let migrations = migrate(&mut config);
+  (Value::Object(config), migrations)
+}
+
+#[test]
+fn removes_properties_which_are_always_enabled() {
+  let (config, migrations) = migrate_value(json!({
+    "lintFormatting": false,
+    "lintSemverRanges": true,
+    "lintVersions": true,
+    "sortAz": ["name"]
+  }));
+  assert_eq!(config, json!({ "sortAz": ["name"] }));
+  assert_eq!(migrations.len(), 3);
+  assert!(migrations.iter().all(|migration| matches!(migration, Migration::Removed { .. })));
+}
+
+#[test]
+fn moves_dependency_types_to_an_ignored_version_group_ahead_of_existing_groups() {
+  let (config, migrations) = migrate_value(json!({
+    "dependencyTypes": ["prod", "dev"],
+    "versionGroups": [{ "label": "Existing", "dependencies": ["react"] }]
+  }));
+  assert_eq!(
+    config,
+    json!({
+      "versionGroups": [
+        {
+          "label": "Migrated from dependencyTypes: ignore every other dependency type",
+          "dependencyTypes": ["!prod", "!dev"],
+          "isIgnored": true
+        },
+        { "label": "Existing", "dependencies": ["react"] }
+      ]
+    })
+  );
+  assert_eq!(
+    migrations,
+    vec![Migration::MovedToVersionGroup {
+      property: "dependencyTypes".to_string()
+    }]
+  );
+}
+
+#[test]
+fn negated_specifier_types_are_inverted_back_to_plain_names() {
+  let (config, _) = migrate_value(json!({ "specifierTypes": ["!latest"] }));
+  assert_eq!(
+    config,
+    json!({
+      "versionGroups": [{
+        "label": "Migrated from specifierTypes: ignore every other specifier type",
+        "specifierTypes": ["latest"],
+        "isIgnored": true
+      }]
+    })
+  );
+}
+
+#[test]
+fn filters_which_match_everything_are_removed_without_adding_a_group() {
+  let (config, migrations) = migrate_value(json!({ "dependencyTypes": ["**"] }));
+  assert_eq!(config, json!({}));
+  assert!(matches!(&migrations[..], [Migration::Removed { property, .. }] if property == "dependencyTypes"));
+}
+
+#[test]
+fn renames_outdated_type_names_inside_groups_and_keeps_negation() {
+  let (config, migrations) = migrate_value(json!({
+    "semverGroups": [{ "dependencyTypes": ["!workspace", "dev"], "range": "^" }],
+    "versionGroups": [{ "specifierTypes": ["hosted-git"], "isIgnored": true }]
+  }));
+  assert_eq!(
+    config,
+    json!({
+      "semverGroups": [{ "dependencyTypes": ["!local", "dev"], "range": "^" }],
+      "versionGroups": [{ "specifierTypes": ["git"], "isIgnored": true }]
+    })
+  );
+  assert_eq!(
+    migrations,
+    vec![
+      Migration::RenamedType {
+        path: "semverGroups[0].dependencyTypes".to_string(),
+        from: "workspace".to_string(),
+        to: "local".to_string(),
+      },
+      Migration::RenamedType {
+        path: "versionGroups[0].specifierTypes".to_string(),
+        from: "hosted-git".to_string(),
+        to: "git".to_string(),
+      },
+    ]
+  );
+}
+
+#[test]
+fn filter_is_left_in_place_for_a_manual_migration() {
+  let (config, migrations) = migrate_value(json!({ "filter": "^react$" }));
+  assert_eq!(config, json!({ "filter": "^react$" }));
+  assert!(migrations[0].is_manual());
+}
+
+#[test]
+fn up_to_date_config_is_unchanged() {
+  let original = json!({
+    "versionGroups": [{ "dependencyTypes": ["local"], "isIgnored": true }],
+    "semverGroups": [{ "range": "^" }]
+  });
+  let (config, migrations) = migrate_value(original.clone());
+  assert_eq!(config, original);
+  assert!(migrations.is_empty());
+}
+
+#[test]
+fn renames_types_in_the_version_groups_it_creates() {
+  let (config, migrations) = migrate_value(json!({
+    "dependencyTypes": ["prod", "workspace"],
+    "specifierTypes": ["exact", "hosted-git"],
+    "versionGroups": [{ "dependencyTypes": ["workspace"], "isIgnored": true }]
+  }));
+  assert_eq!(
+    config,
+    json!({
+      "versionGroups": [
+        {
+          "label": "Migrated from dependencyTypes: ignore every other dependency type",
+          "dependencyTypes": ["!prod", "!local"],
+          "isIgnored": true
+        },
+        {
+          "label": "Migrated from specifierTypes: ignore every other specifier type",
+          "specifierTypes": ["!exact", "!git"],
+          "isIgnored": true
+        },
+        { "dependencyTypes": ["local"], "isIgnored": true }
+      ]
+    })
+  );
+  let renamed_paths: Vec<&str> = migrations
+    .iter()
+    .filter_map(|migration| match migration {
+      Migration::RenamedType { path, .. } => Some(path.as_str()),
+      _ => None,
+    })
+    .collect();
+  assert_eq!(
+    renamed_paths,
+    vec![
+      "versionGroups[0].dependencyTypes",
+      "versionGroups[1].specifierTypes",
+      "versionGroups[2].dependencyTypes"
+    ]
+  );
+}
+
+#[test]
+fn migrating_twice_changes_nothing_more() {
+  let (once, _) = migrate_value(json!({
+    "lintVersions": true,
+    "dependencyTypes": ["prod", "workspace"],
+    "specifierTypes": ["exact", "hosted-git"],
+    "semverGroups": [{ "specifierTypes": ["!hosted-git"], "range": "^" }],
+    "versionGroups": [{ "dependencyTypes": ["workspace"], "isIgnored": true }]
+  }));
+  let (twice, migrations) = migrate_value(once.clone());
+  assert_eq!(twice, once);
+  assert_eq!(migrations, vec![]);
+}
@@ -0,0 +1,237 @@
+use {
+  crate::{
+    disk::{Disk, DiskIo, DiskIoError, File, package_name},
+    errors::{UnsupportedConfigError, UnsupportedConfigErrors},
+    group_selector::{GroupSelector, named::resolve_selector},
+    instance::InstanceDescriptor,
+    rcfile::{
+      Rcfile,
+      from_disk::{RcfileError, RcfileFormat},
+      semver_group::{AnySemverGroup, SemverGroup},
+    },
+    version_group::AnyVersionGroup,
+  },
+  log::{debug, warn},
+  serde::Deserialize,
+  serde_json::Value,
+  std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    path::PathBuf,
+  },
+};
+
+#[cfg(test)]
+#[path = "nested_test.rs"]
+mod nested_test;
+
+/// Config files looked for in each workspace package, in the order they are
+/// tried, before falling back to the `syncpack` property of its package.json.
+/// JavaScript configs are only supported at the root of the project.
+const NESTED_RCFILE_NAMES: &[&str] = &[
+  ".syncpackrc",
+  ".syncpackrc.json",
+  ".syncpackrc.jsonc",
+  ".syncpackrc.json5",
+  ".syncpackrc.yaml",
+  ".syncpackrc.yml",
+  "syncpack.toml",
+];
+
+/// The subset of config which a workspace package can define for itself
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct RawNestedRcfile {
+  #[serde(rename = "$schema", skip_serializing)]
+  _schema: Option<serde::de::IgnoredAny>,
+  #[serde(default)]
+  pub semver_groups: Vec<AnySemverGroup>,
+  #[serde(default)]
+  pub version_groups: Vec<AnyVersionGroup>,
+  #[serde(flatten)]
+  pub unknown_fields: HashMap<String, Value>,
+}
+
+impl RawNestedRcfile {
+  /// Reject anything other than version groups and semver groups, and any
+  /// group which tries to choose its own packages.
+  fn validate(&self, origin: &str) -> Vec<UnsupportedConfigError> {
+    let mut errors = vec![];
+    self.unknown_fields.keys().filter(|key| !key.starts_with("//")).for_each(|key| {
+      errors.push(UnsupportedConfigError::UnsupportedNestedProperty {
+        origin: origin.to_string(),
+        property: key.clone(),
+      });
+    });
+    let semver_groups = self
+      .semver_groups
+      .iter()
+      .map(|group| (&group.packages, &group.unknown_fields))
+      .enumerate()
+      .map(|(index, fields)| (format!("semverGroups[{index}]"), fields));
+    let version_groups = self
+      .version_groups
+      .iter()
+      .map(|group| (&group.packages, &group.unknown_fields))
+      .enumerate()
+      .map(|(index, fields)| (format!("versionGroups[{index}]"), fields));
+    semver_groups.chain(version_groups).for_each(|(path, (packages, unknown_fields))| {
+      if !packages.is_empty() {
+        errors.push(UnsupportedConfigError::NestedGroupPackages {
+          path: format!("{origin} {path}"),
+        });
+      }
+      unknown_fields.keys().filter(|key| !key.starts_with("//")).for_each(|key| {
+        errors.push(UnsupportedConfigError::UnrecognisedProperty {
+          path: format!("{origin} {path}.{key}"),
+        });
+      });
+    });
+    errors
+  }
+}
+
+/// Where each group defined by a workspace package's own config came from,
+/// such as `packages/a/.syncpackrc versionGroups[0]`
+#[derive(Debug, Default)]
+pub struct NestedOrigins {
+  /// In the same order as `Rcfile::nested_version_groups`
+  pub version_groups: Vec<String>,
+  /// In the same order as the nested groups within `Rcfile::semver_groups`
+  pub semver_groups: Vec<String>,
+}
+
+impl Rcfile {
+  /// Read the config of every workspace package other than the root and
+  /// scope its groups to that package. Nested semver groups are inserted
+  /// ahead of the root semver groups here, nested version groups are
+  /// inserted ahead of the root version groups by `get_version_groups`.
+  pub fn apply_nested_configs<T: DiskIo>(&mut self, disk: &Disk, io: &T) -> Result<(), RcfileError> {
+    let root_filepath = disk.package_json_root().map(|file| file.filepath.clone());
+    let mut errors: Vec<UnsupportedConfigError> = vec![];
+    let mut semver_groups: Vec<SemverGroup> = vec![];
+
+    for file in &disk.package_json_files {
+      if root_filepath.as_ref() == Some(&file.filepath) {
+        continue;
+      }
+      let Some((origin_path, raw)) = read_nested_rcfile(file, io).transpose().map_err(RcfileError::DiskIoError)? else {
+        continue;
+      };
+      let origin = origin_path
+        .strip_prefix(&disk.cwd)
+        .unwrap_or(&origin_path)
+        .to_string_lossy()
+        .to_string();
+      debug!("Found nested config at {origin}");
+      let config_errors = raw.validate(&origin);
+      if !config_errors.is_empty() {
+        errors.extend(config_errors);
+        continue;
+      }
+      let scope = vec![package_name(file).to_string()];
+      for (index, mut group) in raw.semver_groups.into_iter().enumerate() {
+        group.packages = scope.clone();
+        let path = format!("{origin} semverGroups[{index}]");
+        group.named_selector = match resolve_selector(&self.selectors, &group.selector, &path) {
+          Ok(named_selector) => named_selector,
+          Err(err) => {
+            errors.push(err);
+            continue;
+          }
+        };
+        match SemverGroup::from_config(group) {
+          Ok(semver_group) => {
+            semver_groups.push(semver_group);
+            self.nested_origins.semver_groups.push(path);
+          }
+          Err(err) => errors.push(err),
+        }
+      }
+      for (index, mut group) in raw.version_groups.into_iter().enumerate() {
+        group.packages = scope.clone();
+        let path = format!("{origin} versionGroups[{index}]");
+        match resolve_selector(&self.selectors, &group.selector, &path) {
+          Ok(named_selector) => group.named_selector = named_selector,
+          Err(err) => {
+            errors.push(err);
+            continue;
+          }
+        }
+        self.nested_version_groups.push(group);
+        self.nested_origins.version_groups.push(path);
+      }
+    }
+
+    if !errors.is_empty() {
+      return Err(RcfileError::UnsupportedConfig(UnsupportedConfigErrors(errors)));
+    }
+
+    // The built-in "local package versions must be exact" group stays first
+    let insert_at = self.semver_groups.len().min(1);
+    self.semver_groups.splice(insert_at..insert_at, semver_groups);
+    Ok(())
+  }
+}
+
+/// Read the nested config of one workspace package, returning the path it was
+/// read from. A config file next to the package.json wins over its `syncpack`
+/// property.
+fn read_nested_rcfile<T: DiskIo>(file: &File<Value>, io: &T) -> Option<Result<(PathBuf, RawNestedRcfile), DiskIoError>> {
+  let dirpath = file.filepath.parent()?;
+  NESTED_RCFILE_NAMES
+    .iter()
+    .map(|name| dirpath.join(name))
+    .find_map(|filepath| {
+      RcfileFormat::from_path(&filepath)
+        .read::<RawNestedRcfile, T>(io, &filepath)
+        .map(|res| res.map(|nested| (filepath, nested.contents)))
+    })
+    .or_else(|| {
+      file.contents.get("syncpack").cloned().map(|value| {
+        serde_json::from_value::<RawNestedRcfile>(value)
+          .map_err(DiskIoError::JsonParse)
+          .map(|nested| (file.filepath.clone(), nested))
+      })
+    })
+}
+
+/// Records every time a nested group claimed an instance which a root group
+/// would otherwise have claimed, so the overlap can be reported once per pair
+/// of groups rather than once per instance.
+#[derive(Debug, Default)]
+pub struct NestedOverrides {
+  /// `(nested origin, root group path)` → names of the overridden dependencies
+  overridden: BTreeMap<(String, String), BTreeSet<String>>,
+}
+
+impl NestedOverrides {
+  /// Compare the first nested group and the first root group which match an
+  /// instance. `nested` and `root` are paired with their origin labels.
+  pub fn check<'a>(
+    &mut self,
+    nested: impl Iterator<Item = (&'a str, &'a GroupSelector)>,
+    root: impl Iterator<Item = (String, &'a GroupSelector)>,
+    descriptor: &InstanceDescriptor,
+    package_name: &str,
+  ) {
+    let Some((nested_origin, _)) = nested.into_iter().find(|(_, selector)| selector.can_add(descriptor, package_name)) else {
+      return;
+    };
+    let Some((root_path, _)) = root.into_iter().find(|(_, selector)| selector.can_add(descriptor, package_name)) else {
+      return;
+    };
+    self
+      .overridden
+      .entry((nested_origin.to_string(), root_path))
+      .or_default()
+      .insert(descriptor.internal_name.clone());
+  }
+
+  /// Warn about every root group which was overridden by a nested group
+  pub fn report(&self) {
+    self.overridden.iter().for_each(|((nested_origin, root_path), dependencies)| {
+      let names = dependencies.iter().cloned().collect::<Vec<_>>().join(", ");
+      warn!("{nested_origin} overrides root {root_path} for {names}");
+    });
+  }
+}
@@ -0,0 +1,206 @@
+use {
+  crate::{
+    disk::{Disk, DiskIo},
+    errors::UnsupportedConfigError,
+    rcfile::{Rcfile, from_disk::RcfileError},
+    test::{builder::TestBuilder, mock_disk::MockDiskIo},
+  },
+  serde_json::{Value, json},
+};
+
+fn expected_version(ctx: &crate::context::Context, id: &str) -> Option<String> {
+  ctx
+    .instances
+    .iter()
+    .find(|instance| instance.id == id)
+    .unwrap_or_else(|| panic!("instance not found: {id}"))
+    .expected_specifier
+    .borrow()
+    .as_ref()
+    .map(|specifier| specifier.get_raw().to_string())
+}
+
+/// Run `apply_nested_configs` against packages written to a MockDisk
+fn apply_nested(packages: Vec<(&str, Value)>, files: Vec<(&str, &str)>) -> Result<Rcfile, RcfileError> {
+  let mut io = MockDiskIo::new();
+  for (path, contents) in &packages {
+    io.add_json(path, contents);
+  }
+  for (path, contents) in files {
+    io.add_file(path, contents.to_string());
+  }
+  let mut disk = Disk::from_workspace(&io, io.root());
+  let file_paths = io.find_package_jsons(io.root(), &["packages/*/package.json".to_string()], true);
+  disk.load_package_files(&io, &file_paths);
+  let mut rcfile = Rcfile::default();
+  rcfile.apply_nested_configs(&disk, &io)?;
+  Ok(rcfile)
+}
+
+fn unsupported_config(result: Result<Rcfile, RcfileError>) -> Vec<UnsupportedConfigError> {
+  match result {
+    Err(RcfileError::UnsupportedConfig(errors)) => errors.0,
+    other => panic!("expected an unsupported config error, got {other:?}"),
+  }
+}
+
+#[tokio::test]
+async fn nested_version_group_in_package_json_takes_precedence_for_its_own_package() {
+  let ctx = TestBuilder::new()
+    .with_packages(vec![
+      json!({
+        "name": "package-a",
+        "dependencies": { "react": "16.0.0" },
+        "syncpack": { "versionGroups": [{ "dependencies": ["react"], "pinVersion": "18.0.0" }] }
+      }),
+      json!({ "name": "package-b", "dependencies": { "react": "16.0.0" } }),
+    ])
+    .with_version_group(json!({ "dependencies": ["react"], "pinVersion": "17.0.0" }))
+    .run()
+    .await;
+
+  assert_eq!(
+    expected_version(&ctx, "react in /dependencies of package-a").as_deref(),
+    Some("18.0.0")
+  );
+  assert_eq!(
+    expected_version(&ctx, "react in /dependencies of package-b").as_deref(),
+    Some("17.0.0")
+  );
+}
+
+#[tokio::test]
+async fn nested_rcfile_is_preferred_over_package_json_property() {
+  let ctx = TestBuilder::new()
+    .with_package(json!({
+      "name": "package-a",
+      "dependencies": { "react": "16.0.0" },
+      "syncpack": { "versionGroups": [{ "dependencies": ["react"], "pinVersion": "15.0.0" }] }
+    }))
+    .with_file_at(
+      "packages/package-a/.syncpackrc",
+      r#"{ "versionGroups": [{ "dependencies": ["react"], "pinVersion": "18.0.0" }] }"#,
+    )
+    .run()
+    .await;
+
+  assert_eq!(
+    expected_version(&ctx, "react in /dependencies of package-a").as_deref(),
+    Some("18.0.0")
+  );
+}
+
+#[tokio::test]
+async fn nested_semver_group_is_scoped_to_its_own_package() {
+  let ctx = TestBuilder::new()
+    .with_packages(vec![
+      json!({ "name": "package-a", "dependencies": { "react": "^18.0.0" } }),
+      json!({ "name": "package-b", "dependencies": { "react": "^18.0.0" } }),
+    ])
+    .with_file_at("packages/package-a/.syncpackrc.yaml", "semverGroups:\n  - range: '~'\n")
+    .with_semver_group(json!({ "range": "^" }))
+    .run()
+    .await;
+
+  let range_of = |id: &str| {
+    ctx
+      .instances
+      .iter()
+      .find(|instance| instance.id == id)
+      .and_then(|instance| instance.preferred_semver_range.clone())
+      .map(|range| range.unwrap())
+  };
+  assert_eq!(range_of("react in /dependencies of package-a").as_deref(), Some("~"));
+  assert_eq!(range_of("react in /dependencies of package-b").as_deref(), Some("^"));
+}
+
+#[test]
+fn nested_groups_come_before_root_semver_groups_and_record_their_origin() {
+  let rcfile = apply_nested(
+    vec![(
+      "packages/a/package.json",
+      json!({ "name": "a", "syncpack": { "semverGroups": [{ "range": "~" }], "versionGroups": [{ "isIgnored": true }] } }),
+    )],
+    vec![],
+  )
+  .unwrap();
+
+  assert_eq!(rcfile.semver_groups.len(), 3);
+  assert_eq!(rcfile.nested_version_groups[0].packages, vec!["a".to_string()]);
+  assert_eq!(rcfile.nested_origins.semver_groups, vec!["packages/a/package.json semverGroups[0]"]);
+  assert_eq!(
+    rcfile.nested_origins.version_groups,
+    vec!["packages/a/package.json versionGroups[0]"]
+  );
+}
+
+#[test]
+fn version_group_errors_name_the_file_and_index_of_the_group() {
+  let mut rcfile = apply_nested(
+    vec![(
+      "packages/a/package.json",
+      json!({ "name": "a", "syncpack": { "versionGroups": [{ "isBanned": true, "severity": { "DiffersToPin": "warn" } }] } }),
+    )],
+    vec![],
+  )
+  .unwrap();
+  let err = rcfile.get_version_groups(&crate::sources::Sources::new()).unwrap_err();
+  assert!(matches!(
+    err,
+    UnsupportedConfigError::InvalidSeverityKey { path, .. } if path == "packages/a/package.json versionGroups[0].severity.DiffersToPin"
+  ));
+
+  let mut rcfile = apply_nested(vec![("packages/a/package.json", json!({ "name": "a" }))], vec![]).unwrap();
+  rcfile.version_groups = serde_json::from_value(json!([{ "isBanned": true, "severity": { "DiffersToPin": "warn" } }])).unwrap();
+  let err = rcfile.get_version_groups(&crate::sources::Sources::new()).unwrap_err();
+  assert!(matches!(
+    err,
+    UnsupportedConfigError::InvalidSeverityKey { path, .. } if path == "versionGroups[0].severity.DiffersToPin"
+  ));
+}
+
+#[test]
+fn root_package_config_is_not_treated_as_nested() {
+  let rcfile = apply_nested(
+    vec![(
+      "package.json",
+      json!({ "name": "root", "syncpack": { "versionGroups": [{ "isIgnored": true }] } }),
+    )],
+    vec![],
+  )
+  .unwrap();
+
+  assert!(rcfile.nested_version_groups.is_empty());
+}
+
+#[test]
+fn nested_group_cannot_choose_its_own_packages() {
+  let errors = unsupported_config(apply_nested(
+    vec![("packages/a/package.json", json!({ "name": "a" }))],
+    vec![(
+      "packages/a/.syncpackrc",
+      r#"{ "versionGroups": [{ "packages": ["b"], "isIgnored": true }] }"#,
+    )],
+  ));
+
+  assert!(matches!(
+    &errors[..],
+    [UnsupportedConfigError::NestedGroupPackages { path }] if path == "packages/a/.syncpackrc versionGroups[0]"
+  ));
+}
+
+#[test]
+fn nested_config_can_only_contain_groups() {
+  let errors = unsupported_config(apply_nested(
+    vec![(
+      "packages/a/package.json",
+      json!({ "name": "a", "syncpack": { "sortFirst": ["name"] } }),
+    )],
+    vec![],
+  ));
+
+  assert!(matches!(
+    &errors[..],
+    [UnsupportedConfigError::UnsupportedNestedProperty { property, .. }] if property == "sortFirst"
+  ));
+}
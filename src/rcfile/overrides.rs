@@ -0,0 +1,194 @@
+use {
+  crate::{
+    errors::{UnsupportedConfigError, UnsupportedConfigErrors},
+    rcfile::{LocalPathPolicy, Rcfile, SourceMode},
+  },
+  log::debug,
+  std::{fmt, str::FromStr},
+};
+
+#[cfg(test)]
+#[path = "overrides_test.rs"]
+mod overrides_test;
+
+/// Declare every scalar rcfile option which can be overridden and the
+/// function which parses its value, so that `OVERRIDABLE_OPTIONS` and the
+/// field each key sets cannot drift apart
+macro_rules! overridable_options {
+  ($($key:literal => $field:ident: $parse:path),* $(,)?) => {
+    /// Every scalar rcfile option which can be overridden. Each is also read
+    /// from its environment variable, see `env_name`.
+    pub const OVERRIDABLE_OPTIONS: &[&str] = &[$($key),*];
+
+    impl Rcfile {
+      /// Set the field of an overridable option. `None` when `key` is not
+      /// one, otherwise a description of the value expected when it is
+      /// invalid.
+      fn set_option(&mut self, key: &str, value: &str) -> Option<Result<(), &'static str>> {
+        match key {
+          $($key => Some($parse(value).map(|parsed| self.$field = parsed)),)*
+          _ => None,
+        }
+      }
+    }
+  };
+}
+
+overridable_options! {
+  "checkLockfile" => check_lockfile: boolean,
+  "formatBugs" => format_bugs: boolean,
+  "formatRepository" => format_repository: boolean,
+  "indent" => indent: text,
+  "localPathPolicy" => local_path_policy: local_path_policy,
+  "maxConcurrentRequests" => max_concurrent_requests: request_count,
+  "minimumReleaseAge" => minimum_release_age: minutes,
+  "respectGitignore" => respect_gitignore: boolean,
+  "sortPackages" => sort_packages: boolean,
+  "sourceMode" => source_mode: source_mode,
+  "strict" => strict: boolean,
+}
+
+/// The environment variable of an overridable option, such as
+/// `SYNCPACK_MAX_CONCURRENT_REQUESTS` for `maxConcurrentRequests`
+pub fn env_name(key: &str) -> String {
+  key.chars().fold("SYNCPACK_".to_string(), |mut name, char| {
+    if char.is_ascii_uppercase() {
+      name.push('_');
+    }
+    name.push(char.to_ascii_uppercase());
+    name
+  })
+}
+
+/// Where an override was provided
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum OverrideOrigin {
+  /// `--set <key>=<value>`
+  Cli,
+  /// An environment variable such as `SYNCPACK_STRICT`
+  Env(String),
+}
+
+impl fmt::Display for OverrideOrigin {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      OverrideOrigin::Cli => write!(f, "--set"),
+      OverrideOrigin::Env(name) => write!(f, "{name}"),
+    }
+  }
+}
+
+/// One rcfile option replaced from outside of the config file
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfigOverride {
+  /// The rcfile property name, such as `maxConcurrentRequests`
+  pub key: String,
+  /// The raw value, parsed according to the type of the property
+  pub value: String,
+  pub origin: OverrideOrigin,
+}
+
+impl ConfigOverride {
+  /// Parse a `--set` value in the form `<key>=<value>`
+  pub fn from_cli(raw: &str) -> Result<ConfigOverride, String> {
+    let (key, value) = raw
+      .split_once('=')
+      .ok_or_else(|| format!("expected <key>=<value> but found '{raw}'"))?;
+    if !OVERRIDABLE_OPTIONS.contains(&key) {
+      return Err(format!("'{key}' cannot be overridden, expected one of: {}", overridable_names()));
+    }
+    Ok(ConfigOverride {
+      key: key.to_string(),
+      value: value.to_string(),
+      origin: OverrideOrigin::Cli,
+    })
+  }
+
+  /// Find every `SYNCPACK_*` environment variable which matches an
+  /// overridable option. Unrelated variables are ignored.
+  pub fn from_env(vars: impl Iterator<Item = (String, String)>) -> Vec<ConfigOverride> {
+    let mut overrides: Vec<ConfigOverride> = vars
+      .filter_map(|(name, value)| {
+        OVERRIDABLE_OPTIONS
+          .iter()
+          .find(|key| env_name(key) == name)
+          .map(|key| ConfigOverride {
+            key: key.to_string(),
+            value,
+            origin: OverrideOrigin::Env(name),
+          })
+      })
+      .collect();
+    // Environment variables have no inherent order, keep errors predictable
+    overrides.sort_by(|a, b| a.key.cmp(&b.key));
+    overrides
+  }
+}
+
+impl Rcfile {
+  /// Replace rcfile options with values from environment variables or
+  /// `--set`, in the order given so that later overrides win. Every invalid
+  /// value is reported rather than only the first.
+  pub fn apply_overrides(&mut self, overrides: &[ConfigOverride]) -> Result<(), UnsupportedConfigErrors> {
+    let errors: Vec<UnsupportedConfigError> = overrides
+      .iter()
+      .filter_map(|config_override| self.apply_override(config_override).err())
+      .collect();
+    if errors.is_empty() {
+      Ok(())
+    } else {
+      Err(UnsupportedConfigErrors(errors))
+    }
+  }
+
+  fn apply_override(&mut self, config_override: &ConfigOverride) -> Result<(), UnsupportedConfigError> {
+    let ConfigOverride { key, value, origin } = config_override;
+    debug!("Overriding {key} with '{value}' from {origin}");
+    match self.set_option(key, value) {
+      Some(Ok(())) => Ok(()),
+      Some(Err(expected)) => Err(UnsupportedConfigError::InvalidOverride {
+        key: key.clone(),
+        value: value.clone(),
+        origin: origin.to_string(),
+        expected: expected.to_string(),
+      }),
+      None => Err(UnsupportedConfigError::UnknownOverride {
+        key: key.clone(),
+        origin: origin.to_string(),
+        supported: overridable_names(),
+      }),
+    }
+  }
+}
+
+fn boolean(value: &str) -> Result<bool, &'static str> {
+  parse(value).map_err(|_| "true or false")
+}
+
+fn text(value: &str) -> Result<Option<String>, &'static str> {
+  Ok(Some(value.to_string()))
+}
+
+fn local_path_policy(value: &str) -> Result<LocalPathPolicy, &'static str> {
+  LocalPathPolicy::from_str(value).map_err(|_| "allow, workspace or version")
+}
+
+fn request_count(value: &str) -> Result<usize, &'static str> {
+  parse(value).ok().filter(|count| *count > 0).ok_or("a whole number greater than 0")
+}
+
+fn minutes(value: &str) -> Result<u64, &'static str> {
+  parse(value).map_err(|_| "a whole number of minutes")
+}
+
+fn source_mode(value: &str) -> Result<SourceMode, &'static str> {
+  SourceMode::from_str(value).map_err(|_| "replace or extend")
+}
+
+fn parse<T: FromStr>(value: &str) -> Result<T, T::Err> {
+  value.trim().parse::<T>()
+}
+
+fn overridable_names() -> String {
+  OVERRIDABLE_OPTIONS.join(", ")
+}
@@ -0,0 +1,172 @@
+use {
+  super::*,
+  crate::{
+    errors::UnsupportedConfigError,
+    rcfile::{LocalPathPolicy, SourceMode},
+  },
+};
+
+fn env_vars(vars: &[(&str, &str)]) -> impl Iterator<Item = (String, String)> {
+  vars
+    .iter()
+    .map(|(name, value)| (name.to_string(), value.to_string()))
+    .collect::<Vec<_>>()
+    .into_iter()
+}
+
+fn cli_override(raw: &str) -> ConfigOverride {
+  ConfigOverride::from_cli(raw).unwrap()
+}
+
+#[test]
+fn env_vars_are_matched_to_rcfile_properties() {
+  let overrides = ConfigOverride::from_env(env_vars(&[
+    ("SYNCPACK_STRICT", "true"),
+    ("PATH", "/usr/bin"),
+    ("SYNCPACK_MAX_CONCURRENT_REQUESTS", "4"),
+    ("SYNCPACK_UNKNOWN", "1"),
+  ]));
+  assert_eq!(
+    overrides,
+    vec![
+      ConfigOverride {
+        key: "maxConcurrentRequests".to_string(),
+        value: "4".to_string(),
+        origin: OverrideOrigin::Env("SYNCPACK_MAX_CONCURRENT_REQUESTS".to_string()),
+      },
+      ConfigOverride {
+        key: "strict".to_string(),
+        value: "true".to_string(),
+        origin: OverrideOrigin::Env("SYNCPACK_STRICT".to_string()),
+      },
+    ]
+  );
+}
+
+#[test]
+fn cli_value_must_contain_a_key_and_value() {
+  assert!(ConfigOverride::from_cli("strict").is_err());
+  assert!(ConfigOverride::from_cli("sortFirst=name").is_err());
+  assert_eq!(cli_override("indent=").value, "");
+}
+
+#[test]
+fn every_scalar_option_can_be_overridden() {
+  let mut rcfile = Rcfile::default();
+  rcfile
+    .apply_overrides(&[
+      cli_override("checkLockfile=true"),
+      cli_override("formatBugs=true"),
+      cli_override("formatRepository=true"),
+      cli_override("indent=\t"),
+      cli_override("localPathPolicy=workspace"),
+      cli_override("maxConcurrentRequests=2"),
+      cli_override("minimumReleaseAge=0"),
+      cli_override("respectGitignore=false"),
+      cli_override("sortPackages=false"),
+      cli_override("sourceMode=extend"),
+      cli_override("strict=true"),
+    ])
+    .unwrap();
+  assert!(rcfile.check_lockfile);
+  assert!(rcfile.format_bugs);
+  assert!(rcfile.format_repository);
+  assert_eq!(rcfile.indent.as_deref(), Some("\t"));
+  assert_eq!(rcfile.local_path_policy, LocalPathPolicy::Workspace);
+  assert_eq!(rcfile.max_concurrent_requests, 2);
+  assert_eq!(rcfile.minimum_release_age, 0);
+  assert!(!rcfile.respect_gitignore);
+  assert!(!rcfile.sort_packages);
+  assert_eq!(rcfile.source_mode, SourceMode::Extend);
+  assert!(rcfile.strict);
+}
+
+#[test]
+fn later_overrides_win() {
+  let mut rcfile = Rcfile::default();
+  let from_env = ConfigOverride::from_env(env_vars(&[("SYNCPACK_STRICT", "true")]));
+  let overrides: Vec<ConfigOverride> = from_env.into_iter().chain([cli_override("strict=false")]).collect();
+  rcfile.apply_overrides(&overrides).unwrap();
+  assert!(!rcfile.strict);
+}
+
+#[test]
+fn every_invalid_value_is_reported_with_its_origin() {
+  let mut rcfile = Rcfile::default();
+  let mut overrides = ConfigOverride::from_env(env_vars(&[("SYNCPACK_STRICT", "yes")]));
+  overrides.push(cli_override("maxConcurrentRequests=0"));
+  let errors = rcfile.apply_overrides(&overrides).unwrap_err().0;
+  assert_eq!(errors.len(), 2);
+  assert!(matches!(
+    &errors[0],
+    UnsupportedConfigError::InvalidOverride { key, origin, .. } if key == "strict" && origin == "SYNCPACK_STRICT"
+  ));
+  assert!(matches!(
+    &errors[1],
+    UnsupportedConfigError::InvalidOverride { key, origin, .. } if key == "maxConcurrentRequests" && origin == "--set"
+  ));
+}
+
+/// Destructure `Rcfile` without `..` so that a new field does not compile
+/// until it is listed as overridable or not, and return the overridable ones
+macro_rules! rcfile_fields {
+  (overridable: [$($scalar:ident),* $(,)?], other: [$($other:ident),* $(,)?] $(,)?) => {{
+    let Rcfile { $($scalar: _,)* $($other: _,)* } = Rcfile::default();
+    vec![$(stringify!($scalar)),*]
+  }};
+}
+
+#[test]
+fn every_scalar_field_of_rcfile_can_be_overridden() {
+  let scalars = rcfile_fields! {
+    overridable: [
+      check_lockfile,
+      format_bugs,
+      format_repository,
+      indent,
+      local_path_policy,
+      max_concurrent_requests,
+      minimum_release_age,
+      respect_gitignore,
+      sort_packages,
+      source_mode,
+      strict,
+    ],
+    other: [
+      all_dependency_types,
+      cross_root_version_groups,
+      dependency_groups,
+      nested_origins,
+      nested_version_groups,
+      selectors,
+      semver_groups,
+      sort_az,
+      sort_exports,
+      sort_first,
+      source,
+      update_groups,
+      version_groups,
+      workspace_roots,
+    ],
+  };
+  let keys: Vec<String> = scalars
+    .iter()
+    .map(|field| {
+      field
+        .split('_')
+        .enumerate()
+        .map(|(index, word)| match index {
+          0 => word.to_string(),
+          _ => word[..1].to_uppercase() + &word[1..],
+        })
+        .collect()
+    })
+    .collect();
+  assert_eq!(keys, OVERRIDABLE_OPTIONS);
+}
+
+#[test]
+fn env_names_are_derived_from_the_rcfile_property() {
+  assert_eq!(env_name("strict"), "SYNCPACK_STRICT");
+  assert_eq!(env_name("maxConcurrentRequests"), "SYNCPACK_MAX_CONCURRENT_REQUESTS");
+}
@@ -1,8 +1,12 @@
 use {
-  crate::{errors::UnsupportedConfigError, group_selector::GroupSelector, semver_range::SemverRange},
+  crate::{
+    errors::UnsupportedConfigError,
+    group_selector::{GroupSelector, named::SelectorExpr},
+    semver_range::SemverRange,
+  },
   serde::Deserialize,
   serde_json::Value,
-  std::collections::HashMap,
+  std::{collections::HashMap, sync::Arc},
 };
 
 #[derive(Debug)]
@@ -50,7 +54,8 @@ impl SemverGroup {
       /* label: */ group.label,
       /* include_packages: */ group.packages,
       /* include_specifier_types: */ group.specifier_types,
-    );
+    )
+    .with_named(group.named_selector);
 
     if let Some(true) = group.is_disabled {
       Ok(SemverGroup { selector, range: None })
@@ -80,6 +85,12 @@ pub struct AnySemverGroup {
   pub packages: Vec<String>,
   #[serde(default)]
   pub specifier_types: Vec<String>,
+  /// Name of a selector defined in the top-level `selectors` property
+  pub selector: Option<String>,
+  /// The compiled `selector`, filled in by `Rcfile` once every named
+  /// selector is known
+  #[serde(skip)]
+  pub named_selector: Option<Arc<SelectorExpr>>,
   //
   pub is_disabled: Option<bool>,
   pub is_ignored: Option<bool>,
@@ -1,8 +1,11 @@
 use {
-  crate::{errors::UnsupportedConfigError, group_selector::GroupSelector},
+  crate::{
+    errors::UnsupportedConfigError,
+    group_selector::{GroupSelector, named::SelectorExpr},
+  },
   serde::Deserialize,
   serde_json::Value,
-  std::collections::HashMap,
+  std::{collections::HashMap, sync::Arc},
   syncpack_specifier::update_target::UpdateTarget,
 };
 
@@ -27,7 +30,8 @@ impl UpdateGroup {
       /* label: */ group.label,
       /* include_packages: */ group.packages,
       /* include_specifier_types: */ group.specifier_types,
-    );
+    )
+    .with_named(group.named_selector);
     let policy = match (group.is_ignored, group.target.as_deref()) {
       (Some(true), None) => UpdatePolicy::Skip,
       (None | Some(false), Some("patch")) => UpdatePolicy::UpTo(UpdateTarget::Patch),
@@ -54,6 +58,12 @@ pub struct AnyUpdateGroup {
   pub packages: Vec<String>,
   #[serde(default)]
   pub specifier_types: Vec<String>,
+  /// Name of a selector defined in the top-level `selectors` property
+  pub selector: Option<String>,
+  /// The compiled `selector`, filled in by `Rcfile` once every named
+  /// selector is known
+  #[serde(skip)]
+  pub named_selector: Option<Arc<SelectorExpr>>,
   //
   pub is_ignored: Option<bool>,
   pub target: Option<String>,
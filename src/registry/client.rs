@@ -10,6 +10,7 @@ use {
   serde_json::Value,
   std::{
     collections::{BTreeMap, HashMap},
+    path::PathBuf,
     sync::Arc,
     time::Duration,
   },
@@ -27,6 +28,25 @@ pub enum RegistryError {
 
   #[error("HTTP error for package '{url}': {status}")]
   HttpError { url: String, status: StatusCode },
+
+  #[error("Failed to read Node.js release index '{path}': {source}")]
+  InvalidNodeReleaseIndex {
+    path: String,
+    #[source]
+    source: Box<dyn std::error::Error + Send + Sync>,
+  },
+}
+
+/// Every release of Node.js, used to update `node` in `engines` and `volta`
+pub const NODE_RELEASE_INDEX_URL: &str = "https://nodejs.org/dist/index.json";
+
+/// One entry of https://nodejs.org/dist/index.json
+#[derive(Deserialize, Debug)]
+struct NodeRelease {
+  /// Such as "v22.1.0"
+  version: String,
+  /// Such as "2024-05-02"
+  date: String,
 }
 
 /// Registry responses such as https://registry.npmjs.org/colors
@@ -65,11 +85,17 @@ pub trait RegistryClient: std::fmt::Debug + Send + Sync {
 #[derive(Debug)]
 pub struct LiveRegistryClient {
   pub client: Client,
+  /// Read the Node.js release index from this file instead of
+  /// `NODE_RELEASE_INDEX_URL`, for tests and offline mirrors
+  pub node_release_index: Option<PathBuf>,
 }
 
 #[async_trait::async_trait]
 impl RegistryClient for LiveRegistryClient {
   async fn fetch(&self, update_url: &UpdateUrl) -> Result<Arc<AllPackageVersions>, RegistryError> {
+    if update_url.url == NODE_RELEASE_INDEX_URL {
+      return self.fetch_node_releases(update_url).await;
+    }
     let req = self.client.get(&update_url.url).header(ACCEPT, "application/json");
     debug!("GET {update_url:?}");
     match req.send().await {
@@ -122,6 +148,69 @@ impl LiveRegistryClient {
         .timeout(Duration::from_secs(30))
         .build()
         .expect("Failed to build reqwest client"),
+      node_release_index: None,
     }
   }
+
+  pub fn with_node_release_index(mut self, filepath: Option<PathBuf>) -> Self {
+    self.node_release_index = filepath;
+    self
+  }
+
+  async fn fetch_node_releases(&self, update_url: &UpdateUrl) -> Result<Arc<AllPackageVersions>, RegistryError> {
+    let raw = match &self.node_release_index {
+      Some(filepath) => {
+        debug!("Reading Node.js release index from {filepath:?}");
+        std::fs::read_to_string(filepath).map_err(|err| RegistryError::InvalidNodeReleaseIndex {
+          path: filepath.to_string_lossy().to_string(),
+          source: Box::new(err),
+        })?
+      }
+      None => {
+        debug!("GET {update_url:?}");
+        let fetch_error = |err: reqwest::Error| RegistryError::FetchError {
+          url: update_url.url.to_string(),
+          source: Box::new(err),
+        };
+        let res = self.client.get(&update_url.url).send().await.map_err(fetch_error)?;
+        match res.status() {
+          StatusCode::OK => res.text().await.map_err(fetch_error)?,
+          status => {
+            return Err(RegistryError::HttpError {
+              url: update_url.url.to_string(),
+              status,
+            });
+          }
+        }
+      }
+    };
+    parse_node_release_index(&update_url.internal_name, &raw)
+      .map(Arc::new)
+      .map_err(|err| RegistryError::InvalidNodeReleaseIndex {
+        path: self
+          .node_release_index
+          .as_ref()
+          .map_or_else(|| update_url.url.to_string(), |filepath| filepath.to_string_lossy().to_string()),
+        source: Box::new(err),
+      })
+  }
+}
+
+/// Convert the Node.js release index into the same shape as a package on the
+/// npm registry. Releases are only dated, so each is treated as published at
+/// midnight UTC.
+pub(crate) fn parse_node_release_index(internal_name: &str, raw: &str) -> Result<AllPackageVersions, serde_json::Error> {
+  let releases: Vec<NodeRelease> = serde_json::from_str(raw)?;
+  let mut versions = vec![];
+  let mut times = HashMap::new();
+  for release in releases {
+    let version = release.version.trim_start_matches('v').to_string();
+    times.insert(version.clone(), format!("{}T00:00:00.000Z", release.date));
+    versions.push(version);
+  }
+  Ok(AllPackageVersions {
+    name: internal_name.to_string(),
+    versions,
+    times,
+  })
 }
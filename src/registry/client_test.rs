@@ -1,4 +1,11 @@
-use {crate::registry::client::PackageMeta, serde_json::json, std::collections::BTreeMap};
+use {
+  crate::{
+    dependency::UpdateUrl,
+    registry::client::{LiveRegistryClient, NODE_RELEASE_INDEX_URL, PackageMeta, RegistryClient, parse_node_release_index},
+  },
+  serde_json::json,
+  std::{collections::BTreeMap, path::PathBuf},
+};
 
 #[test]
 fn filters_out_deprecated_versions() {
@@ -56,3 +63,37 @@ fn includes_all_versions_when_none_deprecated() {
   assert!(versions.contains(&"2.0.0".to_string()));
   assert!(versions.contains(&"3.0.0".to_string()));
 }
+
+#[test]
+fn reads_versions_and_dates_from_node_release_index() {
+  let raw = r#"[
+    {"version": "v22.1.0", "date": "2024-05-02", "lts": false},
+    {"version": "v20.13.1", "date": "2024-05-09", "lts": "Iron"}
+  ]"#;
+  let releases = parse_node_release_index("node", raw).unwrap();
+  assert_eq!(releases.name, "node");
+  assert_eq!(releases.versions, vec!["22.1.0", "20.13.1"]);
+  assert_eq!(releases.times.get("20.13.1").map(String::as_str), Some("2024-05-09T00:00:00.000Z"));
+}
+
+#[tokio::test]
+async fn node_release_index_can_be_read_from_a_local_file() {
+  let filepath = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("fixtures/node-release-index/index.json");
+  let client = LiveRegistryClient::new().with_node_release_index(Some(filepath));
+  let update_url = UpdateUrl {
+    internal_name: "node".to_string(),
+    url: NODE_RELEASE_INDEX_URL.to_string(),
+  };
+  let releases = client.fetch(&update_url).await.unwrap();
+  assert_eq!(releases.versions, vec!["22.1.0", "22.0.0", "20.13.1"]);
+}
+
+#[tokio::test]
+async fn missing_node_release_index_file_is_an_error() {
+  let client = LiveRegistryClient::new().with_node_release_index(Some(PathBuf::from("/does/not/exist.json")));
+  let update_url = UpdateUrl {
+    internal_name: "node".to_string(),
+    url: NODE_RELEASE_INDEX_URL.to_string(),
+  };
+  assert!(client.fetch(&update_url).await.is_err());
+}
@@ -1,5 +1,5 @@
 use {
-  crate::errors::UnsupportedConfigError,
+  crate::{errors::UnsupportedConfigError, span::Span, variant_names::variant_names},
   serde::{Deserialize, Serialize},
   serde_json::Value,
 };
@@ -13,11 +13,14 @@ mod source_test;
 /// Embedded on `DependencyType` so iteration can pair sources × dep types.
 /// Default for user `customTypes` is `PackageJson`. Auto-generated catalog
 /// dep types set this explicitly (`pnpmCatalog*` → `PnpmWorkspace`,
-/// `bunCatalog*` → `PackageJson`).
+/// `yarnCatalog*` → `YarnRc`, `bunCatalog*` → `PackageJson`). The built-in
+/// `deno` dep type reads `DenoJson`.
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub enum SourceKind {
   PackageJson,
   PnpmWorkspace,
+  YarnRc,
+  DenoJson,
 }
 
 impl SourceKind {
@@ -28,6 +31,8 @@ impl SourceKind {
     match raw {
       "PackageJson" => Ok(SourceKind::PackageJson),
       "PnpmWorkspace" => Ok(SourceKind::PnpmWorkspace),
+      "YarnRc" => Ok(SourceKind::YarnRc),
+      "DenoJson" => Ok(SourceKind::DenoJson),
       other => Err(UnsupportedConfigError::InvalidSource { value: other.to_string() }),
     }
   }
@@ -40,35 +45,56 @@ pub struct FormatMismatch {
   pub expected: Value,
   /// The path to the property that was linted
   pub property_path: String,
+  /// Where the property is written in the package.json, when known
+  pub span: Option<Span>,
   /// The broken linting rule
   pub variant: FormatMismatchVariant,
 }
 
-#[derive(Clone, Debug, Eq, PartialEq, Hash)]
-pub enum FormatMismatchVariant {
-  /// - ✓ `rcFile.formatBugs` is enabled
-  /// - ✘ The `bugs` property is not formatted
-  BugsPropertyIsNotFormatted,
-  /// - ✓ `rcFile.formatRepository` is enabled
-  /// - ✘ The `repository` property is not formatted
-  RepositoryPropertyIsNotFormatted,
-  /// - ✓ `rcFile.sortAz` is enabled
-  /// - ✘ This property is not sorted alphabetically
-  PropertyIsNotSortedAz,
-  /// - ✓ `rcFile.sortPackages` is enabled
-  /// - ✘ This package.json's properties are not sorted
-  PackagePropertiesAreNotSorted,
-  /// - ✓ `rcFile.sortExports` is enabled
-  /// - ✘ The `exports` property is not sorted
-  ExportsPropertyIsNotSorted,
+variant_names! {
+  #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+  pub enum FormatMismatchVariant {
+    /// - ✓ `rcFile.formatBugs` is enabled
+    /// - ✘ The `bugs` property is not formatted
+    BugsPropertyIsNotFormatted,
+    /// - ✓ `rcFile.formatRepository` is enabled
+    /// - ✘ The `repository` property is not formatted
+    RepositoryPropertyIsNotFormatted,
+    /// - ✓ `rcFile.sortAz` is enabled
+    /// - ✘ This property is not sorted alphabetically
+    PropertyIsNotSortedAz,
+    /// - ✓ `rcFile.sortPackages` is enabled
+    /// - ✘ This package.json's properties are not sorted
+    PackagePropertiesAreNotSorted,
+    /// - ✓ `rcFile.sortExports` is enabled
+    /// - ✘ The `exports` property is not sorted
+    ExportsPropertyIsNotSorted,
+  }
+}
+
+impl FormatMismatchVariant {
+  /// The config option which enables this check, eg. `sortAz`
+  pub fn get_config_key(&self) -> &'static str {
+    match self {
+      FormatMismatchVariant::BugsPropertyIsNotFormatted => "formatBugs",
+      FormatMismatchVariant::RepositoryPropertyIsNotFormatted => "formatRepository",
+      FormatMismatchVariant::PropertyIsNotSortedAz => "sortAz",
+      FormatMismatchVariant::PackagePropertiesAreNotSorted => "sortPackages",
+      FormatMismatchVariant::ExportsPropertyIsNotSorted => "sortExports",
+    }
+  }
 }
 
 /// A file containing dependency declarations. Either a `package.json`
-/// (`Package`) or the workspace's `pnpm-workspace.yaml` (`PnpmYaml`, unit
-/// variant — yaml lives on `Disk.pnpm_workspace`). `Package` is a struct
+/// (`Package`), the workspace's `pnpm-workspace.yaml` (`PnpmYaml`, unit
+/// variant — yaml lives on `Disk.pnpm_workspace`), or the workspace's
+/// `.yarnrc.yml` (`YarnRc`, unit variant — yaml lives on `Disk.yarnrc`), or a
+/// Deno `deno.json` / `deno.jsonc` (`DenoJson`).
+/// `Package` is a struct
 /// variant carrying an index into `disk.package_json_files`, the cached
 /// package name, and any formatting mismatches detected by
-/// `visit_formatting`.
+/// `visit_formatting`. `DenoJson` carries an index into
+/// `disk.deno_json_files` and its cached name.
 #[derive(Debug)]
 pub enum Source {
   Package {
@@ -80,15 +106,24 @@ pub enum Source {
     formatting_mismatches: Vec<FormatMismatch>,
   },
   PnpmYaml,
+  YarnRc,
+  DenoJson {
+    /// Index into `disk.deno_json_files` for this config file.
+    file_idx: usize,
+    /// Cached `name` property value, or the file's path relative to the
+    /// workspace root when it has none.
+    name: String,
+  },
 }
 
 impl Source {
   /// A short label for this source. Returns the package's name for
-  /// `Package`, or the literal string `"pnpm-workspace.yaml"` for `PnpmYaml`.
+  /// `Package` and `DenoJson`, or the file name for `PnpmYaml` and `YarnRc`.
   pub fn name(&self) -> &str {
     match self {
-      Source::Package { name, .. } => name.as_str(),
+      Source::Package { name, .. } | Source::DenoJson { name, .. } => name.as_str(),
       Source::PnpmYaml => "pnpm-workspace.yaml",
+      Source::YarnRc => ".yarnrc.yml",
     }
   }
 
@@ -96,6 +131,8 @@ impl Source {
     match self {
       Source::Package { .. } => SourceKind::PackageJson,
       Source::PnpmYaml => SourceKind::PnpmWorkspace,
+      Source::YarnRc => SourceKind::YarnRc,
+      Source::DenoJson { .. } => SourceKind::DenoJson,
     }
   }
 }
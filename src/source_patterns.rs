@@ -1,5 +1,9 @@
 use {
-  crate::{context::Config, disk::Disk, rcfile::SourceMode},
+  crate::{
+    context::Config,
+    disk::{self, Disk, DiskIo},
+    rcfile::SourceMode,
+  },
   log::debug,
 };
 
@@ -7,22 +11,30 @@ use {
 #[path = "source_patterns_test.rs"]
 mod source_patterns_test;
 
-trait DebugNone {
+trait DebugOption {
   fn debug_none(self, msg: &str) -> Self;
+  fn debug_some(self, msg: &str) -> Self;
 }
 
-impl<T> DebugNone for Option<T> {
+impl<T> DebugOption for Option<T> {
   fn debug_none(self, msg: &str) -> Self {
     if self.is_none() {
       debug!("{msg}");
     }
     self
   }
+
+  fn debug_some(self, msg: &str) -> Self {
+    if self.is_some() {
+      debug!("{msg}");
+    }
+    self
+  }
 }
 
 /// Based on the user's config file and command line `--source` options, return
 /// the source glob patterns which should be used to resolve package.json files
-pub fn get_source_patterns(config: &Config, disk: &Disk) -> Vec<String> {
+pub fn get_source_patterns<D: DiskIo>(config: &Config, disk: &Disk, io: &D) -> Vec<String> {
   let source_mode = config.cli.source_mode.unwrap_or(config.rcfile.source_mode);
   let user_patterns = get_cli_patterns(config)
     .debug_none("No --source patterns provided")
@@ -30,11 +42,18 @@ pub fn get_source_patterns(config: &Config, disk: &Disk) -> Vec<String> {
     .debug_none("No .source patterns in rcfile")
     .map(normalise_patterns);
   let discovered = get_npm_and_yarn_patterns(disk)
+    .debug_some("Discovered packages from workspaces in package.json")
     .debug_none("No workspaces patterns in package.json")
-    .or_else(|| get_pnpm_patterns(disk))
+    .or_else(|| get_pnpm_patterns(disk).debug_some("Discovered packages from pnpm-workspace.yaml"))
     .debug_none("No packages in pnpm-workspace.yaml")
-    .or_else(|| get_lerna_patterns(disk))
+    .or_else(|| get_lerna_patterns(disk).debug_some("Discovered packages from lerna.json"))
     .debug_none("No packages in lerna.json")
+    .or_else(|| get_bolt_patterns(disk).debug_some("Discovered packages from bolt.workspaces in package.json"))
+    .debug_none("No bolt.workspaces patterns in package.json")
+    .or_else(|| get_rush_patterns(disk).debug_some("Discovered packages from rush.json"))
+    .debug_none("No projects in rush.json")
+    .or_else(|| get_nx_patterns(config, disk, io).debug_some("Discovered packages from Nx project roots"))
+    .debug_none("No Nx projects in workspace.json or project.json files")
     .map(append_root_package_json)
     .map(normalise_patterns);
 
@@ -101,6 +120,56 @@ fn get_lerna_patterns(disk: &Disk) -> Option<Vec<String>> {
     .map(|arr| arr.iter().filter_map(|v| v.as_str().map(String::from)).collect())
 }
 
+/// Look for source patterns in the `bolt.workspaces` property of the root
+/// `package.json`
+fn get_bolt_patterns(disk: &Disk) -> Option<Vec<String>> {
+  disk
+    .package_json_root()?
+    .contents
+    .pointer("/bolt/workspaces")
+    .and_then(|v| v.as_array())
+    .map(|arr| arr.iter().filter_map(|v| v.as_str().map(String::from)).collect())
+}
+
+/// Look for the `projectFolder` of every project in the `rush.json` file
+fn get_rush_patterns(disk: &Disk) -> Option<Vec<String>> {
+  disk
+    .rush_json
+    .as_ref()?
+    .contents
+    .get("projects")
+    .and_then(|v| v.as_array())
+    .map(|arr| {
+      arr
+        .iter()
+        .filter_map(|project| project.get("projectFolder")?.as_str().map(String::from))
+        .collect()
+    })
+}
+
+/// Use the root directory of every Nx project, from the `projects` of
+/// `workspace.json` as a string or as the `root` of an object, or else from
+/// the location of every `project.json` when there is an `nx.json`
+fn get_nx_patterns<D: DiskIo>(config: &Config, disk: &Disk, io: &D) -> Option<Vec<String>> {
+  let roots: Vec<String> = match &disk.nx_workspace_json {
+    Some(file) => file
+      .contents
+      .get("projects")
+      .and_then(|v| v.as_object())
+      .map(|projects| {
+        projects
+          .values()
+          .filter_map(|project| project.as_str().or_else(|| project.get("root")?.as_str()))
+          .map(String::from)
+          .collect()
+      })
+      .unwrap_or_default(),
+    None if disk.has_nx_json => disk::find_nx_project_roots(io, &disk.cwd, config.rcfile.respect_gitignore),
+    None => return None,
+  };
+  (!roots.is_empty()).then_some(roots)
+}
+
 /// Default source patterns to use if no other source patterns are found
 fn get_default_patterns() -> Vec<String> {
   vec![String::from("/package.json"), String::from("packages/*/package.json")]
@@ -69,7 +69,8 @@ mod source_mode {
       context::Config,
       disk::{Disk, File, detect_formatting},
       rcfile::{RawRcfile, Rcfile, SourceMode},
-      test::mock,
+      span::SpanIndex,
+      test::{mock, mock_disk::MockDiskIo},
     },
     serde_json::{Value, json},
     std::path::PathBuf,
@@ -83,6 +84,12 @@ mod source_mode {
       package_json_root_idx: None,
       package_manager: None,
       pnpm_workspace: None,
+      yarnrc: None,
+      deno_json_files: Vec::new(),
+      lockfile: None,
+      rush_json: None,
+      nx_workspace_json: None,
+      has_nx_json: false,
     }
   }
 
@@ -93,6 +100,8 @@ mod source_mode {
       formatting: detect_formatting(&raw),
       contents: root,
       dirty: false,
+      spans: SpanIndex::default(),
+      raw: None,
     };
     Disk {
       cwd: PathBuf::from("/test"),
@@ -101,6 +110,12 @@ mod source_mode {
       package_json_root_idx: Some(0),
       package_manager: None,
       pnpm_workspace: None,
+      yarnrc: None,
+      deno_json_files: Vec::new(),
+      lockfile: None,
+      rush_json: None,
+      nx_workspace_json: None,
+      has_nx_json: false,
     }
   }
 
@@ -126,6 +141,8 @@ mod source_mode {
       formatting: detect_formatting(&raw),
       contents,
       dirty: false,
+      spans: SpanIndex::default(),
+      raw: None,
     };
     let mut disk = empty_disk();
     disk.lerna_json = Some(file);
@@ -147,14 +164,20 @@ mod source_mode {
   fn replace_with_cli_returns_only_cli() {
     let config = config_with(json!({}), &["custom/*"], None);
     let disk = disk_with_npm_workspaces(&["packages/*"]);
-    assert_eq!(get_source_patterns(&config, &disk), vec!["custom/*/package.json"]);
+    assert_eq!(
+      get_source_patterns(&config, &disk, &MockDiskIo::new()),
+      vec!["custom/*/package.json"]
+    );
   }
 
   #[test]
   fn replace_with_rcfile_source_only_returns_rcfile() {
     let config = config_with(json!({ "source": ["custom/*"] }), &[], None);
     let disk = disk_with_npm_workspaces(&["packages/*"]);
-    assert_eq!(get_source_patterns(&config, &disk), vec!["custom/*/package.json"]);
+    assert_eq!(
+      get_source_patterns(&config, &disk, &MockDiskIo::new()),
+      vec!["custom/*/package.json"]
+    );
   }
 
   #[test]
@@ -162,7 +185,7 @@ mod source_mode {
     let config = config_with(json!({}), &[], None);
     let disk = disk_with_npm_workspaces(&["packages/*"]);
     assert_eq!(
-      get_source_patterns(&config, &disk),
+      get_source_patterns(&config, &disk, &MockDiskIo::new()),
       vec!["packages/*/package.json", "/package.json"],
     );
   }
@@ -174,7 +197,7 @@ mod source_mode {
     let config = config_with(json!({ "sourceMode": "extend", "source": ["custom/extra"] }), &[], None);
     let disk = disk_with_npm_workspaces(&["packages/*"]);
     assert_eq!(
-      get_source_patterns(&config, &disk),
+      get_source_patterns(&config, &disk, &MockDiskIo::new()),
       vec!["packages/*/package.json", "/package.json", "custom/extra/package.json"],
     );
   }
@@ -184,7 +207,7 @@ mod source_mode {
     let config = config_with(json!({ "sourceMode": "extend", "source": ["custom/extra"] }), &[], None);
     let disk = disk_with_pnpm(&["packages/*"]);
     assert_eq!(
-      get_source_patterns(&config, &disk),
+      get_source_patterns(&config, &disk, &MockDiskIo::new()),
       vec!["packages/*/package.json", "/package.json", "custom/extra/package.json"],
     );
   }
@@ -194,7 +217,7 @@ mod source_mode {
     let config = config_with(json!({ "sourceMode": "extend", "source": ["custom/extra"] }), &[], None);
     let disk = disk_with_lerna(&["legacy/*"]);
     assert_eq!(
-      get_source_patterns(&config, &disk),
+      get_source_patterns(&config, &disk, &MockDiskIo::new()),
       vec!["legacy/*/package.json", "/package.json", "custom/extra/package.json"],
     );
   }
@@ -204,7 +227,7 @@ mod source_mode {
     let config = config_with(json!({ "sourceMode": "extend", "source": ["from-rcfile"] }), &["from-cli"], None);
     let disk = disk_with_npm_workspaces(&["packages/*"]);
     assert_eq!(
-      get_source_patterns(&config, &disk),
+      get_source_patterns(&config, &disk, &MockDiskIo::new()),
       vec!["packages/*/package.json", "/package.json", "from-cli/package.json"],
     );
   }
@@ -214,7 +237,7 @@ mod source_mode {
     let config = config_with(json!({ "sourceMode": "extend" }), &[], None);
     let disk = disk_with_npm_workspaces(&["packages/*"]);
     assert_eq!(
-      get_source_patterns(&config, &disk),
+      get_source_patterns(&config, &disk, &MockDiskIo::new()),
       vec!["packages/*/package.json", "/package.json"],
     );
   }
@@ -223,7 +246,10 @@ mod source_mode {
   fn extend_with_user_patterns_but_no_discovery_returns_only_user() {
     let config = config_with(json!({ "sourceMode": "extend", "source": ["custom/extra"] }), &[], None);
     let disk = empty_disk();
-    assert_eq!(get_source_patterns(&config, &disk), vec!["custom/extra/package.json"]);
+    assert_eq!(
+      get_source_patterns(&config, &disk, &MockDiskIo::new()),
+      vec!["custom/extra/package.json"]
+    );
   }
 
   #[test]
@@ -231,7 +257,7 @@ mod source_mode {
     let config = config_with(json!({ "sourceMode": "extend" }), &[], None);
     let disk = empty_disk();
     assert_eq!(
-      get_source_patterns(&config, &disk),
+      get_source_patterns(&config, &disk, &MockDiskIo::new()),
       vec!["/package.json", "packages/*/package.json"],
     );
   }
@@ -247,7 +273,7 @@ mod source_mode {
     );
     let disk = disk_with_npm_workspaces(&["packages/*"]);
     assert_eq!(
-      get_source_patterns(&config, &disk),
+      get_source_patterns(&config, &disk, &MockDiskIo::new()),
       vec!["packages/*/package.json", "/package.json", "from-cli/package.json"],
     );
   }
@@ -260,6 +286,99 @@ mod source_mode {
       Some(SourceMode::Replace),
     );
     let disk = disk_with_npm_workspaces(&["packages/*"]);
-    assert_eq!(get_source_patterns(&config, &disk), vec!["from-cli/package.json"]);
+    assert_eq!(
+      get_source_patterns(&config, &disk, &MockDiskIo::new()),
+      vec!["from-cli/package.json"]
+    );
+  }
+
+  // ----- discovery mechanisms after lerna.json -------------------------------
+
+  #[test]
+  fn discovers_bolt_workspaces() {
+    let config = config_with(json!({}), &[], None);
+    let disk = disk_with_root(json!({ "name": "root", "bolt": { "workspaces": ["packages/*"] } }));
+    assert_eq!(
+      get_source_patterns(&config, &disk, &MockDiskIo::new()),
+      vec!["packages/*/package.json", "/package.json"],
+    );
+  }
+
+  #[test]
+  fn discovers_rush_project_folders() {
+    let config = config_with(json!({}), &[], None);
+    let contents = json!({
+      "projects": [
+        { "packageName": "app", "projectFolder": "apps/app" },
+        { "packageName": "lib", "projectFolder": "libs/lib" }
+      ]
+    });
+    let mut disk = empty_disk();
+    disk.rush_json = Some(File {
+      filepath: PathBuf::from("/test/rush.json"),
+      formatting: detect_formatting(""),
+      contents,
+      dirty: false,
+      spans: SpanIndex::default(),
+      raw: None,
+    });
+    assert_eq!(
+      get_source_patterns(&config, &disk, &MockDiskIo::new()),
+      vec!["apps/app/package.json", "libs/lib/package.json", "/package.json"],
+    );
+  }
+
+  #[test]
+  fn discovers_nx_project_roots_from_workspace_json() {
+    let config = config_with(json!({}), &[], None);
+    let mut disk = empty_disk();
+    let contents = json!({ "projects": { "web": "apps/web", "ui": { "root": "libs/ui" } } });
+    let raw = serde_json::to_string_pretty(&contents).unwrap();
+    disk.nx_workspace_json = Some(File {
+      filepath: PathBuf::from("/test/workspace.json"),
+      formatting: detect_formatting(&raw),
+      contents,
+      dirty: false,
+      spans: SpanIndex::default(),
+      raw: None,
+    });
+    assert_eq!(
+      get_source_patterns(&config, &disk, &MockDiskIo::new()),
+      vec!["apps/web/package.json", "libs/ui/package.json", "/package.json"],
+    );
+  }
+
+  #[test]
+  fn discovers_nx_project_roots_from_project_json_files() {
+    let mut io = MockDiskIo::new();
+    io.add_file("apps/web/project.json", "{}".to_string());
+    io.add_file("libs/ui/project.json", "{}".to_string());
+    io.add_file(".gitignore", "libs/ui\n".to_string());
+    let mut disk = empty_disk();
+    disk.cwd = io.root().to_path_buf();
+    disk.has_nx_json = true;
+    let config = config_with(json!({}), &[], None);
+    assert_eq!(
+      get_source_patterns(&config, &disk, &io),
+      vec!["apps/web/package.json", "/package.json"],
+    );
+    let config = config_with(json!({ "respectGitignore": false }), &[], None);
+    assert_eq!(
+      get_source_patterns(&config, &disk, &io),
+      vec!["apps/web/package.json", "libs/ui/package.json", "/package.json"],
+    );
+  }
+
+  #[test]
+  fn workspaces_take_precedence_over_other_mechanisms() {
+    let config = config_with(json!({}), &[], None);
+    let mut disk = disk_with_root(json!({ "name": "root", "workspaces": ["packages/*"], "bolt": { "workspaces": ["bolt/*"] } }));
+    disk.has_nx_json = true;
+    let mut io = MockDiskIo::new();
+    io.add_file("apps/web/project.json", "{}".to_string());
+    assert_eq!(
+      get_source_patterns(&config, &disk, &io),
+      vec!["packages/*/package.json", "/package.json"],
+    );
   }
 }
@@ -7,6 +7,8 @@ use crate::{
 fn source_kind_parses_pascal_case() {
   assert!(matches!(SourceKind::parse("PackageJson"), Ok(SourceKind::PackageJson)));
   assert!(matches!(SourceKind::parse("PnpmWorkspace"), Ok(SourceKind::PnpmWorkspace)));
+  assert!(matches!(SourceKind::parse("YarnRc"), Ok(SourceKind::YarnRc)));
+  assert!(matches!(SourceKind::parse("DenoJson"), Ok(SourceKind::DenoJson)));
   match SourceKind::parse("InvalidValue") {
     Err(UnsupportedConfigError::InvalidSource { value }) => assert_eq!(value, "InvalidValue"),
     other => panic!("expected InvalidSource error, got {other:?}"),
@@ -24,6 +26,17 @@ fn source_kind_returns_correct_variant() {
 
   let yaml_source = Source::PnpmYaml;
   assert_eq!(yaml_source.kind(), SourceKind::PnpmWorkspace);
+
+  let yarnrc_source = Source::YarnRc;
+  assert_eq!(yarnrc_source.kind(), SourceKind::YarnRc);
+  assert_eq!(yarnrc_source.name(), ".yarnrc.yml");
+
+  let deno_source = Source::DenoJson {
+    file_idx: 0,
+    name: "@scope/deno-pkg".to_string(),
+  };
+  assert_eq!(deno_source.kind(), SourceKind::DenoJson);
+  assert_eq!(deno_source.name(), "@scope/deno-pkg");
 }
 
 #[test]
@@ -32,6 +45,8 @@ fn source_pnpm_yaml_is_unit_variant() {
   match s {
     Source::Package { .. } => panic!("not a package"),
     Source::PnpmYaml => {}
+    Source::YarnRc => panic!("not yarnrc"),
+    Source::DenoJson { .. } => panic!("not deno.json"),
   }
 }
 
@@ -52,7 +67,7 @@ fn source_package_is_struct_variant() {
       assert_eq!(name, "pkg");
       assert!(formatting_mismatches.is_empty());
     }
-    Source::PnpmYaml => panic!("not yaml"),
+    Source::PnpmYaml | Source::YarnRc | Source::DenoJson { .. } => panic!("not a package"),
   }
   assert_eq!(s.name(), "pkg");
 }
@@ -1,9 +1,13 @@
 use {
   crate::{
-    dependency::{DependencyType, Strategy},
+    dependency::{
+      DependencyType, Strategy,
+      override_path::{OverrideEntry, read_override_tree, read_resolution},
+    },
     disk::{Disk, json_view, package_name},
     instance::InstanceDescriptor,
     source::{Source, SourceKind},
+    span::SpanIndex,
     specifier::Specifier,
   },
   serde_json::Value,
@@ -18,7 +22,8 @@ mod sources_test;
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct SourceIdx(pub usize);
 
-/// Owned arena of every `Source` (package.json + pnpm yaml) in the project.
+/// Owned arena of every `Source` (package.json + pnpm yaml + .yarnrc.yml +
+/// deno.json) in the project.
 ///
 /// `Source::Package` carries an index into `disk.package_json_files`;
 /// reads/writes route through the Disk. `user_source_indices` lists the slots
@@ -38,6 +43,9 @@ pub struct Sources {
   /// parse-time yaml. `None` if no yaml exists at parse time. Auto-created
   /// yaml at fix-time is invisible to sources — it lives only on Disk.
   pub pnpm_yaml_source_idx: Option<usize>,
+  /// Slot in `all` holding the `Source::YarnRc` unit variant, with the same
+  /// parse-time-only rules as `pnpm_yaml_source_idx`.
+  pub yarnrc_source_idx: Option<usize>,
 }
 
 impl Sources {
@@ -46,6 +54,7 @@ impl Sources {
       all: vec![],
       user_source_indices: vec![],
       pnpm_yaml_source_idx: None,
+      yarnrc_source_idx: None,
     }
   }
 
@@ -53,10 +62,11 @@ impl Sources {
   /// Each file becomes one `Source::Package { file_idx, name,
   /// formatting_mismatches: [] }`. `user_source_indices` is computed by
   /// matching each file's `filepath` against the user-pattern-filtered
-  /// `file_paths`. Appends `Source::PnpmYaml` at the tail when
-  /// `disk.pnpm_workspace` is `Some`.
+  /// `file_paths`. Appends `Source::PnpmYaml` and then `Source::YarnRc` at
+  /// the tail when `disk.pnpm_workspace` / `disk.yarnrc` are `Some`, then one
+  /// `Source::DenoJson` per file in `disk.deno_json_files`.
   pub fn from_disk(disk: &Disk, file_paths: &[PathBuf]) -> Self {
-    let mut all: Vec<Source> = Vec::with_capacity(disk.package_json_files.len() + 1);
+    let mut all: Vec<Source> = Vec::with_capacity(disk.package_json_files.len() + 2);
     let mut user_source_indices: Vec<usize> = Vec::new();
     for (file_idx, file) in disk.package_json_files.iter().enumerate() {
       let name = package_name(file).to_string();
@@ -74,10 +84,32 @@ impl Sources {
       pnpm_yaml_source_idx = Some(all.len());
       all.push(Source::PnpmYaml);
     }
+    let mut yarnrc_source_idx = None;
+    if disk.yarnrc.is_some() {
+      yarnrc_source_idx = Some(all.len());
+      all.push(Source::YarnRc);
+    }
+    for (file_idx, file) in disk.deno_json_files.iter().enumerate() {
+      let name = file
+        .contents
+        .get("name")
+        .and_then(|name| name.as_str())
+        .map(String::from)
+        .unwrap_or_else(|| {
+          file
+            .filepath
+            .strip_prefix(&disk.cwd)
+            .unwrap_or(&file.filepath)
+            .to_string_lossy()
+            .to_string()
+        });
+      all.push(Source::DenoJson { file_idx, name });
+    }
     Self {
       all,
       user_source_indices,
       pnpm_yaml_source_idx,
+      yarnrc_source_idx,
     }
   }
 
@@ -89,12 +121,13 @@ impl Sources {
     idx
   }
 
-  /// Iterate the package.json sources only. Pnpm yaml is excluded.
+  /// Iterate the package.json sources only. Pnpm yaml, .yarnrc.yml and
+  /// deno.json are excluded.
   /// Used by `format`, `find_package`, name lookups.
   pub fn packages_iter(&self) -> impl Iterator<Item = (SourceIdx, &Source)> {
     self.all.iter().enumerate().filter_map(|(i, s)| match s {
       Source::Package { .. } => Some((SourceIdx(i), s)),
-      Source::PnpmYaml => None,
+      Source::PnpmYaml | Source::YarnRc | Source::DenoJson { .. } => None,
     })
   }
 
@@ -108,18 +141,24 @@ impl Sources {
     self.pnpm_yaml_source_idx.map(SourceIdx)
   }
 
+  /// Slot in `all` holding the parse-time YarnRc entry, if any.
+  pub fn find_yarnrc_idx(&self) -> Option<SourceIdx> {
+    self.yarnrc_source_idx.map(SourceIdx)
+  }
+
   /// Two-pass iteration: catalog dep types iterate the canonical source
   /// regardless of user pattern membership; non-catalog dep types iterate
   /// only `user_source_indices` (yaml is always included for PnpmWorkspace
-  /// dep types when yaml exists).
+  /// and YarnRc dep types when that yaml exists).
   ///
   /// Pass 1 (catalog dep types): walk `dep_types` where
   /// `is_catalog_definition`. PackageJson catalogs read from
   /// `disk.package_json_root_idx`'s slot; PnpmWorkspace catalogs read from
-  /// the pnpm yaml.
+  /// the pnpm yaml; YarnRc catalogs read from `.yarnrc.yml`.
   ///
   /// Pass 2 (non-catalog dep types): walk `user_source_indices` for
-  /// PackageJson dep types; walk yaml for PnpmWorkspace dep types.
+  /// PackageJson dep types; walk the matching yaml for PnpmWorkspace and
+  /// YarnRc dep types; walk every deno.json for DenoJson dep types.
   pub fn iter_instances<'a>(&'a self, disk: &'a Disk, dep_types: &'a [DependencyType]) -> impl Iterator<Item = InstanceDescriptor> + 'a {
     // Local package names are derived from package.json sources only — the
     // yaml's synthetic "name" must not poison `is_local_dependency`.
@@ -128,12 +167,22 @@ impl Sources {
       .iter()
       .filter_map(|s| match s {
         Source::Package { name, .. } => Some(name.clone()),
-        Source::PnpmYaml => None,
+        Source::PnpmYaml | Source::YarnRc | Source::DenoJson { .. } => None,
       })
       .collect();
 
-    // ONE yaml→json conversion per call, owned, borrowed by both passes.
+    // ONE yaml→json conversion per file per call, owned, borrowed by both
+    // passes.
     let yaml_json: Option<Value> = disk.pnpm_workspace.as_ref().map(json_view);
+    let yarnrc_json: Option<Value> = disk.yarnrc.as_ref().map(json_view);
+    let yaml_spans: SpanIndex = match (disk.pnpm_workspace.as_ref(), yaml_json.as_ref()) {
+      (Some(yaml), Some(json)) => SpanIndex::from_yaml(&yaml.raw, json),
+      _ => SpanIndex::default(),
+    };
+    let yarnrc_spans: SpanIndex = match (disk.yarnrc.as_ref(), yarnrc_json.as_ref()) {
+      (Some(yaml), Some(json)) => SpanIndex::from_yaml(&yaml.raw, json),
+      _ => SpanIndex::default(),
+    };
 
     // Wrap each dep type in an Rc once. Descriptors built within this call
     // share the Rc allocation; the rcfile's `Vec<DependencyType>` is never
@@ -157,6 +206,7 @@ impl Sources {
             collect_descriptors_for_dep_type(
               dep_type_rc,
               &root_file.contents,
+              &root_file.spans,
               SourceIdx(source_idx),
               &local_package_names,
               &mut out,
@@ -165,14 +215,28 @@ impl Sources {
         }
         SourceKind::PnpmWorkspace => {
           if let (Some(yaml), Some(yaml_idx)) = (yaml_json.as_ref(), self.pnpm_yaml_source_idx) {
-            collect_descriptors_for_dep_type(dep_type_rc, yaml, SourceIdx(yaml_idx), &local_package_names, &mut out);
+            collect_descriptors_for_dep_type(dep_type_rc, yaml, &yaml_spans, SourceIdx(yaml_idx), &local_package_names, &mut out);
           }
         }
+        SourceKind::YarnRc => {
+          if let (Some(yaml), Some(yaml_idx)) = (yarnrc_json.as_ref(), self.yarnrc_source_idx) {
+            collect_descriptors_for_dep_type(
+              dep_type_rc,
+              yaml,
+              &yarnrc_spans,
+              SourceIdx(yaml_idx),
+              &local_package_names,
+              &mut out,
+            );
+          }
+        }
+        // Deno has no catalogs.
+        SourceKind::DenoJson => {}
       }
     }
 
     // Pass 2: non-catalog dep types iterate user_source_indices (or yaml
-    // when source is PnpmWorkspace).
+    // when source is PnpmWorkspace or YarnRc).
     for dep_type_rc in dep_types_rc.iter().filter(|d| !d.is_catalog_definition) {
       match dep_type_rc.source {
         SourceKind::PackageJson => {
@@ -182,12 +246,45 @@ impl Sources {
               continue;
             }
             let file = &disk.package_json_files[*file_idx];
-            collect_descriptors_for_dep_type(dep_type_rc, &file.contents, SourceIdx(source_idx), &local_package_names, &mut out);
+            collect_descriptors_for_dep_type(
+              dep_type_rc,
+              &file.contents,
+              &file.spans,
+              SourceIdx(source_idx),
+              &local_package_names,
+              &mut out,
+            );
           }
         }
         SourceKind::PnpmWorkspace => {
           if let (Some(yaml), Some(yaml_idx)) = (yaml_json.as_ref(), self.pnpm_yaml_source_idx) {
-            collect_descriptors_for_dep_type(dep_type_rc, yaml, SourceIdx(yaml_idx), &local_package_names, &mut out);
+            collect_descriptors_for_dep_type(dep_type_rc, yaml, &yaml_spans, SourceIdx(yaml_idx), &local_package_names, &mut out);
+          }
+        }
+        SourceKind::YarnRc => {
+          if let (Some(yaml), Some(yaml_idx)) = (yarnrc_json.as_ref(), self.yarnrc_source_idx) {
+            collect_descriptors_for_dep_type(
+              dep_type_rc,
+              yaml,
+              &yarnrc_spans,
+              SourceIdx(yaml_idx),
+              &local_package_names,
+              &mut out,
+            );
+          }
+        }
+        SourceKind::DenoJson => {
+          for (source_idx, source) in self.all.iter().enumerate() {
+            let Source::DenoJson { file_idx, .. } = source else { continue };
+            let file = &disk.deno_json_files[*file_idx];
+            collect_descriptors_for_dep_type(
+              dep_type_rc,
+              &file.contents,
+              &file.spans,
+              SourceIdx(source_idx),
+              &local_package_names,
+              &mut out,
+            );
           }
         }
       }
@@ -204,7 +301,7 @@ impl Default for Sources {
 }
 
 /// Extract the catalog name suffix from a built-in catalog dep type name.
-/// Bare `pnpmCatalog` / `bunCatalog` → `"default"`. Suffixed
+/// Bare `pnpmCatalog` / `yarnCatalog` / `bunCatalog` → `"default"`. Suffixed
 /// `pnpmCatalog:react18` → `"react18"`. Dots in the suffix are preserved
 /// because dep type names are field-literal constructed. Returns a borrowed
 /// `&str` (no allocation).
@@ -228,15 +325,50 @@ fn build_descriptor(
     internal_name: name.to_string(),
     is_local_dependency: local_package_names.contains(name),
     name: name.to_string(),
+    override_path: None,
     source_idx,
+    span: None,
     specifier: Specifier::new(raw_specifier),
   }
 }
 
+/// Overrides are named after the dependency at the end of their path, so
+/// they are grouped with direct dependencies of the same name.
+fn build_override_descriptor(
+  dependency_type: &Rc<DependencyType>,
+  entry: OverrideEntry,
+  source_idx: SourceIdx,
+  local_package_names: &HashSet<String>,
+) -> InstanceDescriptor {
+  InstanceDescriptor {
+    override_path: entry.override_path,
+    ..build_descriptor(dependency_type, &entry.name, &entry.raw_specifier, source_idx, local_package_names)
+  }
+}
+
 /// Drive a single dep type against a single source's contents and append
 /// every emitted descriptor onto `out`. Reads `contents` via JSON pointers
-/// per the dep type's strategy.
+/// per the dep type's strategy, then looks up where each specifier is written
+/// in `spans`.
 fn collect_descriptors_for_dep_type(
+  dep_type: &Rc<DependencyType>,
+  contents: &Value,
+  spans: &SpanIndex,
+  source_idx: SourceIdx,
+  local_package_names: &HashSet<String>,
+  out: &mut Vec<InstanceDescriptor>,
+) {
+  let first_new = out.len();
+  read_descriptors_for_dep_type(dep_type, contents, source_idx, local_package_names, out);
+  for descriptor in &mut out[first_new..] {
+    // A local package without a version points at its name instead
+    descriptor.span = spans
+      .get(&descriptor.get_pointer())
+      .or_else(|| dep_type.name_path.as_deref().and_then(|name_path| spans.get(name_path)));
+  }
+}
+
+fn read_descriptors_for_dep_type(
   dep_type: &Rc<DependencyType>,
   contents: &Value,
   source_idx: SourceIdx,
@@ -278,7 +410,37 @@ fn collect_descriptors_for_dep_type(
     Strategy::VersionsByName => {
       if let Some(Value::Object(versions_by_name)) = contents.pointer(&dep_type.path) {
         for (name, raw_specifier) in versions_by_name {
+          if let Value::String(raw_specifier) = raw_specifier
+            && !is_volta_extends(&dep_type.path, name)
+          {
+            out.push(build_descriptor(dep_type, name, raw_specifier, source_idx, local_package_names));
+          }
+        }
+      }
+    }
+    Strategy::OverrideTree => {
+      if let Some(Value::Object(overrides)) = contents.pointer(&dep_type.path) {
+        for entry in read_override_tree(overrides) {
+          out.push(build_override_descriptor(dep_type, entry, source_idx, local_package_names));
+        }
+      }
+    }
+    Strategy::ResolutionPaths => {
+      if let Some(Value::Object(resolutions)) = contents.pointer(&dep_type.path) {
+        for (key, raw_specifier) in resolutions {
           if let Value::String(raw_specifier) = raw_specifier {
+            let entry = read_resolution(key, raw_specifier);
+            out.push(build_override_descriptor(dep_type, entry, source_idx, local_package_names));
+          }
+        }
+      }
+    }
+    Strategy::ImportMap => {
+      if let Some(Value::Object(imports)) = contents.pointer(&dep_type.path) {
+        for (name, raw_specifier) in imports {
+          if let Value::String(raw_specifier) = raw_specifier
+            && is_registry_import(raw_specifier)
+          {
             out.push(build_descriptor(dep_type, name, raw_specifier, source_idx, local_package_names));
           }
         }
@@ -289,3 +451,19 @@ fn collect_descriptors_for_dep_type(
     }
   }
 }
+
+/// Volta's `extends` is the path to another package.json to inherit tool
+/// versions from, rather than the version of a tool.
+fn is_volta_extends(path: &str, name: &str) -> bool {
+  path == "/volta" && name == "extends"
+}
+
+/// Does an import map entry point to a package on npm or JSR? Entries which
+/// map to URLs or local paths, and prefix mappings such as `"@std/": "jsr:/@std/"`,
+/// are not dependencies syncpack can manage.
+fn is_registry_import(raw_specifier: &str) -> bool {
+  raw_specifier
+    .strip_prefix("npm:")
+    .or_else(|| raw_specifier.strip_prefix("jsr:"))
+    .is_some_and(|package| !package.is_empty() && !package.starts_with('/') && !package.ends_with('/'))
+}
@@ -4,6 +4,7 @@ use {
     disk::{Disk, File, detect_formatting, parse_yaml_file},
     source::SourceKind,
     sources::{Sources, parse_catalog_name},
+    span::SpanIndex,
   },
   serde_json::json,
   std::path::PathBuf,
@@ -71,6 +72,8 @@ fn disk_with_packages(values: &[serde_json::Value], yaml: Option<&str>) -> (Disk
       formatting: detect_formatting(&raw),
       contents: value.clone(),
       dirty: false,
+      spans: SpanIndex::from_json(&raw),
+      raw: None,
     });
     all_paths.push(filepath);
   }
@@ -82,6 +85,12 @@ fn disk_with_packages(values: &[serde_json::Value], yaml: Option<&str>) -> (Disk
     package_json_root_idx: None,
     package_manager: None,
     pnpm_workspace,
+    yarnrc: None,
+    deno_json_files: Vec::new(),
+    lockfile: None,
+    rush_json: None,
+    nx_workspace_json: None,
+    has_nx_json: false,
   };
   (disk, all_paths)
 }
@@ -264,6 +273,37 @@ fn iter_pkg_json_dep_type_emits_package_variant() {
   assert!(!descriptors[0].dependency_type.is_catalog_definition);
 }
 
+#[test]
+fn iter_records_where_each_version_is_written() {
+  let (disk, file_paths) = disk_with_packages(
+    &[json!({
+      "name": "a",
+      "version": "1.0.0",
+      "dependencies": { "react": "^18.0.0" }
+    })],
+    Some("catalog:\n  vue: ^3.0.0\n"),
+  );
+  let sources = Sources::from_disk(&disk, &file_paths);
+  let dep_types = vec![
+    pkg_json_dep_type("prod", "/dependencies", Strategy::VersionsByName),
+    name_version_props_dep_type("local", "/name", "/version"),
+    auto_gen_pnpm_catalog_dep_type("pnpmCatalog", "/catalog"),
+  ];
+  let mut got: Vec<(String, Option<(usize, usize)>)> = sources
+    .iter_instances(&disk, &dep_types)
+    .map(|d| (d.name, d.span.map(|span| (span.value.line, span.value.column))))
+    .collect();
+  got.sort();
+  assert_eq!(
+    got,
+    vec![
+      ("a".to_string(), Some((3, 14))),
+      ("react".to_string(), Some((5, 14))),
+      ("vue".to_string(), Some((2, 8))),
+    ]
+  );
+}
+
 #[test]
 fn sources_from_disk_mirrors_package_json_files_one_to_one() {
   let (disk, all_paths) = disk_with_packages(&[json!({"name": "a"}), json!({"name": "b"})], None);
@@ -0,0 +1,310 @@
+use {
+  serde::Serialize,
+  serde_json::Value,
+  std::{collections::HashMap, ops::Range},
+  yamlpath::{Component, Document, Route},
+};
+
+#[cfg(test)]
+#[path = "span_test.rs"]
+mod span_test;
+
+/// A 1-based line and column in a source file. Columns count characters,
+/// not bytes.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+pub struct Position {
+  pub line: usize,
+  pub column: usize,
+}
+
+/// Where a property is written in a source file.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Span {
+  /// Start of the property's key. `None` for the document root and for the
+  /// items of arrays.
+  pub key: Option<Position>,
+  /// Start of the property's value.
+  pub value: Position,
+}
+
+/// The position of every key and value in a JSON or YAML document, looked up
+/// by JSON pointer such as `/dependencies/react`. The root is `""`.
+///
+/// Built from the raw text when a file is read so that messages can point
+/// at the line to edit. Positions describe the file as it was read and are
+/// not updated when the file is modified.
+#[derive(Debug, Default)]
+pub struct SpanIndex {
+  spans: HashMap<String, Span>,
+}
+
+impl SpanIndex {
+  /// Index a JSON document. Comments, trailing commas, single-quoted strings
+  /// and unquoted keys are tolerated so `.jsonc` and JSON5 files can be read
+  /// too. Scanning stops at the first thing which cannot be understood,
+  /// keeping whatever was found before it.
+  pub fn from_json(raw: &str) -> SpanIndex {
+    let mut scanner = JsonScanner {
+      bytes: raw.as_bytes(),
+      raw,
+      offset: 0,
+      position: Position { line: 1, column: 1 },
+      spans: HashMap::new(),
+    };
+    scanner.skip_trivia();
+    scanner.scan_value(String::new(), None);
+    SpanIndex { spans: scanner.spans }
+  }
+
+  /// Index a YAML document, `contents` being its JSON view so the keys to
+  /// look up are known.
+  pub fn from_yaml(raw: &str, contents: &Value) -> SpanIndex {
+    let mut spans = HashMap::new();
+    if let Ok(document) = Document::new(raw) {
+      index_yaml_node(&document, &mut vec![], String::new(), contents, &mut spans);
+    }
+    SpanIndex { spans }
+  }
+
+  pub fn get(&self, pointer: &str) -> Option<Span> {
+    self.spans.get(pointer).copied()
+  }
+}
+
+/// The bytes of the string, number, boolean or null which starts at
+/// `position`, so it can be replaced without touching the text around it.
+/// `None` for objects and arrays, or when `position` is not in `raw`.
+pub fn scalar_json_range(raw: &str, position: Position) -> Option<Range<usize>> {
+  let mut scanner = JsonScanner {
+    bytes: raw.as_bytes(),
+    raw,
+    offset: 0,
+    position: Position { line: 1, column: 1 },
+    spans: HashMap::new(),
+  };
+  while scanner.position != position {
+    scanner.peek()?;
+    scanner.advance();
+  }
+  let start = scanner.offset;
+  match scanner.peek()? {
+    b'{' | b'[' => None,
+    b'"' | b'\'' => scanner.scan_string().map(|_| start..scanner.offset),
+    _ => scanner.scan_value(String::new(), None).then(|| start..scanner.offset),
+  }
+}
+
+/// Append a key to a JSON pointer, escaping `~` and `/` as RFC 6901 requires.
+pub fn child_pointer(parent: &str, key: &str) -> String {
+  format!("{parent}/{}", key.replace('~', "~0").replace('/', "~1"))
+}
+
+struct JsonScanner<'a> {
+  raw: &'a str,
+  bytes: &'a [u8],
+  offset: usize,
+  position: Position,
+  spans: HashMap<String, Span>,
+}
+
+impl JsonScanner<'_> {
+  fn peek(&self) -> Option<u8> {
+    self.bytes.get(self.offset).copied()
+  }
+
+  fn advance(&mut self) {
+    if let Some(byte) = self.peek() {
+      self.offset += 1;
+      if byte == b'\n' {
+        self.position.line += 1;
+        self.position.column = 1;
+      } else if byte & 0b1100_0000 != 0b1000_0000 {
+        // UTF-8 continuation bytes belong to the character before them
+        self.position.column += 1;
+      }
+    }
+  }
+
+  fn skip_trivia(&mut self) {
+    loop {
+      match self.peek() {
+        Some(b' ' | b'\t' | b'\n' | b'\r') => self.advance(),
+        Some(b'/') if self.bytes.get(self.offset + 1) == Some(&b'/') => {
+          while self.peek().is_some_and(|byte| byte != b'\n') {
+            self.advance();
+          }
+        }
+        Some(b'/') if self.bytes.get(self.offset + 1) == Some(&b'*') => {
+          self.advance();
+          self.advance();
+          while self.peek().is_some() && !self.raw[self.offset..].starts_with("*/") {
+            self.advance();
+          }
+          self.advance();
+          self.advance();
+        }
+        _ => return,
+      }
+    }
+  }
+
+  /// Record and skip over the value at the current offset. Returns `false`
+  /// when the document cannot be scanned any further.
+  fn scan_value(&mut self, pointer: String, key: Option<Position>) -> bool {
+    self.spans.insert(pointer.clone(), Span { key, value: self.position });
+    match self.peek() {
+      Some(b'{') => self.scan_object(&pointer),
+      Some(b'[') => self.scan_array(&pointer),
+      Some(b'"' | b'\'') => self.scan_string().is_some(),
+      Some(_) => {
+        while self
+          .peek()
+          .is_some_and(|byte| !matches!(byte, b',' | b'}' | b']' | b' ' | b'\t' | b'\n' | b'\r' | b'/'))
+        {
+          self.advance();
+        }
+        true
+      }
+      None => false,
+    }
+  }
+
+  fn scan_object(&mut self, pointer: &str) -> bool {
+    self.advance();
+    loop {
+      self.skip_trivia();
+      match self.peek() {
+        Some(b'}') => {
+          self.advance();
+          return true;
+        }
+        Some(b',') => self.advance(),
+        Some(_) => {
+          let key_position = self.position;
+          let Some(key) = self.scan_key() else {
+            return false;
+          };
+          self.skip_trivia();
+          if self.peek() != Some(b':') {
+            return false;
+          }
+          self.advance();
+          self.skip_trivia();
+          if !self.scan_value(child_pointer(pointer, &key), Some(key_position)) {
+            return false;
+          }
+        }
+        None => return false,
+      }
+    }
+  }
+
+  fn scan_array(&mut self, pointer: &str) -> bool {
+    self.advance();
+    let mut index = 0;
+    loop {
+      self.skip_trivia();
+      match self.peek() {
+        Some(b']') => {
+          self.advance();
+          return true;
+        }
+        Some(b',') => self.advance(),
+        Some(_) => {
+          if !self.scan_value(format!("{pointer}/{index}"), None) {
+            return false;
+          }
+          index += 1;
+        }
+        None => return false,
+      }
+    }
+  }
+
+  fn scan_key(&mut self) -> Option<String> {
+    match self.peek()? {
+      b'"' | b'\'' => self.scan_string(),
+      _ => {
+        let start = self.offset;
+        while self
+          .peek()
+          .is_some_and(|byte| byte.is_ascii_alphanumeric() || matches!(byte, b'_' | b'$') || byte >= 0x80)
+        {
+          self.advance();
+        }
+        (self.offset > start).then(|| self.raw[start..self.offset].to_string())
+      }
+    }
+  }
+
+  /// Skip over a quoted string and return its unescaped contents
+  fn scan_string(&mut self) -> Option<String> {
+    let quote = self.peek()?;
+    let start = self.offset;
+    self.advance();
+    loop {
+      match self.peek()? {
+        b'\\' => {
+          self.advance();
+          self.advance();
+        }
+        byte if byte == quote => {
+          self.advance();
+          break;
+        }
+        _ => self.advance(),
+      }
+    }
+    let literal = &self.raw[start..self.offset];
+    if quote == b'"' {
+      serde_json::from_str::<String>(literal).ok()
+    } else {
+      Some(literal[1..literal.len() - 1].replace("\\'", "'"))
+    }
+  }
+}
+
+fn index_yaml_node<'a>(
+  document: &Document,
+  route: &mut Vec<Component<'a>>,
+  pointer: String,
+  node: &'a Value,
+  spans: &mut HashMap<String, Span>,
+) {
+  let query = Route::from(route.clone());
+  let to_position = |feature: yamlpath::Feature| position_at(document.source(), feature.location.byte_span.0);
+  let key = match route.last() {
+    Some(Component::Key(_)) => document.query_key_only(&query).ok().map(to_position),
+    _ => None,
+  };
+  let value = document.query_exact(&query).ok().flatten().map(to_position);
+  if let Some(value) = value.or(key) {
+    spans.insert(pointer.clone(), Span { key, value });
+  }
+  match node {
+    Value::Object(object) => {
+      for (key, child) in object {
+        route.push(Component::from(key.as_str()));
+        index_yaml_node(document, route, child_pointer(&pointer, key), child, spans);
+        route.pop();
+      }
+    }
+    Value::Array(array) => {
+      for (index, child) in array.iter().enumerate() {
+        route.push(Component::Index(index));
+        index_yaml_node(document, route, format!("{pointer}/{index}"), child, spans);
+        route.pop();
+      }
+    }
+    _ => {}
+  }
+}
+
+fn position_at(raw: &str, offset: usize) -> Position {
+  let before = &raw[..offset];
+  let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+  Position {
+    line: before.matches('\n').count() + 1,
+    column: before[line_start..].chars().count() + 1,
+  }
+}
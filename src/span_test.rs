@@ -0,0 +1,112 @@
+use {super::*, serde_json::json};
+
+fn at(line: usize, column: usize) -> Position {
+  Position { line, column }
+}
+
+#[test]
+fn indexes_keys_and_values_of_json() {
+  let raw = r#"{
+  "name": "package-a",
+  "dependencies": {
+    "react": "^18.2.0",
+    "@scope/a/b~c": "1.0.0"
+  },
+  "files": ["dist", "src"]
+}"#;
+  let spans = SpanIndex::from_json(raw);
+  assert_eq!(
+    spans.get(""),
+    Some(Span {
+      key: None,
+      value: at(1, 1)
+    })
+  );
+  assert_eq!(
+    spans.get("/dependencies/react"),
+    Some(Span {
+      key: Some(at(4, 5)),
+      value: at(4, 14)
+    })
+  );
+  assert_eq!(
+    spans.get("/dependencies/@scope~1a~1b~0c"),
+    Some(Span {
+      key: Some(at(5, 5)),
+      value: at(5, 21)
+    })
+  );
+  assert_eq!(
+    spans.get("/files/1"),
+    Some(Span {
+      key: None,
+      value: at(7, 21)
+    })
+  );
+  assert_eq!(spans.get("/missing"), None);
+}
+
+#[test]
+fn columns_count_characters_rather_than_bytes() {
+  let raw = r#"{"description": "café ☕", "version": "1.0.0"}"#;
+  let spans = SpanIndex::from_json(raw);
+  assert_eq!(spans.get("/version").unwrap().value, at(1, 38));
+}
+
+#[test]
+fn tolerates_comments_trailing_commas_and_unquoted_keys() {
+  let raw = "{\n  // the import map\n  imports: {\n    /* pinned */ 'react': 'npm:react@18',\n  },\n}";
+  let spans = SpanIndex::from_json(raw);
+  assert_eq!(
+    spans.get("/imports/react"),
+    Some(Span {
+      key: Some(at(4, 18)),
+      value: at(4, 27)
+    })
+  );
+}
+
+#[test]
+fn keeps_what_was_found_before_invalid_json() {
+  let raw = "{\n  \"version\": \"1.0.0\",\n  \"broken\" \"value\"\n}";
+  let spans = SpanIndex::from_json(raw);
+  assert_eq!(spans.get("/version").unwrap().value, at(2, 14));
+  assert_eq!(spans.get("/broken"), None);
+}
+
+#[test]
+fn indexes_keys_and_values_of_yaml() {
+  let raw = "packages:\n  - packages/*\ncatalog:\n  react: ^18.2.0\ncatalogs:\n  react17:\n    \"react-dom\": 17.0.2\n";
+  let contents = json!({
+    "packages": ["packages/*"],
+    "catalog": { "react": "^18.2.0" },
+    "catalogs": { "react17": { "react-dom": "17.0.2" } }
+  });
+  let spans = SpanIndex::from_yaml(raw, &contents);
+  assert_eq!(
+    spans.get("/catalog/react"),
+    Some(Span {
+      key: Some(at(4, 3)),
+      value: at(4, 10)
+    })
+  );
+  assert_eq!(
+    spans.get("/catalogs/react17/react-dom"),
+    Some(Span {
+      key: Some(at(7, 5)),
+      value: at(7, 18)
+    })
+  );
+  assert_eq!(spans.get("/packages/0").unwrap().value, at(2, 5));
+}
+
+#[test]
+fn finds_the_bytes_of_scalar_json_values() {
+  let raw = "{\n  // é\n  \"a\": 'x', \"b\": 12 /* c */,\n  \"d\": [true],\n}";
+  let spans = SpanIndex::from_json(raw);
+  let text_at = |pointer: &str| scalar_json_range(raw, spans.get(pointer).unwrap().value).map(|range| &raw[range]);
+  assert_eq!(text_at("/a"), Some("'x'"));
+  assert_eq!(text_at("/b"), Some("12"));
+  assert_eq!(text_at("/d/0"), Some("true"));
+  assert_eq!(text_at("/d"), None);
+}
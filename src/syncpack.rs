@@ -3,22 +3,31 @@ use {
     catalogs,
     cli::{Cli, ReporterKind, Subcommand},
     commands::{
-      self, fix, fix_mismatches, format, json, lint, lint_semver_ranges, list, list_mismatches, prompt,
-      reporter::{JsonFixReporter, JsonFormatReporter, PrettyFixReporter, PrettyFormatReporter},
-      set_semver_ranges, update,
+      self, fix, fix_mismatches, format, json, lint, lint_semver_ranges, list, list_mismatches,
+      patch::Patch,
+      prompt, report,
+      reporter::{
+        FixReporter, FormatReporter, GithubReporter, JsonFixReporter, JsonFormatReporter, JsonLintReporter, JsonUpdateReporter,
+        JunitReporter, LintReporter, MarkdownReporter, MatrixReporter, PrettyFixReporter, PrettyFormatReporter, PrettyLintReporter,
+        PrettyUpdateReporter, SarifFormatReporter, SarifLintReporter, UpdateReporter,
+      },
+      set_semver_ranges, ui, update,
     },
     context::{Config, Context},
     disk::{Disk, DiskIo},
     errors::SyncpackError,
     logger,
-    rcfile::Rcfile,
+    rcfile::{Rcfile, from_disk::RcfileError},
     registry::{client::RegistryClient, updates::RegistryUpdates},
     source_patterns::get_source_patterns,
     sources::Sources,
     tui::Tui,
+    version_group::AnyVersionGroup,
     visit_formatting::visit_formatting,
     visit_packages::visit_packages,
+    workspace_roots::pin_cross_root_versions,
   },
+  log::error,
   std::sync::Arc,
 };
 
@@ -28,25 +37,125 @@ pub async fn syncpack<D: DiskIo>(
   io: &D,
   registry_client: &Arc<dyn RegistryClient>,
 ) -> Result<(Context, Option<RegistryUpdates>), SyncpackError> {
-  let ctx = analyse(cli, io)?;
+  logger::configure(&cli);
+  let (disk, config) = read_config(cli, io)?;
+  let ctx = analyse(config, disk, io, vec![])?;
   let registry_updates = fetch_updates(&ctx, registry_client).await;
   let ctx = inspect(ctx, &registry_updates);
   Ok((ctx, registry_updates))
 }
 
-/// Analyse the project, discover config and packages, and return a `Context`.
-/// All disk reads happen here.
-fn analyse<D: DiskIo>(cli: Cli, io: &D) -> Result<Context, SyncpackError> {
+/// One workspace root after it has been analysed and inspected
+pub struct WorkspaceRootRun {
+  /// The directory of the root as given by the user, `None` unless
+  /// syncpack is running in multi-root mode
+  pub root: Option<String>,
+  pub ctx: Context,
+  pub registry_updates: Option<RegistryUpdates>,
+}
+
+/// Run the full syncpack CLI once for every directory in `--workspace-roots`
+/// or the `workspaceRoots` of the config file, or once for the current
+/// directory when neither is set.
+///
+/// When `crossRootVersionGroups` is set, every root is read once without
+/// logging to find the versions used across all of them, then read again
+/// with those versions pinned.
+pub async fn syncpack_workspace_roots<D: DiskIo>(
+  cli: Cli,
+  io: &D,
+  registry_client: &Arc<dyn RegistryClient>,
+) -> Result<Vec<WorkspaceRootRun>, SyncpackError> {
   logger::configure(&cli);
-  let mut disk = Disk::from_workspace(io, &cli.cwd);
-  let rcfile = Rcfile::from_disk(&disk, io, &cli).map_err(SyncpackError::RcfileError)?;
+  let (disk, config) = read_config(cli, io)?;
+  let roots = if config.cli.workspace_roots.is_empty() {
+    config.rcfile.workspace_roots.clone()
+  } else {
+    config.cli.workspace_roots.clone()
+  };
+  if roots.is_empty() {
+    let ctx = analyse(config, disk, io, vec![])?;
+    let registry_updates = fetch_updates(&ctx, registry_client).await;
+    let ctx = inspect(ctx, &registry_updates);
+    return Ok(vec![WorkspaceRootRun {
+      root: None,
+      ctx,
+      registry_updates,
+    }]);
+  }
+  let Config { cli, rcfile } = config;
+  let analyse_roots = |pinned_groups: &[AnyVersionGroup]| -> Result<Vec<Context>, SyncpackError> {
+    roots
+      .iter()
+      .map(|root| {
+        let mut root_cli = cli.clone();
+        root_cli.cwd = cli.cwd.join(root);
+        read_config(root_cli, io)
+          .and_then(|(disk, config)| analyse(config, disk, io, pinned_groups.to_vec()))
+          .map_err(|source| SyncpackError::WorkspaceRoot {
+            root: root.clone(),
+            source: Box::new(source),
+          })
+      })
+      .collect()
+  };
+  let contexts = if rcfile.cross_root_version_groups.is_empty() {
+    analyse_roots(&[])?
+  } else {
+    // Anything the first pass would log is logged again by the second
+    let contexts = logger::silently(|| analyse_roots(&[]))?;
+    analyse_roots(&pin_cross_root_versions(&contexts, &rcfile.cross_root_version_groups))?
+  };
+  let mut runs = vec![];
+  for (root, ctx) in roots.into_iter().zip(contexts) {
+    let registry_updates = fetch_updates(&ctx, registry_client).await;
+    let ctx = inspect(ctx, &registry_updates);
+    runs.push(WorkspaceRootRun {
+      root: Some(root),
+      ctx,
+      registry_updates,
+    });
+  }
+  Ok(runs)
+}
+
+/// Run the migrate-config command, which reads the raw config file instead
+/// of building a `Context` because deprecated properties are rejected there
+pub fn migrate_config<D: DiskIo>(cli: &Cli, io: &D) -> Result<(), SyncpackError> {
+  logger::configure(cli);
+  commands::migrate_config::run(cli, io)
+}
+
+/// Read the config file of the project along with the files which locate
+/// its packages, and apply overrides from the environment and `--set`
+/// collected in `cli.config_overrides`.
+fn read_config<D: DiskIo>(cli: Cli, io: &D) -> Result<(Disk, Config), SyncpackError> {
+  let disk = Disk::from_workspace(io, &cli.cwd);
+  let mut rcfile = Rcfile::from_disk(&disk, io, &cli).map_err(SyncpackError::RcfileError)?;
+  rcfile
+    .contents
+    .apply_overrides(&cli.config_overrides)
+    .map_err(RcfileError::UnsupportedConfig)
+    .map_err(SyncpackError::RcfileError)?;
   let config = Config {
     cli,
     rcfile: rcfile.contents,
   };
-  let source_patterns = get_source_patterns(&config, &disk);
-  let file_paths = io.find_package_jsons(&disk.cwd, &source_patterns);
+  Ok((disk, config))
+}
+
+/// Discover packages and return a `Context`. All remaining disk reads happen
+/// here. `pinned_groups` take precedence over the version groups of the
+/// config file.
+fn analyse<D: DiskIo>(mut config: Config, mut disk: Disk, io: &D, pinned_groups: Vec<AnyVersionGroup>) -> Result<Context, SyncpackError> {
+  config.rcfile.version_groups.splice(0..0, pinned_groups);
+  let source_patterns = get_source_patterns(&config, &disk, io);
+  let file_paths = io.find_package_jsons(&disk.cwd, &source_patterns, config.rcfile.respect_gitignore);
   disk.load_package_files(io, &file_paths);
+  if config.rcfile.check_lockfile || matches!(config.cli.subcommand, Subcommand::Json | Subcommand::List) {
+    disk.load_lockfile(io);
+  }
+  config.rcfile.apply_nested_configs(&disk, io).map_err(SyncpackError::RcfileError)?;
   let dep_types = catalogs::make_catalog_dep_types(&disk)?;
   let sources = Sources::from_disk(&disk, &file_paths);
   Context::create(config, disk, sources, dep_types).map_err(SyncpackError::ContextError)
@@ -55,7 +164,7 @@ fn analyse<D: DiskIo>(cli: Cli, io: &D) -> Result<Context, SyncpackError> {
 /// Fetch updates from the npm registry, if applicable
 async fn fetch_updates(ctx: &Context, registry_client: &Arc<dyn RegistryClient>) -> Option<RegistryUpdates> {
   match ctx.config.cli.subcommand {
-    Subcommand::Update => {
+    Subcommand::Report | Subcommand::Update => {
       let registry_updates = RegistryUpdates::fetch(
         registry_client,
         &ctx.version_groups,
@@ -80,40 +189,200 @@ fn inspect(ctx: Context, registry_updates: &Option<RegistryUpdates>) -> Context
     Subcommand::Json => visit_packages(ctx, &None),
     Subcommand::Lint => visit_packages(ctx, &None),
     Subcommand::List => visit_packages(ctx, &None),
+    // Statuses of the report are read before it visits them again with
+    // registry updates
+    Subcommand::Report => visit_packages(ctx, &None),
     Subcommand::Update => visit_packages(ctx, registry_updates),
     _ => ctx,
   }
 }
 
-/// Run the side-effects of the chosen subcommand
-pub fn run<D: DiskIo>(ctx: Context, registry_updates: Option<RegistryUpdates>, io: &D, tui: &dyn Tui) -> Result<Context, SyncpackError> {
-  match ctx.config.cli.subcommand {
-    Subcommand::Fix => {
-      let pretty = PrettyFixReporter;
-      let json_reporter = JsonFixReporter;
-      let reporter: &dyn commands::reporter::FixReporter = match ctx.config.cli.reporter {
-        ReporterKind::Pretty => &pretty,
-        ReporterKind::Json => &json_reporter,
-      };
-      fix::run(ctx, reporter, io)
+/// Run the side-effects of the chosen subcommand for every workspace root.
+/// In multi-root mode the output of each root is headed by its directory,
+/// and every root is run even when an earlier one has issues. Reporters
+/// which print one document, and `--patch`, cover every root at once.
+pub fn run_workspace_roots<D: DiskIo>(runs: Vec<WorkspaceRootRun>, io: &D, tui: &dyn Tui) -> Result<(), SyncpackError> {
+  let Some(cli) = runs.first().map(|run| run.ctx.config.cli.clone()) else {
+    return Ok(());
+  };
+  let reporters = Reporters::new();
+  let mut result = Ok(());
+  for WorkspaceRootRun {
+    root,
+    ctx,
+    registry_updates,
+  } in runs
+  {
+    let Some(root) = root else {
+      result = run(ctx, registry_updates, io, tui, &reporters).map(|_| ());
+      break;
+    };
+    let is_machine_readable =
+      matches!(ctx.config.cli.subcommand, Subcommand::Json) || !matches!(ctx.config.cli.reporter, ReporterKind::Pretty);
+    if !is_machine_readable {
+      ui::util::print_workspace_root(&root);
     }
-    Subcommand::FixMismatches => fix_mismatches::run(ctx),
-    Subcommand::Format => {
-      let pretty = PrettyFormatReporter;
-      let json_reporter = JsonFormatReporter;
-      let reporter: &dyn commands::reporter::FormatReporter = match ctx.config.cli.reporter {
-        ReporterKind::Pretty => &pretty,
-        ReporterKind::Json => &json_reporter,
-      };
-      format::run(ctx, reporter, io)
+    match run(ctx, registry_updates, io, tui, &reporters) {
+      Ok(_) => {}
+      // Every other error outranks issues, which outrank too many warnings
+      Err(err @ (SyncpackError::IssuesFound | SyncpackError::TooManyWarnings { .. })) => {
+        if !matches!(result, Err(SyncpackError::IssuesFound | SyncpackError::AlreadyReported(_))) {
+          result = Err(err);
+        }
+      }
+      Err(source) => {
+        let exit_code = source.exit_code();
+        error!(
+          "{}",
+          SyncpackError::WorkspaceRoot {
+            root,
+            source: Box::new(source)
+          }
+        );
+        if !matches!(result, Err(SyncpackError::AlreadyReported(_))) {
+          result = Err(SyncpackError::AlreadyReported(exit_code));
+        }
+      }
+    }
+  }
+  reporters.finish(&cli, io).and(result)
+}
+
+/// One of each reporter, shared by every workspace root so that reporters
+/// which print a single document print it once after the last root
+pub struct Reporters {
+  pretty_fix: PrettyFixReporter,
+  pretty_format: PrettyFormatReporter,
+  pretty_lint: PrettyLintReporter,
+  pretty_update: PrettyUpdateReporter,
+  json_fix: JsonFixReporter,
+  json_format: JsonFormatReporter,
+  json_lint: JsonLintReporter,
+  json_update: JsonUpdateReporter,
+  sarif_format: SarifFormatReporter,
+  sarif_lint: SarifLintReporter,
+  junit: JunitReporter,
+  github: GithubReporter,
+  markdown: MarkdownReporter,
+  matrix: MatrixReporter,
+  csv: MatrixReporter,
+  /// Diffs of `--dry-run --patch`, written once after the last root
+  patch: Patch,
+}
+
+impl Reporters {
+  pub fn new() -> Self {
+    Self {
+      pretty_fix: PrettyFixReporter,
+      pretty_format: PrettyFormatReporter,
+      pretty_lint: PrettyLintReporter,
+      pretty_update: PrettyUpdateReporter,
+      json_fix: JsonFixReporter,
+      json_format: JsonFormatReporter,
+      json_lint: JsonLintReporter::default(),
+      json_update: JsonUpdateReporter,
+      sarif_format: SarifFormatReporter::default(),
+      sarif_lint: SarifLintReporter::default(),
+      junit: JunitReporter::default(),
+      github: GithubReporter::default(),
+      markdown: MarkdownReporter::default(),
+      matrix: MatrixReporter::new(false),
+      csv: MatrixReporter::new(true),
+      patch: Patch::default(),
+    }
+  }
+
+  // clap only accepts the `--reporter` kinds each subcommand lists, any
+  // other kind is from a `Cli` built in code and falls back to pretty
+
+  fn fix(&self, cli: &Cli) -> &dyn FixReporter {
+    match cli.reporter {
+      ReporterKind::Json => &self.json_fix,
+      ReporterKind::Junit => &self.junit,
+      ReporterKind::Github => &self.github,
+      _ => &self.pretty_fix,
+    }
+  }
+
+  fn format(&self, cli: &Cli) -> &dyn FormatReporter {
+    match cli.reporter {
+      ReporterKind::Json => &self.json_format,
+      ReporterKind::Sarif => &self.sarif_format,
+      ReporterKind::Junit => &self.junit,
+      ReporterKind::Github => &self.github,
+      ReporterKind::Markdown => &self.markdown,
+      _ => &self.pretty_format,
+    }
+  }
+
+  fn lint(&self, cli: &Cli) -> &dyn LintReporter {
+    match cli.reporter {
+      ReporterKind::Json => &self.json_lint,
+      ReporterKind::Sarif => &self.sarif_lint,
+      ReporterKind::Junit => &self.junit,
+      ReporterKind::Github => &self.github,
+      ReporterKind::Markdown => &self.markdown,
+      _ => &self.pretty_lint,
+    }
+  }
+
+  fn list(&self, cli: &Cli) -> &dyn LintReporter {
+    match cli.reporter {
+      ReporterKind::Json => &self.json_lint,
+      ReporterKind::Csv => &self.csv,
+      _ if cli.matrix => &self.matrix,
+      _ => &self.pretty_lint,
     }
+  }
+
+  fn update(&self, cli: &Cli) -> &dyn UpdateReporter {
+    match cli.reporter {
+      ReporterKind::Json => &self.json_update,
+      ReporterKind::Junit => &self.junit,
+      ReporterKind::Github => &self.github,
+      ReporterKind::Markdown => &self.markdown,
+      _ => &self.pretty_update,
+    }
+  }
+
+  /// Print the documents collected from every root and write the patch file
+  pub fn finish<D: DiskIo>(&self, cli: &Cli, io: &D) -> Result<(), SyncpackError> {
+    match cli.subcommand {
+      Subcommand::Fix => self.fix(cli).on_finish(),
+      Subcommand::Format => self.format(cli).on_finish(),
+      Subcommand::Lint => self.lint(cli).on_finish(),
+      Subcommand::List => self.list(cli).on_finish(),
+      Subcommand::Update => self.update(cli).on_finish(),
+      _ => {}
+    }
+    self.patch.write(cli, io)
+  }
+}
+
+/// Run the side-effects of the chosen subcommand
+pub fn run<D: DiskIo>(
+  ctx: Context,
+  registry_updates: Option<RegistryUpdates>,
+  io: &D,
+  tui: &dyn Tui,
+  reporters: &Reporters,
+) -> Result<Context, SyncpackError> {
+  let cli = ctx.config.cli.clone();
+  match cli.subcommand {
+    Subcommand::Fix => fix::run(ctx, reporters.fix(&cli), io, &reporters.patch),
+    Subcommand::FixMismatches => fix_mismatches::run(ctx),
+    Subcommand::Format => format::run(ctx, reporters.format(&cli), io, &reporters.patch),
     Subcommand::Json => json::run(ctx),
-    Subcommand::Lint => lint::run(ctx),
+    Subcommand::Lint => lint::run(ctx, reporters.lint(&cli), io),
     Subcommand::LintSemverRanges => lint_semver_ranges::run(ctx),
-    Subcommand::List => list::run(ctx),
+    Subcommand::List => list::run(ctx, reporters.list(&cli)),
     Subcommand::ListMismatches => list_mismatches::run(ctx),
     Subcommand::Prompt => prompt::run(ctx),
+    Subcommand::Report => report::run(ctx, registry_updates, io),
     Subcommand::SetSemverRanges => set_semver_ranges::run(ctx),
-    Subcommand::Update => update::run(ctx, registry_updates.expect("registry_updates is None"), io, tui),
+    Subcommand::Update => {
+      let reporter = reporters.update(&cli);
+      update::run(ctx, registry_updates.expect("registry_updates is None"), io, tui, reporter)
+    }
   }
 }
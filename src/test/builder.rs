@@ -25,6 +25,7 @@ use {
     disk::{Disk, File, PackageManager, detect_formatting, parse_yaml_file},
     registry::updates::RegistryUpdates,
     sources::Sources,
+    span::SpanIndex,
     test::mock_tui::MockTui,
     visit_formatting::visit_formatting,
     visit_packages::visit_packages,
@@ -47,7 +48,9 @@ pub struct TestBuilder {
   package_manager: Option<PackageManager>,
   packages: Vec<Value>,
   manifests_at: Vec<(String, Value)>,
+  files_at: Vec<(String, String)>,
   pnpm_yaml: Option<String>,
+  yarnrc: Option<String>,
   bun_root: Option<Value>,
   registry_updates: Option<Value>,
   registry_times: BTreeMap<String, HashMap<String, String>>,
@@ -69,7 +72,9 @@ impl TestBuilder {
       package_manager: None,
       packages: vec![],
       manifests_at: vec![],
+      files_at: vec![],
       pnpm_yaml: None,
+      yarnrc: None,
       bun_root: None,
       registry_updates: None,
       registry_times: BTreeMap::new(),
@@ -89,7 +94,14 @@ impl TestBuilder {
   /// carry `descriptor.source_idx` pointing at that slot and the dep type
   /// flag `is_catalog_definition == true`. Implies
   /// `with_pnpm_package_manager()` so discovery actually runs.
-  pub fn with_pnpm_catalogs(mut self, yaml_content: &str) -> Self {
+  pub fn with_pnpm_catalogs(self, yaml_content: &str) -> Self {
+    self.with_pnpm_workspace_yaml(yaml_content)
+  }
+
+  /// Inject pnpm-workspace.yaml content which is not about catalogs, such
+  /// as `patchedDependencies` or `overrides`. Implies
+  /// `with_pnpm_package_manager()` so discovery actually runs.
+  pub fn with_pnpm_workspace_yaml(mut self, yaml_content: &str) -> Self {
     self.pnpm_yaml = Some(yaml_content.to_string());
     if self.package_manager.is_none() {
       self.package_manager = Some(PackageManager::Pnpm);
@@ -97,6 +109,17 @@ impl TestBuilder {
     self
   }
 
+  /// Inject `.yarnrc.yml` content. The yaml lands as `Source::YarnRc` in
+  /// `ctx.sources.all` (last slot). Implies `with_yarn_package_manager()`
+  /// so discovery actually runs.
+  pub fn with_yarn_catalogs(mut self, yaml_content: &str) -> Self {
+    self.yarnrc = Some(yaml_content.to_string());
+    if self.package_manager.is_none() {
+      self.package_manager = Some(PackageManager::Yarn);
+    }
+    self
+  }
+
   /// Inject Bun catalog blocks at top-level (`/catalog`, `/catalogs/{name}`)
   /// of a synthetic root package.json at `packages[0]`. Implies
   /// `with_bun_package_manager()`.
@@ -173,6 +196,13 @@ impl TestBuilder {
     self
   }
 
+  /// Add a raw text file at an arbitrary path (e.g. `packages/foo/.syncpackrc`).
+  /// Only honoured by `run`, which reads through MockDisk.
+  pub fn with_file_at(mut self, path: &str, contents: &str) -> Self {
+    self.files_at.push((path.to_string(), contents.to_string()));
+    self
+  }
+
   pub fn with_version_group(mut self, group: Value) -> Self {
     self.version_groups.push(group);
     self
@@ -268,7 +298,7 @@ impl TestBuilder {
   pub async fn run_with_updates(self) -> (Context, Option<RegistryUpdates>) {
     use {
       crate::{
-        cli::Cli,
+        cli::Invocation,
         registry::client::RegistryClient,
         syncpack,
         test::{mock_disk::MockDiskIo, registry_client::MockRegistryClient},
@@ -290,6 +320,10 @@ impl TestBuilder {
       disk.add_json(path, json);
     }
 
+    for (path, contents) in &self.files_at {
+      disk.add_file(path, contents.clone());
+    }
+
     // Synthetic Bun root + bun.lock to trigger PM=Bun + catalog discovery.
     if let Some(ref root) = self.bun_root {
       disk.add_json("package.json", root);
@@ -302,9 +336,15 @@ impl TestBuilder {
       disk.add_file("pnpm-lock.yaml", "lockfileVersion: '6.0'\n".to_string());
     }
 
+    // Synthetic .yarnrc.yml + yarn.lock to trigger PM=Yarn + discovery.
+    if let Some(ref yaml) = self.yarnrc {
+      disk.add_file(".yarnrc.yml", yaml.clone());
+      disk.add_file("yarn.lock", "".to_string());
+    }
+
     // Honour `with_*_package_manager(...)` when no catalog artifact already
     // wrote a lock file. PM is detected purely from disk in `.run()`.
-    if self.bun_root.is_none() && self.pnpm_yaml.is_none() {
+    if self.bun_root.is_none() && self.pnpm_yaml.is_none() && self.yarnrc.is_none() {
       match self.package_manager {
         Some(PackageManager::Pnpm) => disk.add_file("pnpm-lock.yaml", "lockfileVersion: '6.0'\n".to_string()),
         Some(PackageManager::Bun) => disk.add_file("bun.lock", "{}".to_string()),
@@ -350,7 +390,9 @@ impl TestBuilder {
     }
     let registry_client: Arc<dyn RegistryClient> = Arc::new(mock);
 
-    let cli = Cli::parse(&args).expect("Cli::parse failed");
+    let Invocation::Run(cli) = Invocation::parse(&args).expect("Invocation::parse failed") else {
+      panic!("TestBuilder only runs subcommands which build a Context");
+    };
     let (ctx, registry_updates) = syncpack::syncpack(cli, &disk, &registry_client)
       .await
       .expect("syncpack analyse/inspect failed");
@@ -410,7 +452,7 @@ impl TestBuilder {
   }
 
   /// Synthetic Disk for build paths: includes the bun_root (if any) at slot 0,
-  /// real workspace packages at slots 1..N, and pnpm yaml when set. Test infra
+  /// real workspace packages at slots 1..N, and pnpm / yarn yaml when set. Test infra
   /// uses /test/* paths because no real fs reads happen on this code path.
   fn build_disk(&self) -> Disk {
     let cwd = PathBuf::from("/test");
@@ -423,6 +465,8 @@ impl TestBuilder {
         formatting: detect_formatting(&raw),
         contents: root.clone(),
         dirty: false,
+        spans: SpanIndex::from_json(&raw),
+        raw: None,
       });
       package_json_root_idx = Some(0);
     }
@@ -438,6 +482,8 @@ impl TestBuilder {
         formatting: detect_formatting(&raw),
         contents: pkg.clone(),
         dirty: false,
+        spans: SpanIndex::from_json(&raw),
+        raw: None,
       });
     }
     for (path, json) in &self.manifests_at {
@@ -447,12 +493,18 @@ impl TestBuilder {
         formatting: detect_formatting(&raw),
         contents: json.clone(),
         dirty: false,
+        spans: SpanIndex::from_json(&raw),
+        raw: None,
       });
     }
     let pnpm_workspace = self
       .pnpm_yaml
       .as_ref()
       .and_then(|raw| parse_yaml_file(raw.clone(), PathBuf::from("/test/pnpm-workspace.yaml")));
+    let yarnrc = self
+      .yarnrc
+      .as_ref()
+      .and_then(|raw| parse_yaml_file(raw.clone(), PathBuf::from("/test/.yarnrc.yml")));
     Disk {
       cwd,
       lerna_json: None,
@@ -460,6 +512,12 @@ impl TestBuilder {
       package_json_root_idx,
       package_manager: self.package_manager,
       pnpm_workspace,
+      yarnrc,
+      deno_json_files: Vec::new(),
+      lockfile: None,
+      rush_json: None,
+      nx_workspace_json: None,
+      has_nx_json: false,
     }
   }
 }
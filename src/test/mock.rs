@@ -8,6 +8,7 @@ use {
     rcfile::Rcfile,
     registry::{client::RegistryClient, updates::RegistryUpdates},
     sources::Sources,
+    span::SpanIndex,
   },
   log::LevelFilter,
   serde_json::Value,
@@ -16,13 +17,20 @@ use {
 
 pub fn cli() -> Cli {
   Cli {
+    baseline_path: None,
     check: true,
+    config_overrides: vec![],
     config_path: None,
     cwd: env::current_dir().unwrap(),
     disable_ansi: true,
     dry_run: true,
     filters: None,
+    invocation_cwd: env::current_dir().unwrap(),
     log_levels: vec![LevelFilter::Error],
+    matrix: false,
+    max_warnings: None,
+    out_path: None,
+    patch_path: None,
     reporter: ReporterKind::Pretty,
     show_hints: false,
     show_ignored: false,
@@ -33,8 +41,10 @@ pub fn cli() -> Cli {
     source_mode: None,
     subcommand: Subcommand::Lint,
     target: UpdateTarget::Latest,
+    update_baseline: false,
     interactive: false,
     no_cache: false,
+    workspace_roots: vec![],
   }
 }
 
@@ -82,6 +92,8 @@ pub fn package_json_file_from_value(contents: Value) -> File<Value> {
     formatting: detect_formatting(&raw),
     contents,
     dirty: false,
+    spans: SpanIndex::from_json(&raw),
+    raw: None,
   }
 }
 
@@ -117,6 +129,12 @@ pub fn disk_and_sources_from_mocks(values: Vec<serde_json::Value>) -> (Disk, Sou
     package_json_root_idx: None,
     package_manager: None,
     pnpm_workspace: None,
+    yarnrc: None,
+    deno_json_files: Vec::new(),
+    lockfile: None,
+    rush_json: None,
+    nx_workspace_json: None,
+    has_nx_json: false,
   };
   let sources = Sources::from_disk(&disk, &all_paths);
   (disk, sources)
@@ -1,5 +1,12 @@
 use {
-  crate::disk::{DiskDirEntry, DiskIo, DiskIoError, File, NodeJsError, YamlFile, detect_formatting},
+  crate::{
+    disk::{
+      DiskDirEntry, DiskIo, DiskIoError, File, NodeJsError, SYNCPACKIGNORE, YamlFile, build_source_overrides, detect_formatting,
+      ignore_matcher_from_str, log_excluded_counts,
+    },
+    span::SpanIndex,
+  },
+  ignore::gitignore::Gitignore,
   std::{
     cell::RefCell,
     collections::{HashMap, HashSet},
@@ -99,6 +106,8 @@ impl DiskIo for MockDiskIo {
         formatting: detect_formatting(raw),
         contents,
         dirty: false,
+        spans: SpanIndex::default(),
+        raw: None,
       })
     })
   }
@@ -114,6 +123,8 @@ impl DiskIo for MockDiskIo {
         formatting: detect_formatting(raw),
         contents: raw.clone(),
         dirty: false,
+        spans: SpanIndex::default(),
+        raw: None,
       })
     })
   }
@@ -141,6 +152,8 @@ impl DiskIo for MockDiskIo {
         formatting: detect_formatting(raw),
         contents,
         dirty: false,
+        spans: SpanIndex::default(),
+        raw: None,
       })
     })
   }
@@ -168,14 +181,21 @@ impl DiskIo for MockDiskIo {
     Ok(())
   }
 
-  fn find_package_jsons(&self, root: &Path, patterns: &[String]) -> Vec<PathBuf> {
-    let mut builder = ignore::overrides::OverrideBuilder::new(root);
-    for pattern in patterns {
-      let _ = builder.add(pattern);
-    }
-    let overrides = builder.build().unwrap_or_else(|_| ignore::overrides::Override::empty());
-
-    self
+  /// Only the `.syncpackignore` and `.gitignore` files at `root` are
+  /// honoured, nested ignore files are not.
+  fn find_package_jsons(&self, root: &Path, patterns: &[String], respect_gitignore: bool) -> Vec<PathBuf> {
+    let overrides = build_source_overrides(root, patterns);
+    let ignore_file = |file_name: &str| {
+      let contents = self.files.get(&root.join(file_name)).map_or("", String::as_str);
+      ignore_matcher_from_str(root, contents)
+    };
+    let syncpackignore = ignore_file(SYNCPACKIGNORE);
+    let gitignore = if respect_gitignore {
+      ignore_file(".gitignore")
+    } else {
+      Gitignore::empty()
+    };
+    let candidates: Vec<PathBuf> = self
       .files
       .keys()
       .filter(|path| path.file_name().and_then(|n| n.to_str()).is_some_and(|n| n.ends_with(".json")))
@@ -184,6 +204,22 @@ impl DiskIo for MockDiskIo {
         overrides.matched(rel, false).is_whitelist()
       })
       .cloned()
-      .collect()
+      .collect();
+    let found: Vec<PathBuf> = candidates
+      .iter()
+      .filter(|path| {
+        !syncpackignore.matched_path_or_any_parents(path, false).is_ignore()
+          && !gitignore.matched_path_or_any_parents(path, false).is_ignore()
+      })
+      .cloned()
+      .collect();
+    if log::log_enabled!(log::Level::Debug) {
+      log_excluded_counts(
+        &candidates,
+        &found,
+        &[(SYNCPACKIGNORE, &syncpackignore), (".gitignore", &gitignore)],
+      );
+    }
+    found
   }
 }
@@ -0,0 +1,52 @@
+/// Declare an enum along with `NAMES`, the name of every variant in the
+/// order they are declared, so that lists of its variants cannot go out of
+/// date. Enums whose variants have no fields also get `ALL`, every value in
+/// that order.
+macro_rules! variant_names {
+  (
+    $(#[$meta:meta])*
+    $vis:vis enum $name:ident {
+      $($(#[$variant_meta:meta])* $variant:ident),* $(,)?
+    }
+  ) => {
+    $(#[$meta])*
+    $vis enum $name {
+      $($(#[$variant_meta])* $variant),*
+    }
+
+    impl $name {
+      /// Every variant, in the order they are declared
+      pub const ALL: &'static [$name] = &[$($name::$variant),*];
+      /// The name of every variant, in the order they are declared
+      pub const NAMES: &'static [&'static str] = &[$(stringify!($variant)),*];
+    }
+  };
+  (
+    $(#[$meta:meta])*
+    $vis:vis enum $name:ident {
+      $(
+        $(#[$variant_meta:meta])*
+        $variant:ident
+        $(($($tuple:ty),* $(,)?))?
+        $({$($field:ident: $field_ty:ty),* $(,)?})?
+      ),* $(,)?
+    }
+  ) => {
+    $(#[$meta])*
+    $vis enum $name {
+      $(
+        $(#[$variant_meta])*
+        $variant
+        $(($($tuple),*))?
+        $({$($field: $field_ty),*})?
+      ),*
+    }
+
+    impl $name {
+      /// The name of every variant, in the order they are declared
+      pub const NAMES: &'static [&'static str] = &[$(stringify!($variant)),*];
+    }
+  };
+}
+
+pub(crate) use variant_names;
@@ -4,7 +4,7 @@ use {
     context::Context,
     dependency::UpdateUrl,
     errors::UnsupportedConfigError,
-    group_selector::GroupSelector,
+    group_selector::{GroupSelector, named::SelectorExpr},
     instance::{Instance, InstanceIdx, InstanceState, InvalidInstance, Severity, SuspectInstance, severity::SeverityMap},
     registry::updates::RegistryUpdates,
     source::Source,
@@ -20,6 +20,7 @@ use {
     cmp::Ordering,
     collections::{BTreeMap, HashMap},
     rc::Rc,
+    sync::Arc,
   },
   syncpack_specifier::update_target::UpdateTarget,
 };
@@ -52,7 +53,7 @@ pub enum PreferVersion {
   LowestSemver,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AnyVersionGroup {
   #[serde(default)]
@@ -65,6 +66,12 @@ pub struct AnyVersionGroup {
   pub packages: Vec<String>,
   #[serde(default)]
   pub specifier_types: Vec<String>,
+  /// Name of a selector defined in the top-level `selectors` property
+  pub selector: Option<String>,
+  /// The compiled `selector`, filled in by `Rcfile` once every named
+  /// selector is known
+  #[serde(skip)]
+  pub named_selector: Option<Arc<SelectorExpr>>,
   //
   pub is_banned: Option<bool>,
   pub is_ignored: Option<bool>,
@@ -424,17 +431,18 @@ impl VersionGroup {
     })
   }
 
-  pub fn from_config(group: AnyVersionGroup, index: usize, sources: &Sources) -> Result<Self, UnsupportedConfigError> {
+  pub fn from_config(group: AnyVersionGroup, path: &str, sources: &Sources) -> Result<Self, UnsupportedConfigError> {
     let selector = GroupSelector::new(
       group.dependencies,
       group.dependency_types,
       group.label,
       group.packages,
       group.specifier_types,
-    );
+    )
+    .with_named(group.named_selector);
 
     if let Some(true) = group.is_banned {
-      let severity = validate_severity(group.severity, "Banned", index, BANNED_KEYS)?;
+      let severity = validate_severity(group.severity, "Banned", path, BANNED_KEYS)?;
       return Ok(Self::Banned(BannedGroup {
         selector,
         dependencies: BTreeMap::new(),
@@ -450,7 +458,7 @@ impl VersionGroup {
       }));
     }
     if let Some(pin_version) = &group.pin_version {
-      let severity = validate_severity(group.severity, "Pinned", index, PINNED_KEYS)?;
+      let severity = validate_severity(group.severity, "Pinned", path, PINNED_KEYS)?;
       return Ok(Self::Pinned(PinnedGroup {
         selector,
         dependencies: BTreeMap::new(),
@@ -460,14 +468,14 @@ impl VersionGroup {
     }
     if let Some(policy) = &group.policy {
       if policy == "sameRange" {
-        let severity = validate_severity(group.severity, "SameRange", index, SAME_RANGE_KEYS)?;
+        let severity = validate_severity(group.severity, "SameRange", path, SAME_RANGE_KEYS)?;
         return Ok(Self::SameRange(SameRangeGroup {
           selector,
           dependencies: BTreeMap::new(),
           severity,
         }));
       } else if policy == "sameMinor" {
-        let severity = validate_severity(group.severity, "SameMinor", index, SAME_MINOR_KEYS)?;
+        let severity = validate_severity(group.severity, "SameMinor", path, SAME_MINOR_KEYS)?;
         let prefer_version = group.prefer_version.as_ref().map(|pv| {
           if pv == "lowestSemver" {
             PreferVersion::LowestSemver
@@ -482,14 +490,14 @@ impl VersionGroup {
           severity,
         }));
       } else if policy == "catalog" {
-        let severity = validate_severity(group.severity, "Catalog", index, CATALOG_KEYS)?;
+        let severity = validate_severity(group.severity, "Catalog", path, CATALOG_KEYS)?;
         return Ok(Self::Catalog(CatalogGroup {
           selector,
           dependencies: BTreeMap::new(),
           severity,
         }));
       } else if policy == "semverRangeOnly" {
-        let severity = validate_severity(group.severity, "SemverRangeOnly", index, SEMVER_RANGE_ONLY_KEYS)?;
+        let severity = validate_severity(group.severity, "SemverRangeOnly", path, SEMVER_RANGE_ONLY_KEYS)?;
         return Ok(Self::SemverRangeOnly(SemverRangeOnlyGroup {
           selector,
           dependencies: BTreeMap::new(),
@@ -500,7 +508,7 @@ impl VersionGroup {
       }
     }
     if let Some(snap_to) = &group.snap_to {
-      let severity = validate_severity(group.severity, "SnappedTo", index, SNAPPED_TO_KEYS)?;
+      let severity = validate_severity(group.severity, "SnappedTo", path, SNAPPED_TO_KEYS)?;
       return Ok(Self::SnappedTo(SnappedToGroup {
         selector,
         dependencies: BTreeMap::new(),
@@ -519,7 +527,7 @@ impl VersionGroup {
     if let Some(prefer_version) = &group.prefer_version {
       let prefer_highest = prefer_version != "lowestSemver";
       let group_type = if prefer_highest { "HighestSemver" } else { "LowestSemver" };
-      let severity = validate_severity(group.severity, group_type, index, PREFERRED_SEMVER_KEYS)?;
+      let severity = validate_severity(group.severity, group_type, path, PREFERRED_SEMVER_KEYS)?;
       return Ok(Self::PreferredSemver(PreferredSemverGroup {
         selector,
         dependencies: BTreeMap::new(),
@@ -527,7 +535,7 @@ impl VersionGroup {
         severity,
       }));
     }
-    let severity = validate_severity(group.severity, "HighestSemver", index, PREFERRED_SEMVER_KEYS)?;
+    let severity = validate_severity(group.severity, "HighestSemver", path, PREFERRED_SEMVER_KEYS)?;
     Ok(Self::PreferredSemver(PreferredSemverGroup {
       selector,
       dependencies: BTreeMap::new(),
@@ -609,6 +617,7 @@ const PINNED_KEYS: &[&str] = &[
 const PREFERRED_SEMVER_KEYS: &[&str] = &[
   "SemverRangeMismatch",
   "DiffersToLocal",
+  "UsesLocalPath",
   "DiffersToCatalog",
   "DiffersToHighestOrLowestSemver",
 ];
@@ -626,13 +635,13 @@ const CATALOG_KEYS: &[&str] = &["NotUsingCatalog", "MissingFromCatalog"];
 fn validate_severity(
   severity: SeverityMap,
   group_type: &'static str,
-  index: usize,
+  path: &str,
   permitted: &'static [&'static str],
 ) -> Result<SeverityMap, UnsupportedConfigError> {
   for key in severity.keys() {
     if !permitted.contains(&key.as_str()) {
       return Err(UnsupportedConfigError::InvalidSeverityKey {
-        path: format!("versionGroups[{index}].severity.{key}"),
+        path: format!("{path}.severity.{key}"),
         group_type: group_type.to_string(),
         key: key.clone(),
         permitted: permitted.to_vec(),
@@ -658,7 +667,7 @@ pub(crate) fn visit_groups_with_registry(
     .enumerate()
     .map(|(index, json)| {
       let cfg: AnyVersionGroup = serde_json::from_value(json.clone()).unwrap();
-      VersionGroup::from_config(cfg, index, &ctx.sources).unwrap()
+      VersionGroup::from_config(cfg, &format!("versionGroups[{index}]"), &ctx.sources).unwrap()
     })
     .collect();
   groups.push(VersionGroup::get_catch_all());
@@ -198,6 +198,15 @@ async fn removes_instance_with_unnamed_version_string_strategy() {
       overridden: None,
       severity: None,
     },
+    ExpectedInstance {
+      state: InstanceState::valid(IsHighestOrLowestSemver),
+      dependency_name: "node",
+      id: "node in /engines of package-a",
+      actual: ">=16.0.0",
+      expected: Some(">=16.0.0"),
+      overridden: None,
+      severity: None,
+    },
   ]);
 }
 
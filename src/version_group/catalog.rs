@@ -36,10 +36,10 @@ impl CatalogGroup {
 
       // Precompute the missing-from-catalog merge result so every non-def
       // consumer in the dep can be marked consistently. Only used by the
-      // 0-catalogs (PM=pnpm/bun) and 1-catalog-without-dep branches.
+      // 0-catalogs (PM=pnpm/bun/yarn berry) and 1-catalog-without-dep branches.
       let missing_target_catalog_name: Option<String> = if catalog_defs.is_empty() {
         match distinct_catalog_count {
-          0 if matches!(ctx.package_manager(), Some(PackageManager::Pnpm) | Some(PackageManager::Bun)) => Some("default".to_string()),
+          0 if supports_implicit_default_catalog(ctx) => Some("default".to_string()),
           1 => ctx.distinct_catalog_names().first().map(|s| s.to_string()),
           _ => None,
         }
@@ -104,7 +104,7 @@ impl CatalogGroup {
         }
 
         // Branch 5: dep is not defined in any catalog. Two routes reach this
-        // point: 0 catalogs project-wide (PM=pnpm/bun → implicit default) OR
+        // point: 0 catalogs project-wide (PM=pnpm/bun/yarn berry → implicit default) OR
         // exactly 1 catalog exists and dep is absent from it.
         if let (Some(catalog_name), Some(merge_result)) = (&missing_target_catalog_name, &merged_winning_specifier) {
           match merge_result {
@@ -158,6 +158,17 @@ impl CatalogGroup {
   }
 }
 
+/// Whether a project with no catalogs yet can have a default catalog created
+/// for it. Yarn only qualifies once `.yarnrc.yml` exists, because Yarn Classic
+/// has no catalogs.
+fn supports_implicit_default_catalog(ctx: &Context) -> bool {
+  match ctx.package_manager() {
+    Some(PackageManager::Pnpm | PackageManager::Bun) => true,
+    Some(PackageManager::Yarn) => ctx.disk.yarnrc.is_some(),
+    _ => false,
+  }
+}
+
 /// Build the `catalog:` / `catalog:{name}` Specifier consumers should switch to.
 fn catalog_target_for(catalog_name: &str) -> Rc<Specifier> {
   let raw = if catalog_name == "default" {
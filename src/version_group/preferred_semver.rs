@@ -7,7 +7,7 @@ use {
       FixableInstance, Instance, InstanceIdx, SemverGroupAndVersionConflict, SuspectInstance, UnfixableInstance, ValidInstance,
       severity::SeverityMap,
     },
-    rcfile::update_group::UpdatePolicy,
+    rcfile::{LocalPathPolicy, update_group::UpdatePolicy},
     registry::updates::RegistryUpdates,
     semver_range::SemverRange,
     specifier::Specifier,
@@ -96,17 +96,37 @@ impl PreferredSemverGroup {
             continue;
           }
           debug!("{L4}it depends on the local instance");
-          if instance.descriptor.specifier.is_link() {
-            debug!("{L5}it is using the link specifier");
+          if instance.descriptor.specifier.is_file() || instance.descriptor.specifier.is_link() {
+            debug!("{L5}it is using a file: or link: path");
             if let Some(local_idx) = dep.local_instance.borrow().as_ref() {
               let local_instance = &arena[local_idx.0];
-              if instance.link_resolves_to_local_package(local_instance, &ctx.sources.all, &ctx.disk) {
-                debug!("{L6}link resolves to local package directory");
-                debug!("{L7}mark as satisfying local");
-                instance.mark_valid(ValidInstance::SatisfiesLocal, &instance.descriptor.specifier);
+              if instance.path_resolves_to_local_package(local_instance, &ctx.sources.all, &ctx.disk) {
+                debug!("{L6}path resolves to local package directory");
+                match ctx.config.rcfile.local_path_policy {
+                  LocalPathPolicy::Allow => {
+                    debug!("{L7}localPathPolicy allows it");
+                    debug!("{L8}mark as satisfying local");
+                    instance.mark_valid(ValidInstance::SatisfiesLocal, &instance.descriptor.specifier);
+                  }
+                  LocalPathPolicy::Workspace if !ctx.config.rcfile.strict => {
+                    debug!("{L7}localPathPolicy prefers the workspace protocol");
+                    debug!("{L8}mark as fixable error");
+                    instance.mark_fixable(FixableInstance::UsesLocalPath, &instance.get_workspace_protocol_specifier());
+                  }
+                  LocalPathPolicy::Workspace | LocalPathPolicy::Version => {
+                    debug!("{L7}localPathPolicy prefers the local version");
+                    debug!("{L8}mark as fixable error");
+                    let fix_target = instance
+                      .preferred_semver_range
+                      .as_ref()
+                      .and_then(|range| local_specifier.with_range(range))
+                      .unwrap_or_else(|| Rc::clone(&local_specifier));
+                    instance.mark_fixable(FixableInstance::UsesLocalPath, &fix_target);
+                  }
+                }
                 continue;
               } else {
-                debug!("{L6}link resolves to a different directory");
+                debug!("{L6}path resolves to a different directory");
                 debug!("{L7}mark as differs to local");
                 instance.mark_fixable(FixableInstance::DiffersToLocal, &local_specifier);
                 continue;
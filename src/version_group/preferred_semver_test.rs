@@ -449,6 +449,307 @@ mod local {
     ]);
   }
 
+  #[tokio::test]
+  async fn instance_uses_file_path_to_local_package() {
+    let ctx = TestBuilder::new()
+      .with_packages(vec![
+        json!({
+          "name": "package-a",
+          "version": "1.0.0"
+        }),
+        json!({
+          "name": "package-b",
+          "version": "2.0.0",
+          "dependencies": {
+            "package-a": "file:../package-a"
+          }
+        }),
+      ])
+      .run()
+      .await;
+    expect(&ctx).to_have_instances(vec![
+      ExpectedInstance {
+        state: InstanceState::valid(IsLocalAndValid),
+        dependency_name: "package-b",
+        id: "package-b in /version of package-b",
+        actual: "2.0.0",
+        expected: Some("2.0.0"),
+        overridden: None,
+        severity: None,
+      },
+      ExpectedInstance {
+        state: InstanceState::valid(IsLocalAndValid),
+        dependency_name: "package-a",
+        id: "package-a in /version of package-a",
+        actual: "1.0.0",
+        expected: Some("1.0.0"),
+        overridden: None,
+        severity: None,
+      },
+      ExpectedInstance {
+        state: InstanceState::valid(SatisfiesLocal),
+        dependency_name: "package-a",
+        id: "package-a in /dependencies of package-b",
+        actual: "file:../package-a",
+        expected: Some("file:../package-a"),
+        overridden: None,
+        severity: None,
+      },
+    ]);
+  }
+
+  #[tokio::test]
+  async fn instance_uses_file_path_to_wrong_directory() {
+    let ctx = TestBuilder::new()
+      .with_packages(vec![
+        json!({
+          "name": "package-a",
+          "version": "1.0.0"
+        }),
+        json!({
+          "name": "package-b",
+          "version": "2.0.0",
+          "dependencies": {
+            "package-a": "file:../../elsewhere/package-a"
+          }
+        }),
+      ])
+      .run()
+      .await;
+    expect(&ctx).to_have_instances(vec![
+      ExpectedInstance {
+        state: InstanceState::valid(IsLocalAndValid),
+        dependency_name: "package-b",
+        id: "package-b in /version of package-b",
+        actual: "2.0.0",
+        expected: Some("2.0.0"),
+        overridden: None,
+        severity: None,
+      },
+      ExpectedInstance {
+        state: InstanceState::valid(IsLocalAndValid),
+        dependency_name: "package-a",
+        id: "package-a in /version of package-a",
+        actual: "1.0.0",
+        expected: Some("1.0.0"),
+        overridden: None,
+        severity: None,
+      },
+      ExpectedInstance {
+        state: InstanceState::fixable(DiffersToLocal),
+        dependency_name: "package-a",
+        id: "package-a in /dependencies of package-b",
+        actual: "file:../../elsewhere/package-a",
+        expected: Some("1.0.0"),
+        overridden: None,
+        severity: None,
+      },
+    ]);
+  }
+
+  #[tokio::test]
+  async fn local_path_policy_workspace_rewrites_path_to_workspace_protocol() {
+    let ctx = TestBuilder::new()
+      .with_packages(vec![
+        json!({
+          "name": "package-a",
+          "version": "1.0.0"
+        }),
+        json!({
+          "name": "package-b",
+          "version": "2.0.0",
+          "dependencies": {
+            "package-a": "file:../package-a"
+          }
+        }),
+      ])
+      .with_config(json!({"localPathPolicy": "workspace"}))
+      .run()
+      .await;
+    expect(&ctx).to_have_instances(vec![
+      ExpectedInstance {
+        state: InstanceState::valid(IsLocalAndValid),
+        dependency_name: "package-b",
+        id: "package-b in /version of package-b",
+        actual: "2.0.0",
+        expected: Some("2.0.0"),
+        overridden: None,
+        severity: None,
+      },
+      ExpectedInstance {
+        state: InstanceState::valid(IsLocalAndValid),
+        dependency_name: "package-a",
+        id: "package-a in /version of package-a",
+        actual: "1.0.0",
+        expected: Some("1.0.0"),
+        overridden: None,
+        severity: None,
+      },
+      ExpectedInstance {
+        state: InstanceState::fixable(UsesLocalPath),
+        dependency_name: "package-a",
+        id: "package-a in /dependencies of package-b",
+        actual: "file:../package-a",
+        expected: Some("workspace:*"),
+        overridden: None,
+        severity: None,
+      },
+    ]);
+  }
+
+  #[tokio::test]
+  async fn local_path_policy_workspace_uses_shorthand_of_semver_group() {
+    let ctx = TestBuilder::new()
+      .with_packages(vec![
+        json!({
+          "name": "package-a",
+          "version": "1.0.0"
+        }),
+        json!({
+          "name": "package-b",
+          "version": "2.0.0",
+          "dependencies": {
+            "package-a": "link:../package-a"
+          }
+        }),
+      ])
+      .with_config(json!({"localPathPolicy": "workspace"}))
+      .with_semver_group(json!({"range": "^"}))
+      .run()
+      .await;
+    expect(&ctx).to_have_instances(vec![
+      ExpectedInstance {
+        state: InstanceState::valid(IsLocalAndValid),
+        dependency_name: "package-b",
+        id: "package-b in /version of package-b",
+        actual: "2.0.0",
+        expected: Some("2.0.0"),
+        overridden: None,
+        severity: None,
+      },
+      ExpectedInstance {
+        state: InstanceState::valid(IsLocalAndValid),
+        dependency_name: "package-a",
+        id: "package-a in /version of package-a",
+        actual: "1.0.0",
+        expected: Some("1.0.0"),
+        overridden: None,
+        severity: None,
+      },
+      ExpectedInstance {
+        state: InstanceState::fixable(UsesLocalPath),
+        dependency_name: "package-a",
+        id: "package-a in /dependencies of package-b",
+        actual: "link:../package-a",
+        expected: Some("workspace:^"),
+        overridden: None,
+        severity: None,
+      },
+    ]);
+  }
+
+  #[tokio::test]
+  async fn local_path_policy_workspace_rewrites_path_to_local_version_in_strict_mode() {
+    let ctx = TestBuilder::new()
+      .with_packages(vec![
+        json!({
+          "name": "package-a",
+          "version": "1.0.0"
+        }),
+        json!({
+          "name": "package-b",
+          "version": "2.0.0",
+          "dependencies": {
+            "package-a": "link:../package-a"
+          }
+        }),
+      ])
+      .with_config(json!({"localPathPolicy": "workspace"}))
+      .with_strict(true)
+      .run()
+      .await;
+    expect(&ctx).to_have_instances(vec![
+      ExpectedInstance {
+        state: InstanceState::valid(IsLocalAndValid),
+        dependency_name: "package-b",
+        id: "package-b in /version of package-b",
+        actual: "2.0.0",
+        expected: Some("2.0.0"),
+        overridden: None,
+        severity: None,
+      },
+      ExpectedInstance {
+        state: InstanceState::valid(IsLocalAndValid),
+        dependency_name: "package-a",
+        id: "package-a in /version of package-a",
+        actual: "1.0.0",
+        expected: Some("1.0.0"),
+        overridden: None,
+        severity: None,
+      },
+      ExpectedInstance {
+        state: InstanceState::fixable(UsesLocalPath),
+        dependency_name: "package-a",
+        id: "package-a in /dependencies of package-b",
+        actual: "link:../package-a",
+        expected: Some("1.0.0"),
+        overridden: None,
+        severity: None,
+      },
+    ]);
+  }
+
+  #[tokio::test]
+  async fn local_path_policy_version_rewrites_path_to_local_version_with_semver_group_range() {
+    let ctx = TestBuilder::new()
+      .with_packages(vec![
+        json!({
+          "name": "package-a",
+          "version": "1.0.0"
+        }),
+        json!({
+          "name": "package-b",
+          "version": "2.0.0",
+          "dependencies": {
+            "package-a": "file:../package-a"
+          }
+        }),
+      ])
+      .with_config(json!({"localPathPolicy": "version"}))
+      .with_semver_group(json!({"range": "^"}))
+      .run()
+      .await;
+    expect(&ctx).to_have_instances(vec![
+      ExpectedInstance {
+        state: InstanceState::valid(IsLocalAndValid),
+        dependency_name: "package-b",
+        id: "package-b in /version of package-b",
+        actual: "2.0.0",
+        expected: Some("2.0.0"),
+        overridden: None,
+        severity: None,
+      },
+      ExpectedInstance {
+        state: InstanceState::valid(IsLocalAndValid),
+        dependency_name: "package-a",
+        id: "package-a in /version of package-a",
+        actual: "1.0.0",
+        expected: Some("1.0.0"),
+        overridden: None,
+        severity: None,
+      },
+      ExpectedInstance {
+        state: InstanceState::fixable(UsesLocalPath),
+        dependency_name: "package-a",
+        id: "package-a in /dependencies of package-b",
+        actual: "file:../package-a",
+        expected: Some("^1.0.0"),
+        overridden: None,
+        severity: None,
+      },
+    ]);
+  }
+
   #[tokio::test]
   async fn instance_has_same_version_number_as_local_package_but_mismatches_a_different_but_compatible_semver_group() {
     let ctx = TestBuilder::new()
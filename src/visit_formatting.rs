@@ -13,6 +13,8 @@ pub fn visit_formatting(mut ctx: Context) -> Context {
   for (source_idx, source) in ctx.sources.all.iter().enumerate() {
     let Source::Package { file_idx, .. } = source else { continue };
     let file = &ctx.disk.package_json_files[*file_idx];
+    // "/" is how the root is written in a `property_path`
+    let span_of = |property_path: &str| file.spans.get(if property_path == "/" { "" } else { property_path });
     if (rcfile.sort_packages || !rcfile.sort_first.is_empty())
       && let Some(expected) = format::get_sorted_first(rcfile, &file.contents)
     {
@@ -21,6 +23,7 @@ pub fn visit_formatting(mut ctx: Context) -> Context {
         FormatMismatch {
           expected,
           property_path: "/".to_string(),
+          span: span_of("/"),
           variant: PackagePropertiesAreNotSorted,
         },
       ));
@@ -33,6 +36,7 @@ pub fn visit_formatting(mut ctx: Context) -> Context {
         FormatMismatch {
           expected,
           property_path: "/bugs".to_string(),
+          span: span_of("/bugs"),
           variant: BugsPropertyIsNotFormatted,
         },
       ));
@@ -45,6 +49,7 @@ pub fn visit_formatting(mut ctx: Context) -> Context {
         FormatMismatch {
           expected,
           property_path: "/repository".to_string(),
+          span: span_of("/repository"),
           variant: RepositoryPropertyIsNotFormatted,
         },
       ));
@@ -57,6 +62,7 @@ pub fn visit_formatting(mut ctx: Context) -> Context {
         FormatMismatch {
           expected,
           property_path: "/exports".to_string(),
+          span: span_of("/exports"),
           variant: ExportsPropertyIsNotSorted,
         },
       ));
@@ -69,6 +75,7 @@ pub fn visit_formatting(mut ctx: Context) -> Context {
             FormatMismatch {
               expected,
               property_path: format!("/{key}"),
+              span: span_of(&format!("/{key}")),
               variant: PropertyIsNotSortedAz,
             },
           ));
@@ -1,11 +1,15 @@
 use {
   crate::{
     context::Context,
+    instance::{Instance, SuspectInstance},
     registry::updates::RegistryUpdates,
+    specifier::Specifier,
     version_group::{VersionGroup, VersionGroupBehavior},
   },
   itertools::Itertools,
-  std::cmp::Ordering,
+  log::warn,
+  node_semver::Version,
+  std::{cmp::Ordering, rc::Rc},
 };
 
 /// Iterate version groups (SnappedTo last) and assign `InstanceState` to every
@@ -32,6 +36,12 @@ pub fn visit_packages(ctx: Context, registry_updates: &Option<RegistryUpdates>)
       group.visit(&ctx, registry_updates);
     });
 
+  if ctx.config.rcfile.check_lockfile {
+    visit_lockfile(&ctx);
+  }
+
+  visit_patched_dependencies(&ctx);
+
   let strict = ctx.config.rcfile.strict;
   for group in ctx.version_groups.iter() {
     for dep in group.dependencies().values() {
@@ -43,3 +53,89 @@ pub fn visit_packages(ctx: Context, registry_updates: &Option<RegistryUpdates>)
   }
   ctx
 }
+
+/// Compare the version each instance resolved to in the lockfile against its
+/// specifier, and against the highest version any other instance of the same
+/// dependency resolved to. Only instances which are otherwise valid are
+/// marked, so a lockfile which is out of date never hides a mismatch between
+/// specifiers.
+fn visit_lockfile(ctx: &Context) {
+  for group in ctx.version_groups.iter() {
+    if matches!(group, VersionGroup::Ignored(_)) {
+      continue;
+    }
+    for dep in group.dependencies().values() {
+      let resolved: Vec<(&Instance, Rc<Version>)> = dep
+        .instances
+        .iter()
+        .map(|idx| &ctx.instances[idx.0])
+        .filter_map(|instance| {
+          let version = instance.resolved_version.as_deref().and_then(Specifier::new_node_version)?;
+          Some((instance, version))
+        })
+        .collect();
+      let Some(highest) = resolved.iter().map(|(_, version)| version).max().cloned() else {
+        continue;
+      };
+      for (instance, version) in resolved {
+        if !instance.is_valid() {
+          continue;
+        }
+        let range = instance.descriptor.specifier.get_node_range();
+        if range.is_some_and(|range| !range.satisfies(&version)) {
+          instance.mark_suspect(SuspectInstance::ResolvedVersionOutOfRange);
+        } else if version != highest {
+          instance.mark_suspect(SuspectInstance::ResolvedVersionMismatch);
+        }
+      }
+    }
+  }
+}
+
+/// Find patches in pnpm's `patchedDependencies` which no instance of their
+/// dependency can be installed at once every fix or update is applied. Valid
+/// instances are marked as suspect, while a pending fix or update which would
+/// orphan the patch is logged, so it is reported again once it has been
+/// applied.
+fn visit_patched_dependencies(ctx: &Context) {
+  for patch in ctx.disk.patched_dependencies() {
+    let Some(patched_version) = Specifier::new_node_version(&patch.version) else {
+      continue;
+    };
+    let instances: Vec<&Instance> = ctx
+      .instances
+      .iter()
+      .filter(|instance| instance.descriptor.name == patch.name && !instance.is_local_instance)
+      .collect();
+    let is_orphaned = !instances.is_empty() && instances.iter().all(|instance| !can_install_version(instance, &patched_version));
+    if !is_orphaned {
+      continue;
+    }
+    for instance in instances {
+      if instance.is_valid() {
+        instance.mark_suspect(SuspectInstance::OrphanedPatch);
+      } else if instance.is_fixable() {
+        warn!(
+          "{} will orphan the patch of {}@{} in {}",
+          instance.id, patch.name, patch.version, patch.declared_in
+        );
+      }
+    }
+  }
+}
+
+/// Whether `version` is installed for this instance once its fix or update is
+/// applied. Banned instances are removed by a fix, and the lockfile is only
+/// trusted while the specifier is unchanged.
+fn can_install_version(instance: &Instance, version: &Version) -> bool {
+  if instance.is_banned() {
+    return false;
+  }
+  let expected = instance.expected_specifier.borrow();
+  let specifier = expected.as_ref().unwrap_or(&instance.descriptor.specifier);
+  let is_unchanged = specifier.get_raw() == instance.descriptor.specifier.get_raw();
+  match instance.resolved_version.as_deref() {
+    Some(resolved) if is_unchanged => resolved == version.to_string(),
+    _ => specifier.get_node_range().is_some_and(|range| range.satisfies(version)),
+  }
+}
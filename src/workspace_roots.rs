@@ -0,0 +1,79 @@
+use {
+  crate::{
+    context::Context, group_selector::GroupSelector, rcfile::CrossRootVersionGroup, specifier::Specifier, version_group::AnyVersionGroup,
+  },
+  std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    rc::Rc,
+  },
+};
+
+#[cfg(test)]
+#[path = "workspace_roots_test.rs"]
+mod workspace_roots_test;
+
+/// Create a pinned version group for every dependency matched by a
+/// `crossRootVersionGroups` entry, pinned to the highest (or lowest) semver
+/// version found in any of the workspace roots. Prepending them to the
+/// version groups of every root holds the dependency to the same version in
+/// all of them.
+///
+/// Like version groups, a dependency belongs to the first cross-root group
+/// which matches it. Local packages are never pinned.
+pub fn pin_cross_root_versions(contexts: &[Context], groups: &[CrossRootVersionGroup]) -> Vec<AnyVersionGroup> {
+  let mut claimed: HashSet<String> = HashSet::new();
+  let mut pinned_groups: Vec<AnyVersionGroup> = vec![];
+  for group in groups {
+    let selector = GroupSelector::new(
+      group.dependencies.clone(),
+      group.dependency_types.clone(),
+      group.label.clone(),
+      group.packages.clone(),
+      group.specifier_types.clone(),
+    );
+    let mut specifiers_by_name: BTreeMap<String, Vec<Rc<Specifier>>> = BTreeMap::new();
+    for ctx in contexts {
+      for instance in &ctx.instances {
+        let descriptor = &instance.descriptor;
+        let package_name = ctx.sources.all[descriptor.source_idx.0].name();
+        if instance.is_local_instance
+          || claimed.contains(&descriptor.internal_name)
+          || descriptor.specifier.get_node_version().is_none()
+          || !selector.can_add(descriptor, package_name)
+        {
+          continue;
+        }
+        specifiers_by_name
+          .entry(descriptor.internal_name.clone())
+          .or_default()
+          .push(Rc::clone(&descriptor.specifier));
+      }
+    }
+    let prefer_lowest = group.prefer_version.as_deref() == Some("lowestSemver");
+    for (name, specifiers) in specifiers_by_name {
+      let specifiers = specifiers.into_iter();
+      let Some(pin) = (if prefer_lowest { specifiers.min() } else { specifiers.max() }) else {
+        continue;
+      };
+      claimed.insert(name.clone());
+      pinned_groups.push(AnyVersionGroup {
+        dependencies: vec![name],
+        dependency_types: group.dependency_types.clone(),
+        label: group.label.clone(),
+        packages: group.packages.clone(),
+        specifier_types: group.specifier_types.clone(),
+        selector: None,
+        named_selector: None,
+        is_banned: None,
+        is_ignored: None,
+        pin_version: Some(pin.get_raw().to_string()),
+        policy: None,
+        snap_to: None,
+        prefer_version: None,
+        severity: HashMap::new(),
+        unknown_fields: HashMap::new(),
+      });
+    }
+  }
+  pinned_groups
+}
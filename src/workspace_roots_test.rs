@@ -0,0 +1,181 @@
+use {
+  crate::{
+    cli::Invocation,
+    commands::reporter,
+    instance::{FixableInstance, InstanceState, ValidInstance},
+    registry::client::RegistryClient,
+    syncpack::{WorkspaceRootRun, run_workspace_roots, syncpack_workspace_roots},
+    test::{mock_disk::MockDiskIo, mock_tui::MockTui, registry_client::MockRegistryClient},
+  },
+  serde_json::json,
+  std::sync::Arc,
+};
+
+fn two_workspaces() -> MockDiskIo {
+  let mut disk = MockDiskIo::new();
+  disk.add_json("web/package.json", &json!({"name": "web", "dependencies": {"react": "18.2.0"}}));
+  disk.add_json(
+    "web/packages/ui/package.json",
+    &json!({"name": "web-ui", "dependencies": {"react": "18.2.0"}}),
+  );
+  disk.add_json(
+    "mobile/package.json",
+    &json!({"name": "mobile", "dependencies": {"react": "18.3.1"}}),
+  );
+  disk
+}
+
+async fn run(disk: &MockDiskIo, args: &[&str]) -> Vec<WorkspaceRootRun> {
+  let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+  let registry_client: Arc<dyn RegistryClient> = Arc::new(MockRegistryClient::from_json(json!({})));
+  let Invocation::Run(cli) = Invocation::parse(&args).expect("Invocation::parse failed") else {
+    panic!("expected a subcommand which runs against workspace roots");
+  };
+  syncpack_workspace_roots(cli, disk, &registry_client)
+    .await
+    .expect("syncpack_workspace_roots failed")
+}
+
+fn state_of(run: &WorkspaceRootRun, id: &str) -> InstanceState {
+  run
+    .ctx
+    .instances
+    .iter()
+    .find(|instance| instance.id == id)
+    .unwrap_or_else(|| panic!("instance not found: {id}"))
+    .state
+    .borrow()
+    .clone()
+}
+
+fn package_names(run: &WorkspaceRootRun) -> Vec<&str> {
+  let mut names: Vec<&str> = run.ctx.disk.package_json_files.iter().map(crate::disk::package_name).collect();
+  names.sort();
+  names
+}
+
+#[tokio::test]
+async fn runs_once_without_workspace_roots() {
+  let runs = run(&two_workspaces(), &["syncpack", "lint"]).await;
+  assert_eq!(runs.len(), 1);
+  assert_eq!(runs[0].root, None);
+}
+
+#[tokio::test]
+async fn runs_once_for_every_workspace_root() {
+  let runs = run(&two_workspaces(), &["syncpack", "lint", "--workspace-roots", "web,mobile"]).await;
+  let roots: Vec<Option<&str>> = runs.iter().map(|run| run.root.as_deref()).collect();
+  assert_eq!(roots, vec![Some("web"), Some("mobile")]);
+  assert_eq!(package_names(&runs[0]), vec!["web", "web-ui"]);
+  assert_eq!(package_names(&runs[1]), vec!["mobile"]);
+  assert!(state_of(&runs[0], "react in /dependencies of web").is_valid());
+  assert!(state_of(&runs[1], "react in /dependencies of mobile").is_valid());
+}
+
+#[tokio::test]
+async fn workspace_roots_are_read_from_the_config_file() {
+  let mut disk = two_workspaces();
+  disk.add_json(".syncpackrc", &json!({"workspaceRoots": ["mobile", "web"]}));
+  let runs = run(&disk, &["syncpack", "lint"]).await;
+  let roots: Vec<Option<&str>> = runs.iter().map(|run| run.root.as_deref()).collect();
+  assert_eq!(roots, vec![Some("mobile"), Some("web")]);
+}
+
+#[tokio::test]
+async fn cross_root_version_groups_hold_dependencies_to_one_version() {
+  let mut disk = two_workspaces();
+  disk.add_json(
+    ".syncpackrc",
+    &json!({
+      "workspaceRoots": ["web", "mobile"],
+      "crossRootVersionGroups": [{"dependencies": ["react"], "label": "React everywhere"}]
+    }),
+  );
+  let runs = run(&disk, &["syncpack", "lint"]).await;
+  assert!(matches!(
+    state_of(&runs[0], "react in /dependencies of web-ui"),
+    InstanceState::Invalid(crate::instance::InvalidInstance::Fixable(FixableInstance::DiffersToPin))
+  ));
+  assert!(matches!(
+    state_of(&runs[1], "react in /dependencies of mobile"),
+    InstanceState::Valid(ValidInstance::IsIdenticalToPin)
+  ));
+}
+
+#[tokio::test]
+async fn cross_root_version_groups_can_prefer_the_lowest_version() {
+  let mut disk = two_workspaces();
+  disk.add_json(
+    ".syncpackrc",
+    &json!({
+      "crossRootVersionGroups": [{"dependencies": ["react"], "preferVersion": "lowestSemver"}]
+    }),
+  );
+  let runs = run(&disk, &["syncpack", "lint", "--workspace-roots", "web,mobile"]).await;
+  assert!(matches!(
+    state_of(&runs[0], "react in /dependencies of web"),
+    InstanceState::Valid(ValidInstance::IsIdenticalToPin)
+  ));
+  assert!(matches!(
+    state_of(&runs[1], "react in /dependencies of mobile"),
+    InstanceState::Invalid(crate::instance::InvalidInstance::Fixable(FixableInstance::DiffersToPin))
+  ));
+}
+
+#[tokio::test]
+async fn file_paths_are_relative_to_where_syncpack_was_run() {
+  let runs = run(&two_workspaces(), &["syncpack", "lint", "--workspace-roots", "web,mobile"]).await;
+  let uris: Vec<String> = runs
+    .iter()
+    .flat_map(|run| {
+      run
+        .ctx
+        .disk
+        .package_json_files
+        .iter()
+        .map(|file| reporter::get_uri(&run.ctx, &file.filepath))
+    })
+    .collect();
+  assert!(uris.contains(&"web/package.json".to_string()));
+  assert!(uris.contains(&"web/packages/ui/package.json".to_string()));
+  assert!(uris.contains(&"mobile/package.json".to_string()));
+}
+
+#[tokio::test]
+async fn patch_has_the_diffs_of_every_workspace_root() {
+  let mut disk = MockDiskIo::new();
+  disk.add_json("web/package.json", &json!({"name": "web", "dependencies": {"react": "18.2.0"}}));
+  disk.add_json(
+    "web/packages/ui/package.json",
+    &json!({"name": "web-ui", "dependencies": {"react": "17.0.2"}}),
+  );
+  disk.add_json(
+    "mobile/package.json",
+    &json!({"name": "mobile", "dependencies": {"react": "18.3.1"}}),
+  );
+  disk.add_json(
+    "mobile/packages/ui/package.json",
+    &json!({"name": "mobile-ui", "dependencies": {"react": "18.2.0"}}),
+  );
+  let runs = run(
+    &disk,
+    &[
+      "syncpack",
+      "fix",
+      "--dry-run",
+      "--patch",
+      "syncpack.patch",
+      "--reporter",
+      "json",
+      "--workspace-roots",
+      "web,mobile",
+    ],
+  )
+  .await;
+  run_workspace_roots(runs, &disk, &MockTui::select_all()).expect("run_workspace_roots failed");
+  let patch = disk
+    .written_text(&disk.root().join("syncpack.patch"))
+    .expect("patch was not written");
+  assert!(patch.contains("--- a/web/packages/ui/package.json\n+++ b/web/packages/ui/package.json\n"));
+  assert!(patch.contains("--- a/mobile/packages/ui/package.json\n+++ b/mobile/packages/ui/package.json\n"));
+}
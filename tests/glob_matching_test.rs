@@ -133,6 +133,55 @@ fn issue_334_bare_package_json_matches_root_only() {
   }
 }
 
+#[test]
+fn issue_334_gitignore_can_be_disregarded() {
+  let paths = package_paths(&run_json(
+    "issue-334",
+    &["--source", "apps/**/package.json", "--set", "respectGitignore=false"],
+  ));
+  assert!(
+    has_component(&paths, "dist"),
+    "expected 'dist' directory to be included when respectGitignore is false, got: {paths:?}",
+  );
+  assert!(
+    !has_component(&paths, "node_modules"),
+    "expected 'node_modules' to be pruned, got: {paths:?}",
+  );
+}
+
+// — syncpackignore —
+
+#[test]
+fn syncpackignore_excludes_matching_directories() {
+  let paths = package_paths(&run_json("syncpackignore", &[]));
+  assert!(
+    ends_with(&paths, "packages/a/package.json"),
+    "expected 'packages/a/package.json' to be picked up, got: {paths:?}",
+  );
+  assert!(
+    !has_component(&paths, "generated"),
+    "expected 'packages/generated' to be excluded by .syncpackignore, got: {paths:?}",
+  );
+}
+
+#[test]
+fn syncpackignore_wins_over_explicit_source_patterns() {
+  let paths = package_paths(&run_json("syncpackignore", &["--source", "packages/generated/package.json"]));
+  assert!(
+    paths.is_empty(),
+    "expected 'packages/generated' to be excluded by .syncpackignore, got: {paths:?}",
+  );
+}
+
+#[test]
+fn syncpackignore_is_honoured_when_gitignore_is_not() {
+  let paths = package_paths(&run_json("syncpackignore", &["--set", "respectGitignore=false"]));
+  assert!(
+    !has_component(&paths, "generated"),
+    "expected 'packages/generated' to be excluded by .syncpackignore, got: {paths:?}",
+  );
+}
+
 // — defaults —
 
 #[test]
@@ -320,3 +320,220 @@ fn fix_json_package_contains_real_path() {
     );
   }
 }
+
+// — --reporter sarif —
+
+fn parse_sarif(stdout: &str) -> Value {
+  serde_json::from_str::<Value>(stdout).unwrap_or_else(|_| panic!("invalid SARIF log: {stdout}"))
+}
+
+#[test]
+fn lint_sarif_outputs_one_log_with_a_result_per_issue() {
+  let (stdout, _stderr, code) = run_syncpack(&["lint", "--reporter", "sarif"]);
+  let log = parse_sarif(&stdout);
+  assert_eq!(log["version"], "2.1.0");
+  let results = log["runs"][0]["results"].as_array().expect("results should be an array");
+  let errors: Vec<&Value> = results.iter().filter(|result| result["level"] == "error").collect();
+  assert_eq!(errors.len(), 1);
+  assert_eq!(errors[0]["ruleId"], "DiffersToHighestOrLowestSemver");
+  let location = &errors[0]["locations"][0]["physicalLocation"];
+  assert_eq!(location["artifactLocation"]["uri"], "packages/pkg-a/package.json");
+  assert_eq!(location["region"]["startLine"], 5);
+  assert_eq!(code, 1, "exit code should be the same as the pretty reporter");
+}
+
+#[test]
+fn format_check_sarif_outputs_one_log_with_a_result_per_mismatch() {
+  let (stdout, _stderr, code) = run_syncpack(&["format", "--check", "--reporter", "sarif"]);
+  let log = parse_sarif(&stdout);
+  let results = log["runs"][0]["results"].as_array().expect("results should be an array");
+  let (json_stdout, _stderr, json_code) = run_syncpack(&["format", "--check", "--reporter", "json"]);
+  assert_eq!(results.len(), parse_ndjson_lines(&json_stdout).len());
+  assert!(results.iter().all(|result| result["level"] == "error"));
+  assert_eq!(code, json_code);
+}
+
+#[test]
+fn fix_does_not_accept_sarif_reporter() {
+  let (_stdout, stderr, code) = run_syncpack(&["fix", "--dry-run", "--reporter", "sarif"]);
+  assert!(stderr.contains("invalid value 'sarif'"), "unexpected stderr: {stderr}");
+  assert_eq!(code, 3, "invalid options are a config error");
+}
+
+// — --reporter junit —
+
+#[test]
+fn lint_junit_outputs_a_suite_per_package() {
+  let (stdout, _stderr, code) = run_syncpack(&["lint", "--reporter", "junit"]);
+  assert!(stdout.starts_with("<?xml"), "expected XML: {stdout}");
+  assert_eq!(stdout.matches("<testsuite ").count(), 3);
+  assert!(stdout.contains(r#"<testsuites name="syncpack" tests="5" failures="1">"#));
+  assert!(stdout.contains(r#"type="DiffersToHighestOrLowestSemver""#));
+  assert_eq!(code, 1, "exit code should be the same as the pretty reporter");
+}
+
+#[test]
+fn fix_dry_run_junit_fails_each_fixable_instance() {
+  let (stdout, _stderr, code) = run_syncpack(&["fix", "--dry-run", "--reporter", "junit"]);
+  assert!(stdout.contains(r#"<testsuite name="pkg-a" tests="2" failures="1" file="packages/pkg-a/package.json">"#));
+  assert_eq!(code, 0);
+}
+
+// — --reporter github —
+
+#[test]
+fn lint_github_groups_annotations_by_version_group() {
+  let (stdout, _stderr, _code) = run_syncpack(&["lint", "--reporter", "github"]);
+  let lines: Vec<&str> = stdout.lines().collect();
+  assert_eq!(lines.first(), Some(&"::group::Default Version Group"));
+  assert_eq!(lines.last(), Some(&"::endgroup::"));
+  assert!(lines.contains(
+    &r#"::error file=packages/pkg-a/package.json,line=5,col=12,title=DiffersToHighestOrLowestSemver::foo is "1.0.0" at .dependencies but should be "2.0.0""#
+  ));
+}
+
+#[test]
+fn format_check_github_annotates_each_mismatch() {
+  let (stdout, _stderr, code) = run_syncpack(&["format", "--check", "--reporter", "github"]);
+  let (json_stdout, _stderr, _code) = run_syncpack(&["format", "--check", "--reporter", "json"]);
+  let annotations = stdout.lines().filter(|line| line.starts_with("::error ")).count();
+  assert_eq!(annotations, parse_ndjson_lines(&json_stdout).len());
+  assert!(stdout.contains("::group::pkg-b"));
+  assert_eq!(code, 1);
+}
+
+// — --reporter json for lint, list and update —
+
+#[test]
+fn lint_json_outputs_one_ndjson_line_per_issue_with_its_version_group() {
+  let (stdout, _stderr, code) = run_syncpack(&["lint", "--reporter", "json"]);
+  let lines = parse_ndjson_lines(&stdout);
+  let errors: Vec<&Value> = lines.iter().filter(|line| line["severity"] != "warn").collect();
+  assert_eq!(errors.len(), 1);
+  assert_eq!(errors[0]["dependency"], "foo");
+  assert_eq!(errors[0]["statusCode"], "DiffersToHighestOrLowestSemver");
+  assert_eq!(errors[0]["versionGroup"], "HighestSemver");
+  assert_eq!(errors[0]["versionGroupIndex"], 0);
+  assert_eq!(errors[0]["versionGroupLabel"], "Default Version Group");
+  assert_eq!(code, 1, "exit code should be the same as the pretty reporter");
+}
+
+#[test]
+fn list_json_outputs_every_instance_with_its_version_group() {
+  let (stdout, _stderr, code) = run_syncpack(&["list", "--reporter", "json"]);
+  let lines = parse_ndjson_lines(&stdout);
+  let (json_stdout, _stderr, json_code) = run_syncpack(&["json"]);
+  assert_eq!(lines.len(), parse_ndjson_lines(&json_stdout).len());
+  assert!(lines.iter().all(|line| line["versionGroupLabel"].is_string()));
+  assert_eq!(code, json_code);
+}
+
+#[test]
+fn list_csv_outputs_the_matrix_of_dependencies_by_package() {
+  let (stdout, _stderr, code) = run_syncpack(&["list", "--reporter", "csv", "--dependencies", "foo"]);
+  assert_eq!(stdout, "Dependency,pkg-a,pkg-b\nfoo,1.0.0,2.0.0\n");
+  assert_eq!(code, 1, "exit code should be the same as the pretty reporter");
+}
+
+#[test]
+fn list_csv_respects_the_packages_filter() {
+  let (stdout, _stderr, code) = run_syncpack(&["list", "--reporter", "csv", "--packages", "pkg-b"]);
+  assert_eq!(stdout, "Dependency,pkg-b\nfoo,2.0.0\npkg-b,1.0.0\n");
+  assert_eq!(code, 0);
+}
+
+// — --reporter markdown —
+
+#[test]
+fn lint_markdown_summarises_issues_per_version_group() {
+  let (stdout, _stderr, code) = run_syncpack(&["lint", "--reporter", "markdown"]);
+  assert!(stdout.starts_with("## Syncpack lint\n"), "unexpected stdout: {stdout}");
+  assert!(stdout.contains("| fix | 1 |"));
+  assert!(stdout.contains("<summary><strong>Default Version Group</strong> (2)</summary>"));
+  assert!(stdout.contains("| foo | `2.0.0` | pkg-a `1.0.0` | [DiffersToHighestOrLowestSemver]"));
+  assert_eq!(code, 1, "exit code should be the same as the pretty reporter");
+}
+
+#[test]
+fn format_check_markdown_has_a_section_per_package() {
+  let (stdout, _stderr, code) = run_syncpack(&["format", "--check", "--reporter", "markdown"]);
+  assert!(stdout.contains("| error | 3 |"));
+  assert!(stdout.contains("<summary><strong>pkg-b</strong> (2)</summary>"));
+  assert!(stdout.contains("<summary><strong>pkg-a</strong> (1)</summary>"));
+  assert_eq!(code, 1);
+}
+
+// — --dry-run --patch —
+
+#[test]
+fn fix_dry_run_prints_a_diff_of_each_file() {
+  let (_stdout, stderr, _code) = run_syncpack(&["fix", "--dry-run", "--no-ansi"]);
+  assert!(stderr.contains("--- a/packages/pkg-a/package.json\n+++ b/packages/pkg-a/package.json\n"));
+  assert!(stderr.contains("-    \"foo\": \"1.0.0\"\n+    \"foo\": \"2.0.0\"\n"));
+}
+
+#[test]
+fn fix_dry_run_patch_can_be_applied_with_git() {
+  let patch_path = std::env::temp_dir().join(format!("syncpack-fix-{}.patch", std::process::id()));
+  let (_stdout, _stderr, code) = run_syncpack(&["fix", "--dry-run", "--patch", patch_path.to_str().unwrap()]);
+  assert_eq!(code, 0);
+  let patch = std::fs::read_to_string(&patch_path).expect("patch should be written");
+  assert!(
+    patch.starts_with("--- a/packages/pkg-a/package.json\n"),
+    "unexpected patch: {patch}"
+  );
+  let git_apply = Command::new("git")
+    .args(["apply", "--check", patch_path.to_str().unwrap()])
+    .current_dir(fixture_dir())
+    .output()
+    .expect("failed to run git");
+  assert!(git_apply.status.success(), "{}", String::from_utf8_lossy(&git_apply.stderr));
+  let _ = std::fs::remove_file(&patch_path);
+}
+
+#[test]
+fn patch_requires_dry_run() {
+  let (_stdout, stderr, code) = run_syncpack(&["format", "--patch", "syncpack.patch"]);
+  assert!(stderr.contains("--dry-run"), "unexpected stderr: {stderr}");
+  assert_eq!(code, 3, "invalid options are a config error");
+}
+
+// — exit codes —
+
+#[test]
+fn lint_errors_exit_1_whatever_max_warnings_is() {
+  let (_stdout, _stderr, code) = run_syncpack(&["lint", "--max-warnings", "100"]);
+  assert_eq!(code, 1);
+}
+
+#[test]
+fn max_warnings_must_be_a_number() {
+  let (_stdout, stderr, code) = run_syncpack(&["lint", "--max-warnings", "some"]);
+  assert!(stderr.contains("--max-warnings"), "unexpected stderr: {stderr}");
+  assert_eq!(code, 3);
+}
+
+// — lint --baseline —
+
+#[test]
+fn lint_baseline_tolerates_the_issues_recorded_by_update_baseline() {
+  let baseline_path = std::env::temp_dir().join(format!("syncpack-baseline-{}.json", std::process::id()));
+  let baseline_arg = baseline_path.to_str().unwrap();
+  let (_stdout, _stderr, code) = run_syncpack(&["lint", "--baseline", baseline_arg, "--update-baseline"]);
+  assert_eq!(code, 0);
+  let baseline: Value = serde_json::from_str(&std::fs::read_to_string(&baseline_path).unwrap()).unwrap();
+  let packages: Vec<&str> = baseline
+    .as_array()
+    .unwrap()
+    .iter()
+    .map(|entry| entry["package"].as_str().unwrap())
+    .collect();
+  assert_eq!(packages, vec!["package.json", "packages/pkg-a/package.json"]);
+  let (stdout, _stderr, code) = run_syncpack(&["lint", "--baseline", baseline_arg, "--reporter", "json"]);
+  assert_eq!(code, 0);
+  assert!(
+    parse_ndjson_lines(&stdout).is_empty(),
+    "known issues should not be reported: {stdout}"
+  );
+  let _ = std::fs::remove_file(&baseline_path);
+}